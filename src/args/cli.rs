@@ -35,6 +35,30 @@ pub enum ZatCommand {
 
   /// Process templates defined in a remote Zat repository
   ProcessRemote(ProcessRemoteTemplatesArgs),
+
+  /// Process templates defined in a Zat repository archive (.tar.gz or .zip)
+  ProcessArchive(ProcessArchiveTemplatesArgs),
+
+  /// Add the templates of a generator defined in a Zat repository to an existing project
+  Generate(GenerateArgs),
+
+  /// Create a Zat repository from an existing project
+  Templatize(TemplatizeArgs),
+
+  /// Test the templates of a Zat repository against the expected output of its test cases
+  Test(TestTemplatesArgs),
+
+  /// Describe the variables, plugins, shell hook and files of a Zat repository without processing it
+  Describe(DescribeArgs),
+
+  /// Validate the variables, manifest and plugins of a Zat repository without processing it
+  Validate(ValidateArgs),
+
+  /// Manage the registry of named remote templates
+  Templates(TemplatesArgs),
+
+  /// Inspect the user config file
+  Config(ConfigArgs),
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -43,11 +67,19 @@ pub enum ChoiceMenuStyle {
   Selection
 }
 
+#[derive(Debug, Clone, ValueEnum)]
+pub enum OnCollision {
+  Fail,
+  Overwrite,
+  Skip
+}
+
 #[derive(SubArgs, Debug, Clone)]
 pub struct ProcessTemplatesArgs {
    /// The location of the Zat repository. This should exist.
-   #[arg(long)]
-   pub repository_dir: String,
+   /// Supply multiple times to compose several repositories into the same target directory, in the order supplied.
+   #[arg(long, required = true)]
+   pub repository_dir: Vec<String>,
 
    /// Where to extract the template to. This should directory should not exist.
    #[arg(long)]
@@ -55,17 +87,61 @@ pub struct ProcessTemplatesArgs {
 
    /// One or more files to ignore within the 'template' directory. Supply multiple times for different files or folders.
    /// '.git' are always specified.
-   /// Accepts any valid regular expressions.
+   /// Accepts any valid regular expressions. Ignores in the user config file are also applied.
    #[arg(long)]
    pub ignores: Vec<String>,
 
-   /// Verbose debug logging
+   /// Verbose debug logging. Also enabled by 'verbose = true' in the user config file.
    #[arg(long)]
    pub verbose: bool,
 
-   /// Choice menu style
-   #[arg(long, value_enum, default_value_t = ChoiceMenuStyle::Selection)]
-   pub choice_menu_style: ChoiceMenuStyle
+   /// Turn off verbose debug logging, even if 'verbose = true' is set in the user config file.
+   #[arg(long, conflicts_with = "verbose")]
+   pub no_verbose: bool,
+
+   /// Choice menu style. Defaults to the style in the user config file, or selection if not set.
+   #[arg(long, value_enum)]
+   pub choice_menu_style: Option<ChoiceMenuStyle>,
+
+   /// What to do when a file from one repository would replace a file written by an earlier repository.
+   /// Defaults to overwrite.
+   #[arg(long, value_enum)]
+   pub on_collision: Option<OnCollision>,
+
+   /// Don't run plugins. Variables with plugins are prompted for with their default values instead.
+   #[arg(long)]
+   pub no_plugins: bool,
+
+   /// Run plugins again instead of using their cached results.
+   #[arg(long)]
+   pub no_plugin_cache: bool,
+
+   /// Don't run the shell hook of the repository.
+   #[arg(long)]
+   pub no_hooks: bool,
+
+   /// Stop a shell hook, lifecycle hook or post-processing command that runs for longer than this many seconds.
+   /// Hooks have no timeout by default.
+   #[arg(long, value_name = "SECONDS")]
+   pub hook_timeout: Option<u64>
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum BootstrapKind {
+  /// Variables with filters and a default value
+  Minimal,
+
+  /// Adds choices, scopes and a conditional template
+  Choices,
+
+  /// Adds a variable with a default value supplied by a sample plugin
+  Plugin,
+
+  /// Adds a sample shell hook
+  ShellHook,
+
+  /// Choices, scopes, a plugin and a shell hook
+  Full,
 }
 
 #[derive(SubArgs, Debug, Clone)]
@@ -74,14 +150,31 @@ pub struct BootstrapProjectArgs {
    /// The location of where to create the sample repository. This should directory should not exist.
    #[arg(long)]
    pub repository_dir: String,
+
+   /// The features demonstrated by the sample repository.
+   #[arg(long, value_enum, default_value_t = BootstrapKind::Minimal)]
+   pub kind: BootstrapKind,
 }
 
 #[derive(SubArgs, Debug, Clone)]
 pub struct ProcessRemoteTemplatesArgs {
 
-  /// Remote http(s) URL of a Git repository.
+  /// URL of a Git repository. One of: http(s)://, ssh://, scp-style (git@github.com:org/repo.git), file:// for local repositories
+  /// or the GitHub shorthand gh:owner/repository.
+  #[arg(long, required_unless_present = "template", conflicts_with = "template")]
+  pub repository_url: Option<String>,
+
+  /// Name of a template in the template registry (see `zat templates`) or the GitHub shorthand gh:owner/repository.
   #[arg(long)]
-  pub repository_url: String,
+  pub template: Option<String>,
+
+  /// Git branch, tag or commit to checkout. Overrides any ref in the template registry.
+  #[arg(long = "ref")]
+  pub git_ref: Option<String>,
+
+  /// Folder within the Git repository that contains the Zat repository. Overrides any subdir in the template registry.
+  #[arg(long)]
+  pub subdir: Option<String>,
 
    /// Where to extract the template to. This should directory should not exist.
    #[arg(long)]
@@ -89,17 +182,281 @@ pub struct ProcessRemoteTemplatesArgs {
 
    /// One or more files ignore. Supply multiple times for different files or folders.
    /// The files '.variables.zat-prompt' and '.git' are always specified.
-   /// Accepts any valid regular expressions.
+   /// Accepts any valid regular expressions. Ignores in the user config file are also applied.
+   #[arg(long)]
+   pub ignores: Vec<String>,
+
+   /// Verbose debug logging. Also enabled by 'verbose = true' in the user config file.
+   #[arg(long)]
+   pub verbose: bool,
+
+   /// Turn off verbose debug logging, even if 'verbose = true' is set in the user config file.
+   #[arg(long, conflicts_with = "verbose")]
+   pub no_verbose: bool,
+
+   /// Choice menu style. Defaults to the style in the user config file, or selection if not set.
+   #[arg(long, value_enum)]
+   pub choice_menu_style: Option<ChoiceMenuStyle>,
+
+   /// Don't run plugins. Variables with plugins are prompted for with their default values instead.
+   #[arg(long)]
+   pub no_plugins: bool,
+
+   /// Run plugins again instead of using their cached results.
+   #[arg(long)]
+   pub no_plugin_cache: bool,
+
+   /// Don't run the shell hook of the repository.
+   #[arg(long)]
+   pub no_hooks: bool,
+
+   /// Stop a shell hook, lifecycle hook or post-processing command that runs for longer than this many seconds.
+   /// Hooks have no timeout by default.
+   #[arg(long, value_name = "SECONDS")]
+   pub hook_timeout: Option<u64>
+}
+
+#[derive(SubArgs, Debug, Clone)]
+pub struct ProcessArchiveTemplatesArgs {
+
+  /// Local path or file:// URL of a '.tar.gz', '.tgz' or '.zip' archive containing a Zat repository.
+  /// A single top-level folder within the archive is stripped automatically.
+  #[arg(long)]
+  pub archive: String,
+
+   /// Where to extract the template to. This should directory should not exist.
+   #[arg(long)]
+   pub target_dir: String,
+
+   /// One or more files ignore. Supply multiple times for different files or folders.
+   /// The files '.variables.zat-prompt' and '.git' are always specified.
+   /// Accepts any valid regular expressions. Ignores in the user config file are also applied.
    #[arg(long)]
    pub ignores: Vec<String>,
 
-   /// Verbose debug logging
+   /// Verbose debug logging. Also enabled by 'verbose = true' in the user config file.
    #[arg(long)]
    pub verbose: bool,
 
-   /// Choice menu style
-   #[arg(long, value_enum, default_value_t = ChoiceMenuStyle::Selection)]
-   pub choice_menu_style: ChoiceMenuStyle
+   /// Turn off verbose debug logging, even if 'verbose = true' is set in the user config file.
+   #[arg(long, conflicts_with = "verbose")]
+   pub no_verbose: bool,
+
+   /// Choice menu style. Defaults to the style in the user config file, or selection if not set.
+   #[arg(long, value_enum)]
+   pub choice_menu_style: Option<ChoiceMenuStyle>,
+
+   /// Don't run plugins. Variables with plugins are prompted for with their default values instead.
+   #[arg(long)]
+   pub no_plugins: bool,
+
+   /// Run plugins again instead of using their cached results.
+   #[arg(long)]
+   pub no_plugin_cache: bool,
+
+   /// Don't run the shell hook of the repository.
+   #[arg(long)]
+   pub no_hooks: bool,
+
+   /// Stop a shell hook, lifecycle hook or post-processing command that runs for longer than this many seconds.
+   /// Hooks have no timeout by default.
+   #[arg(long, value_name = "SECONDS")]
+   pub hook_timeout: Option<u64>
+}
+
+#[derive(SubArgs, Debug, Clone)]
+pub struct GenerateArgs {
+
+  /// The location of the Zat repository. This should exist.
+  pub repository_dir: String,
+
+  /// The name of the generator to run. Generators are defined in the 'generators' directory of the Zat repository.
+  pub generator: String,
+
+   /// The existing project to add the generated files to. This directory should exist.
+   #[arg(long)]
+   pub target_dir: String,
+
+   /// A path within the target directory to write the generated files to. Eg. src/handlers
+   #[arg(long)]
+   pub path_prefix: Option<String>,
+
+   /// One or more files to ignore within the generator's 'template' directory. Supply multiple times for different files or folders.
+   /// '.git' are always specified.
+   /// Accepts any valid regular expressions. Ignores in the user config file are also applied.
+   #[arg(long)]
+   pub ignores: Vec<String>,
+
+   /// Verbose debug logging. Also enabled by 'verbose = true' in the user config file.
+   #[arg(long)]
+   pub verbose: bool,
+
+   /// Turn off verbose debug logging, even if 'verbose = true' is set in the user config file.
+   #[arg(long, conflicts_with = "verbose")]
+   pub no_verbose: bool,
+
+   /// Choice menu style. Defaults to the style in the user config file, or selection if not set.
+   #[arg(long, value_enum)]
+   pub choice_menu_style: Option<ChoiceMenuStyle>,
+
+   /// What to do when a generated file already exists in the project. Defaults to fail.
+   #[arg(long, value_enum)]
+   pub on_collision: Option<OnCollision>,
+
+   /// Don't run plugins. Variables with plugins are prompted for with their default values instead.
+   #[arg(long)]
+   pub no_plugins: bool,
+
+   /// Run plugins again instead of using their cached results.
+   #[arg(long)]
+   pub no_plugin_cache: bool,
+
+   /// Don't run the shell hook of the repository.
+   #[arg(long)]
+   pub no_hooks: bool,
+
+   /// Stop a shell hook, lifecycle hook or post-processing command that runs for longer than this many seconds.
+   /// Hooks have no timeout by default.
+   #[arg(long, value_name = "SECONDS")]
+   pub hook_timeout: Option<u64>
+}
+
+#[derive(SubArgs, Debug, Clone)]
+pub struct TemplatizeArgs {
+
+   /// The existing project to create the Zat repository from. This directory should exist.
+   #[arg(long)]
+   pub source: String,
+
+   /// The location of where to create the Zat repository. This directory should not exist.
+   #[arg(long)]
+   pub repository_dir: String,
+
+   /// Text in the project to replace with a variable, as <TEXT>=<VARIABLE_NAME>. Eg. my-service=project
+   /// Variations of the text in other cases, such as MyService and my_service, are also replaced.
+   /// Supply multiple times for different variables.
+   #[arg(long, required = true)]
+   pub replace: Vec<String>,
+
+   /// One or more files in the project to ignore. Supply multiple times for different files or folders.
+   /// '.git' is always specified.
+   /// Accepts any valid regular expressions.
+   #[arg(long)]
+   pub ignores: Vec<String>,
+}
+
+#[derive(SubArgs, Debug, Clone)]
+pub struct TestTemplatesArgs {
+
+   /// The location of the Zat repository. Test cases are directories within its 'tests' directory, each with an
+   /// 'answers.txt' file and an 'expected' directory.
+   #[arg(long)]
+   pub repository_dir: String,
+
+   /// Replace the expected output of each test case with its rendered output.
+   #[arg(long)]
+   pub bless: bool,
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum DescribeFormat {
+  Text,
+  Json
+}
+
+#[derive(SubArgs, Debug, Clone)]
+pub struct DescribeArgs {
+
+   /// The location of the Zat repository to describe.
+   #[arg(long, required_unless_present = "repository_url", conflicts_with = "repository_url")]
+   pub repository_dir: Option<String>,
+
+   /// URL of a Git repository to describe, or the GitHub shorthand gh:owner/repository.
+   #[arg(long)]
+   pub repository_url: Option<String>,
+
+   /// Git branch, tag or commit to checkout. Only used with --repository-url.
+   #[arg(long = "ref", requires = "repository_url")]
+   pub git_ref: Option<String>,
+
+   /// Folder within the Git repository that contains the Zat repository. Only used with --repository-url.
+   #[arg(long, requires = "repository_url")]
+   pub subdir: Option<String>,
+
+   /// One or more files to ignore, as they would be supplied to 'zat process'.
+   /// Accepts any valid regular expressions.
+   #[arg(long)]
+   pub ignores: Vec<String>,
+
+   /// The format of the description. Json is intended for tools, and is the only output on stdout.
+   #[arg(long, value_enum, default_value_t = DescribeFormat::Text)]
+   pub format: DescribeFormat,
+}
+
+#[derive(SubArgs, Debug, Clone)]
+pub struct ValidateArgs {
+
+   /// The location of the Zat repository to validate.
+   #[arg(long)]
+   pub repository_dir: String,
+}
+
+#[derive(SubArgs, Debug, Clone)]
+pub struct TemplatesArgs {
+  #[command(subcommand)]
+  pub command: TemplatesCommand
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum TemplatesCommand {
+  /// List the registered templates
+  List,
+
+  /// Register a remote template under a name
+  Add(AddTemplateArgs),
+
+  /// Remove a registered template
+  Remove(RemoveTemplateArgs),
+}
+
+#[derive(SubArgs, Debug, Clone)]
+pub struct AddTemplateArgs {
+
+  /// The name to register the template under. Use this name with `process-remote --template`.
+  #[arg(long)]
+  pub name: String,
+
+  /// URL of a Git repository. Supports the same URLs as `process-remote --repository-url`.
+  #[arg(long)]
+  pub url: String,
+
+  /// Git branch, tag or commit to checkout.
+  #[arg(long = "ref")]
+  pub git_ref: Option<String>,
+
+  /// Folder within the Git repository that contains the Zat repository.
+  #[arg(long)]
+  pub subdir: Option<String>,
+}
+
+#[derive(SubArgs, Debug, Clone)]
+pub struct RemoveTemplateArgs {
+
+  /// The name of the registered template to remove.
+  #[arg(long)]
+  pub name: String,
+}
+
+#[derive(SubArgs, Debug, Clone)]
+pub struct ConfigArgs {
+  #[command(subcommand)]
+  pub command: ConfigCommand
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ConfigCommand {
+  /// Show the defaults from the user config file merged with Zat's built-in defaults
+  Show,
 }
 
 
@@ -3,14 +3,20 @@ use crate::config::user_config::MenuStyle;
 use crate::config::ConfigShellHookStatus;
 use crate::error::*;
 use super::ChoiceMenuStyle;
+use super::OnCollision;
 use super::UserConfigProvider;
-use super::cli::ProcessTemplatesArgs;
+use super::cli::{GenerateArgs, ProcessTemplatesArgs};
 use crate::config::UserConfig;
 use crate::config::IgnoredFiles;
 use crate::config::Filters;
 use crate::config::TargetDir;
 use crate::config::RepositoryDir;
 use crate::config::TemplateFilesDir;
+use crate::config::{UserDefaults, UserDefaultsFile, ZatConfigDir};
+use crate::config::CollisionPolicy;
+use crate::config::RepositoryOrigin;
+use crate::config::RepositoryHooks;
+use std::time::Duration;
 
 
 #[derive(Debug, Clone, PartialEq)]
@@ -37,15 +43,87 @@ enum ShellHookStatus {
   DoesNotExist,
 }
 
-pub struct DefaultUserConfigProvider;
+pub struct DefaultUserConfigProvider {
+  user_defaults_file: Option<UserDefaultsFile>
+}
 
 impl DefaultUserConfigProvider {
+  /// Uses the user config file in the Zat config directory. If there is no config directory, there are no user defaults.
   pub fn new() -> Self {
-    DefaultUserConfigProvider
+    let user_defaults_file = ZatConfigDir::new().ok().as_ref().map(UserDefaultsFile::from);
+    DefaultUserConfigProvider::with_user_defaults_file(user_defaults_file)
+  }
+
+  pub fn with_user_defaults_file(user_defaults_file: Option<UserDefaultsFile>) -> Self {
+    DefaultUserConfigProvider {
+      user_defaults_file
+    }
+  }
+
+  fn get_user_defaults(&self) -> ZatResult<UserDefaults> {
+    match &self.user_defaults_file {
+      Some(user_defaults_file) => user_defaults_file.load(),
+      None => Ok(UserDefaults::default())
+    }
   }
 }
 
 impl DefaultUserConfigProvider {
+  /// Verifies that each additional repository exists and has a template files directory.
+  fn get_additional_repository_dirs(repository_dirs: impl Iterator<Item = RepositoryDir>) -> ZatResult<Vec<RepositoryDir>> {
+    repository_dirs
+      .map(|repository_dir| {
+        let template_files_dir = TemplateFilesDir::from(&repository_dir);
+
+        if !repository_dir.does_exist() {
+          Err(ZatError::template_dir_does_not_exist(repository_dir.path()))
+        } else if !template_files_dir.does_exist() {
+          Err(ZatError::template_files_dir_does_not_exist(template_files_dir.path()))
+        } else {
+          Ok(repository_dir)
+        }
+      })
+      .collect()
+  }
+
+  fn get_ignores(user_defaults: &UserDefaults, cli_ignores: Vec<String>) -> IgnoredFiles {
+    let ignores_with_defaults =
+      IgnoredFiles::default_ignores()
+        .into_iter()
+        .chain(user_defaults.ignores(cli_ignores)); // use default ignores with user-supplied ignores
+
+    IgnoredFiles::from(ignores_with_defaults)
+  }
+
+  /// Whether verbose logging was turned on or off on the command line, or None if neither flag was supplied.
+  fn get_cli_verbose(verbose: bool, no_verbose: bool) -> Option<bool> {
+    match (verbose, no_verbose) {
+      (_, true) => Some(false),
+      (true, _) => Some(true),
+      _ => None,
+    }
+  }
+
+  fn get_menu_style(user_defaults: &UserDefaults, cli_choice_menu_style: Option<ChoiceMenuStyle>) -> MenuStyle {
+    let choice_menu_style =
+      cli_choice_menu_style
+        .map(|style| match style {
+          ChoiceMenuStyle::Numbered => MenuStyle::Numbered,
+          ChoiceMenuStyle::Selection => MenuStyle::Selection,
+        });
+
+    user_defaults.menu_style(choice_menu_style)
+  }
+
+  fn get_collision_policy(on_collision: Option<OnCollision>, default_collision_policy: CollisionPolicy) -> CollisionPolicy {
+    match on_collision {
+      Some(OnCollision::Fail) => CollisionPolicy::Fail,
+      Some(OnCollision::Overwrite) => CollisionPolicy::Overwrite,
+      Some(OnCollision::Skip) => CollisionPolicy::Skip,
+      None => default_collision_policy,
+    }
+  }
+
   fn get_shell_hook_status(template_dir: &RepositoryDir) -> ShellHookStatus {
     let shell_hook = template_dir.shell_hook_file();
     let shell_hook_exists = shell_hook.exists();
@@ -61,7 +139,10 @@ impl DefaultUserConfigProvider {
 impl UserConfigProvider for DefaultUserConfigProvider {
 
   fn get_user_config(&self, args: ProcessTemplatesArgs) -> ZatResult<UserConfig> {
-    let repository_dir = RepositoryDir::new(&args.repository_dir);
+    let user_defaults = self.get_user_defaults()?;
+    let mut repository_dirs = args.repository_dir.iter().map(|dir| RepositoryDir::new(dir));
+    let repository_dir = repository_dirs.next().unwrap_or_default();
+    let additional_repository_dirs = DefaultUserConfigProvider::get_additional_repository_dirs(repository_dirs)?;
     let target_dir = TargetDir::new(&args.target_dir);
     let template_files_dir = TemplateFilesDir::from(&repository_dir);
 
@@ -89,16 +170,15 @@ impl UserConfigProvider for DefaultUserConfigProvider {
     let shell_hook_file_status =
       DefaultUserConfigProvider::get_shell_hook_status(&repository_dir);
 
-    let ignores_with_defaults =
-      IgnoredFiles::default_ignores()
-        .into_iter()
-        .chain(args.ignores); // use default ignores with user-supplied ignores
+    let ignores = DefaultUserConfigProvider::get_ignores(&user_defaults, args.ignores);
 
-    let ignores = IgnoredFiles::from(ignores_with_defaults);
+    let verbose = user_defaults.verbose(DefaultUserConfigProvider::get_cli_verbose(args.verbose, args.no_verbose));
 
-    let verbose = args.verbose;
+    let menu_style = DefaultUserConfigProvider::get_menu_style(&user_defaults, args.choice_menu_style);
 
-    let choice_menu_style = args.choice_menu_style;
+    let variable_defaults = user_defaults.variables;
+
+    let collision_policy = DefaultUserConfigProvider::get_collision_policy(args.on_collision, CollisionPolicy::Overwrite);
 
     match (repository_dir_exists, template_files_dir_exists, target_dir_exists) {
       (RepositoryDirStatus::DoesNotExist, _, _) => {
@@ -119,12 +199,6 @@ impl UserConfigProvider for DefaultUserConfigProvider {
           ShellHookStatus::DoesNotExist => ConfigShellHookStatus::NoShellHook
         };
 
-        let menu_style = match choice_menu_style {
-            ChoiceMenuStyle::Numbered => MenuStyle::Numbered,
-            ChoiceMenuStyle::Selection => MenuStyle::Selection,
-        };
-
-
         Ok(
           UserConfig {
             repository_dir,
@@ -134,12 +208,83 @@ impl UserConfigProvider for DefaultUserConfigProvider {
             ignores,
             verbose,
             shell_hook_status,
-            menu_style
+            menu_style,
+            variable_defaults,
+            additional_repository_dirs,
+            collision_policy,
+            no_plugins: args.no_plugins,
+            no_plugin_cache: args.no_plugin_cache,
+            no_hooks: args.no_hooks,
+            hook_timeout: args.hook_timeout.map(Duration::from_secs),
+            origin: RepositoryOrigin::Local,
+            hooks: RepositoryHooks::default(),
+            actions: vec![]
           }
         )
       },
     }
   }
+
+  fn get_generator_config(&self, args: GenerateArgs) -> ZatResult<UserConfig> {
+    let user_defaults = self.get_user_defaults()?;
+    let repository_dir = RepositoryDir::new(&args.repository_dir);
+    let generator_dir = repository_dir.generator_dir(&args.generator);
+    let template_files_dir = TemplateFilesDir::from(&generator_dir);
+    let project_dir = TargetDir::new(&args.target_dir);
+
+    if !repository_dir.does_exist() {
+      return Err(ZatError::template_dir_does_not_exist(repository_dir.path()))
+    }
+
+    if !generator_dir.does_exist() {
+      return Err(ZatError::generator_does_not_exist(&args.generator, repository_dir.path(), &repository_dir.generator_names()))
+    }
+
+    if !template_files_dir.does_exist() {
+      return Err(ZatError::template_files_dir_does_not_exist(template_files_dir.path()))
+    }
+
+    if !project_dir.does_exist() {
+      return Err(ZatError::generate_target_dir_does_not_exist(&project_dir.path))
+    }
+
+    // Generated files are written within the path prefix of the project
+    let target_dir =
+      match &args.path_prefix {
+        Some(path_prefix) => TargetDir::new(&std::path::Path::new(&project_dir.path).join(path_prefix).to_string_lossy()),
+        None => project_dir
+      };
+
+    let shell_hook_status =
+      match DefaultUserConfigProvider::get_shell_hook_status(&generator_dir) {
+        ShellHookStatus::Exists => ConfigShellHookStatus::RunShellHook(generator_dir.shell_hook_file().to_string_lossy().to_string()),
+        ShellHookStatus::DoesNotExist => ConfigShellHookStatus::NoShellHook
+      };
+
+    Ok(
+      UserConfig {
+        repository_dir: generator_dir,
+        template_files_dir,
+        target_dir,
+        filters: Filters::default(),
+        ignores: DefaultUserConfigProvider::get_ignores(&user_defaults, args.ignores),
+        verbose: user_defaults.verbose(DefaultUserConfigProvider::get_cli_verbose(args.verbose, args.no_verbose)),
+        shell_hook_status,
+        menu_style: DefaultUserConfigProvider::get_menu_style(&user_defaults, args.choice_menu_style),
+        variable_defaults: user_defaults.variables,
+        additional_repository_dirs: vec![],
+        // Generators write into existing projects, so don't replace existing files unless asked to
+        collision_policy: DefaultUserConfigProvider::get_collision_policy(args.on_collision, CollisionPolicy::Fail),
+        no_plugins: args.no_plugins,
+        no_plugin_cache: args.no_plugin_cache,
+        no_hooks: args.no_hooks,
+        hook_timeout: args.hook_timeout.map(Duration::from_secs),
+        origin: RepositoryOrigin::Local,
+        hooks: RepositoryHooks::default(),
+        actions: vec![]
+      }
+    )
+  }
 }
 
 
@@ -153,6 +298,7 @@ mod tests {
   use super::super::test_util::temp_dir_with;
   use std::format as s;
   use crate::error::user_config_error_reason::UserConfigErrorReason;
+  use crate::error::generate_command_error_reason::GenerateCommandErrorReason;
 
 
   /// Returns UserConfig or panics on any errors.
@@ -185,14 +331,20 @@ mod tests {
 
     let args =
       ProcessTemplatesArgs {
-        repository_dir: template_dir_path.clone(),
+        repository_dir: vec![template_dir_path.clone()],
         target_dir: target_dir_path.clone(),
         ignores,
         verbose: false,
-        choice_menu_style: ChoiceMenuStyle::Numbered
+        no_verbose: false,
+        choice_menu_style: Some(ChoiceMenuStyle::Numbered),
+        on_collision: None,
+        no_plugins: false,
+        no_plugin_cache: false,
+        no_hooks: false,
+        hook_timeout: None
       };
 
-    let user_config_provider = DefaultUserConfigProvider;
+    let user_config_provider = DefaultUserConfigProvider::with_user_defaults_file(None);
     let config = get_user_config(user_config_provider, args);
 
     let expected_repository_dir = RepositoryDir::new(&template_dir_path);
@@ -234,14 +386,20 @@ mod tests {
 
     let args =
       ProcessTemplatesArgs {
-        repository_dir: repository_dir_path.clone(),
+        repository_dir: vec![repository_dir_path.clone()],
         target_dir: target_dir_path.clone(),
         ignores,
         verbose: false,
-        choice_menu_style: ChoiceMenuStyle::Numbered
+        no_verbose: false,
+        choice_menu_style: Some(ChoiceMenuStyle::Numbered),
+        on_collision: None,
+        no_plugins: false,
+        no_plugin_cache: false,
+        no_hooks: false,
+        hook_timeout: None
       };
 
-    let user_config_provider = DefaultUserConfigProvider;
+    let user_config_provider = DefaultUserConfigProvider::with_user_defaults_file(None);
     let config = get_user_config(user_config_provider, args);
 
     let expected_repository_dir = RepositoryDir::new(&repository_dir_path);
@@ -273,14 +431,20 @@ mod tests {
 
     let args =
       ProcessTemplatesArgs {
-        repository_dir: repository_dir_path.clone(),
+        repository_dir: vec![repository_dir_path.clone()],
         target_dir: target_dir_path.clone(),
         ignores: vec![],
         verbose: false,
-        choice_menu_style: ChoiceMenuStyle::Numbered
+        no_verbose: false,
+        choice_menu_style: Some(ChoiceMenuStyle::Numbered),
+        on_collision: None,
+        no_plugins: false,
+        no_plugin_cache: false,
+        no_hooks: false,
+        hook_timeout: None
       };
 
-    let user_config_provider = DefaultUserConfigProvider;
+    let user_config_provider = DefaultUserConfigProvider::with_user_defaults_file(None);
     match get_user_config_fallable(user_config_provider, args) {
       Ok(_) => panic!("get_config should fail if the repository directory does not exist"),
       Err(error) => {
@@ -304,14 +468,20 @@ mod tests {
 
     let args =
       ProcessTemplatesArgs {
-        repository_dir: repository_dir_path.clone(),
+        repository_dir: vec![repository_dir_path.clone()],
         target_dir: target_dir_path.clone(),
         ignores: vec![],
         verbose: false,
-        choice_menu_style: ChoiceMenuStyle::Numbered
+        no_verbose: false,
+        choice_menu_style: Some(ChoiceMenuStyle::Numbered),
+        on_collision: None,
+        no_plugins: false,
+        no_plugin_cache: false,
+        no_hooks: false,
+        hook_timeout: None
       };
 
-    let user_config_provider = DefaultUserConfigProvider;
+    let user_config_provider = DefaultUserConfigProvider::with_user_defaults_file(None);
     match get_user_config_fallable(user_config_provider, args) {
       Ok(_) => panic!("get_config should fail if the template files directory does not exist"),
       Err(error) => {
@@ -335,14 +505,20 @@ mod tests {
 
     let args =
       ProcessTemplatesArgs {
-        repository_dir: template_dir_path.clone(),
+        repository_dir: vec![template_dir_path.clone()],
         target_dir: target_dir_path.clone(),
         ignores: vec![],
         verbose: false,
-        choice_menu_style: ChoiceMenuStyle::Numbered
+        no_verbose: false,
+        choice_menu_style: Some(ChoiceMenuStyle::Numbered),
+        on_collision: None,
+        no_plugins: false,
+        no_plugin_cache: false,
+        no_hooks: false,
+        hook_timeout: None
       };
 
-    let user_config_provider = DefaultUserConfigProvider;
+    let user_config_provider = DefaultUserConfigProvider::with_user_defaults_file(None);
     match get_user_config_fallable(user_config_provider, args) {
       Ok(_) => panic!("get_config should fail if the target directory does exist"),
       Err(error) => {
@@ -353,6 +529,94 @@ mod tests {
     }
   }
 
+  #[test]
+  fn config_uses_user_defaults_with_cli_precedence() {
+    let target_dir = TempDir::new().unwrap();
+    let repository_dir = temp_dir_with(TEMPLATE_FILES_DIR);
+    let config_dir = TempDir::new().unwrap();
+
+    let repository_dir_path = repository_dir.path().display().to_string();
+    let target_dir_path = target_dir.path().display().to_string();
+
+    drop(target_dir);
+
+    let user_defaults_content = r#"
+      choice_menu_style = "numbered"
+      ignores = ["\\.idea"]
+      verbose = true
+
+      [variables]
+      author_email = "me@example.com"
+    "#;
+
+    std::fs::write(config_dir.path().join(crate::config::user_defaults::USER_DEFAULTS_FILE), user_defaults_content).unwrap();
+
+    let args =
+      ProcessTemplatesArgs {
+        repository_dir: vec![repository_dir_path],
+        target_dir: target_dir_path,
+        ignores: vec!["target".to_owned()],
+        verbose: false,
+        no_verbose: false,
+        choice_menu_style: Some(ChoiceMenuStyle::Selection),
+        on_collision: None,
+        no_plugins: false,
+        no_plugin_cache: false,
+        no_hooks: false,
+        hook_timeout: None
+      };
+
+    let user_defaults_file = UserDefaultsFile::from(&ZatConfigDir::from(config_dir.path()));
+    let user_config_provider = DefaultUserConfigProvider::with_user_defaults_file(Some(user_defaults_file));
+    let config = get_user_config(user_config_provider, args);
+
+    let mut expected_ignores = vec!["\\.idea".to_owned(), "target".to_owned()];
+    expected_ignores.append(&mut IgnoredFiles::default_ignores());
+
+    let actual_ignores_set: HashSet<String> = config.ignores.ignores;
+    let expected_ignores_set: HashSet<String> = HashSet::from_iter(expected_ignores);
+
+    assert_eq!(actual_ignores_set, expected_ignores_set);
+    assert_eq!(config.menu_style, MenuStyle::Selection);
+    assert!(config.verbose);
+    assert_eq!(config.variable_defaults.get("author_email"), Some(&"me@example.com".to_owned()))
+  }
+
+  #[test]
+  fn config_turns_off_verbose_from_user_defaults_with_no_verbose() {
+    let target_dir = TempDir::new().unwrap();
+    let repository_dir = temp_dir_with(TEMPLATE_FILES_DIR);
+    let config_dir = TempDir::new().unwrap();
+
+    let repository_dir_path = repository_dir.path().display().to_string();
+    let target_dir_path = target_dir.path().display().to_string();
+
+    drop(target_dir);
+
+    std::fs::write(config_dir.path().join(crate::config::user_defaults::USER_DEFAULTS_FILE), "verbose = true").unwrap();
+
+    let args =
+      ProcessTemplatesArgs {
+        repository_dir: vec![repository_dir_path],
+        target_dir: target_dir_path,
+        ignores: vec![],
+        verbose: false,
+        no_verbose: true,
+        choice_menu_style: None,
+        on_collision: None,
+        no_plugins: false,
+        no_plugin_cache: false,
+        no_hooks: false,
+        hook_timeout: None
+      };
+
+    let user_defaults_file = UserDefaultsFile::from(&ZatConfigDir::from(config_dir.path()));
+    let user_config_provider = DefaultUserConfigProvider::with_user_defaults_file(Some(user_defaults_file));
+    let config = get_user_config(user_config_provider, args);
+
+    assert!(!config.verbose)
+  }
+
 
   mod shell_hook {
     use crate::{config::SHELL_HOOK_FILE, args::test_util::create_file_in};
@@ -383,4 +647,134 @@ mod tests {
     }
   }
 
+
+  #[test]
+  fn config_is_loaded_with_additional_repositories() {
+    let target_dir = TempDir::new().unwrap();
+    let repository_dir = temp_dir_with(TEMPLATE_FILES_DIR);
+    let additional_repository_dir = temp_dir_with(TEMPLATE_FILES_DIR);
+
+    let repository_dir_path = repository_dir.path().display().to_string();
+    let additional_repository_dir_path = additional_repository_dir.path().display().to_string();
+    let target_dir_path = target_dir.path().display().to_string();
+
+    drop(target_dir);
+
+    let args =
+      ProcessTemplatesArgs {
+        repository_dir: vec![repository_dir_path.clone(), additional_repository_dir_path.clone()],
+        target_dir: target_dir_path,
+        ignores: vec![],
+        verbose: false,
+        no_verbose: false,
+        choice_menu_style: None,
+        on_collision: Some(OnCollision::Skip),
+        no_plugins: true,
+        no_plugin_cache: false,
+        no_hooks: true,
+        hook_timeout: None
+      };
+
+    let config = get_user_config(DefaultUserConfigProvider::with_user_defaults_file(None), args);
+
+    assert_eq!(config.repository_dir, RepositoryDir::new(&repository_dir_path));
+    assert_eq!(config.additional_repository_dirs, vec![RepositoryDir::new(&additional_repository_dir_path)]);
+    assert_eq!(config.collision_policy, CollisionPolicy::Skip);
+    assert!(config.no_plugins && config.no_hooks);
+
+    let repository_configs = config.repository_configs();
+    let template_files_dirs: Vec<&TemplateFilesDir> = repository_configs.iter().map(|c| &c.template_files_dir).collect();
+
+    assert_eq!(template_files_dirs, vec![&TemplateFilesDir::from(&RepositoryDir::new(&repository_dir_path)), &TemplateFilesDir::from(&RepositoryDir::new(&additional_repository_dir_path))])
+  }
+
+  #[test]
+  fn config_fails_if_an_additional_repository_does_not_exist() {
+    let target_dir = TempDir::new().unwrap();
+    let repository_dir = temp_dir_with(TEMPLATE_FILES_DIR);
+    let additional_repository_dir = TempDir::new().unwrap();
+
+    let repository_dir_path = repository_dir.path().display().to_string();
+    let additional_repository_dir_path = additional_repository_dir.path().display().to_string();
+    let target_dir_path = target_dir.path().display().to_string();
+
+    drop(target_dir);
+
+    let args =
+      ProcessTemplatesArgs {
+        repository_dir: vec![repository_dir_path, additional_repository_dir_path],
+        target_dir: target_dir_path,
+        ignores: vec![],
+        verbose: false,
+        no_verbose: false,
+        choice_menu_style: None,
+        on_collision: None,
+        no_plugins: false,
+        no_plugin_cache: false,
+        no_hooks: false,
+        hook_timeout: None
+      };
+
+    let result = get_user_config_fallable(DefaultUserConfigProvider::with_user_defaults_file(None), args);
+
+    assert!(matches!(result, Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::UserConfigError(UserConfigErrorReason::TemplateFilesDirDoesNotExist(..))))))
+  }
+
+  fn generate_args(repository_dir: &str, generator: &str, target_dir: &str, path_prefix: Option<&str>) -> GenerateArgs {
+    GenerateArgs {
+      repository_dir: repository_dir.to_owned(),
+      generator: generator.to_owned(),
+      target_dir: target_dir.to_owned(),
+      path_prefix: path_prefix.map(|p| p.to_owned()),
+      ignores: vec![],
+      verbose: false,
+      no_verbose: false,
+      choice_menu_style: None,
+      on_collision: None,
+      no_plugins: false,
+      no_plugin_cache: false,
+      no_hooks: false,
+      hook_timeout: None
+    }
+  }
+
+  #[test]
+  fn generator_config_is_loaded() {
+    let target_dir = TempDir::new().unwrap();
+    let repository_dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(repository_dir.path().join("generators").join("handler").join(TEMPLATE_FILES_DIR)).unwrap();
+
+    let repository_dir_path = repository_dir.path().display().to_string();
+    let target_dir_path = target_dir.path().display().to_string();
+
+    let args = generate_args(&repository_dir_path, "handler", &target_dir_path, Some("src"));
+    let config = DefaultUserConfigProvider::with_user_defaults_file(None).get_generator_config(args).unwrap();
+
+    let generator_dir = RepositoryDir::new(&s!("{}/generators/handler", repository_dir_path));
+
+    assert_eq!(config.template_files_dir, TemplateFilesDir::from(&generator_dir));
+    assert_eq!(config.repository_dir, generator_dir);
+    assert_eq!(config.target_dir, TargetDir::new(&s!("{}/src", target_dir_path)));
+    assert_eq!(config.collision_policy, CollisionPolicy::Fail)
+  }
+
+  #[test]
+  fn generator_config_fails_if_the_generator_does_not_exist() {
+    let target_dir = TempDir::new().unwrap();
+    let repository_dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(repository_dir.path().join("generators").join("handler")).unwrap();
+    std::fs::create_dir_all(repository_dir.path().join("generators").join("component")).unwrap();
+
+    let repository_dir_path = repository_dir.path().display().to_string();
+    let target_dir_path = target_dir.path().display().to_string();
+
+    let args = generate_args(&repository_dir_path, "model", &target_dir_path, None);
+    let result = DefaultUserConfigProvider::with_user_defaults_file(None).get_generator_config(args);
+
+    crate::assert_error_with!(
+      result,
+      Err(ZatError::GenerateCommandError(GenerateCommandErrorReason::GeneratorDoesNotExist(error, _))) => error,
+      |error: String| assert!(error.ends_with("The available generators are: component, handler."))
+    )
+  }
 }
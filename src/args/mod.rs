@@ -8,10 +8,19 @@ pub use user_config_provider::UserConfigProvider;
 pub use default_user_config_provider::DefaultUserConfigProvider;
 pub use cli::ZatCommand;
 pub use cli::ProcessTemplatesArgs;
-pub use cli::BootstrapProjectArgs;
+pub use cli::{BootstrapProjectArgs, BootstrapKind};
 pub use cli::ProcessRemoteTemplatesArgs;
+pub use cli::ProcessArchiveTemplatesArgs;
+pub use cli::GenerateArgs;
+pub use cli::TemplatizeArgs;
+pub use cli::TestTemplatesArgs;
+pub use cli::{DescribeArgs, DescribeFormat};
+pub use cli::ValidateArgs;
+pub use cli::{TemplatesArgs, TemplatesCommand, AddTemplateArgs, RemoveTemplateArgs};
+pub use cli::{ConfigArgs, ConfigCommand};
 pub use arg_supplier::ArgSupplier;
 pub use cli::ChoiceMenuStyle;
+pub use cli::OnCollision;
 
 use cli::Args;
 
@@ -1,6 +1,6 @@
 use crate::error::ZatResult;
 use crate::config::UserConfig;
-use super::cli::ProcessTemplatesArgs;
+use super::cli::{GenerateArgs, ProcessTemplatesArgs};
 
 
 /// Behaviour to return configuration provided by the "user"
@@ -8,4 +8,7 @@ pub trait UserConfigProvider {
 
   /// Returns the UserConfig
   fn get_user_config(&self, args: ProcessTemplatesArgs) -> ZatResult<UserConfig>;
+
+  /// Returns the UserConfig to run a generator against an existing project
+  fn get_generator_config(&self, args: GenerateArgs) -> ZatResult<UserConfig>;
 }
@@ -11,12 +11,7 @@ impl ChoiceScopeFilter for DefaultChoiceScopeFilter {
       //filter variables that have don't have a scope or where the scope matches one of the choices
       variables
         .tokens
-        .retain(|v| {
-          match &v.scopes {
-            Some(scopes) => Self::filter_scopes_by_choices(choices, scopes),
-            None => true // No scopes so include everything
-          }
-      });
+        .retain(|v| Self::is_in_scope(choices, &v.scopes));
     }
 }
 
@@ -29,6 +24,14 @@ enum ChoicesAndScopesDefined {
 
 impl DefaultChoiceScopeFilter {
 
+  /// Whether anything with these scopes, such as a variable or a hook, is included by the selected choices.
+  pub fn is_in_scope(choices: &HashMap<UserChoiceKey, UserChoiceValue>, scopes: &Option<Vec<Scope>>) -> bool {
+    match scopes {
+      Some(scopes) => Self::filter_scopes_by_choices(choices, scopes),
+      None => true // No scopes so include everything
+    }
+  }
+
   fn filter_scopes_by_choices(choices: &HashMap<UserChoiceKey, UserChoiceValue>, scopes: &[Scope]) -> bool {
       // Not having any choices defined is a special case.
       // If there are any "include" scopes they should not be included when we don't have the choices defined that should filter them in. Likewise any "exclude" scopes should be included as there are no choices to exclude them.
@@ -47,7 +50,7 @@ impl DefaultChoiceScopeFilter {
         ChoicesAndScopesDefined::ScopesOnly => {
           scopes
             .iter()
-            .map(|scope| match scope {
+            .any(|scope| match scope {
               // We don't have a matching choice, so these should be excluded
               Scope::IncludeChoiceScope(..)      => false,
               Scope::IncludeChoiceValueScope(..) => false,
@@ -55,7 +58,6 @@ impl DefaultChoiceScopeFilter {
               Scope::ExcludeChoiceScope(..)      => true,
               Scope::ExcludeChoiceValueScope(..) => true,
             })
-            .any(|v| v)
         },
         ChoicesAndScopesDefined::ChoicesAndScopes => {
           scopes
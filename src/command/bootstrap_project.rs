@@ -2,11 +2,12 @@ use std::fs;
 use std::path::Path;
 use std::format as s;
 
-use crate::args::BootstrapProjectArgs;
+use crate::args::{BootstrapKind, BootstrapProjectArgs};
 use crate::error::{ZatError, ZatAction};
 use crate::config::{RepositoryDir, DOT_VARIABLES_PROMPT, TemplateFilesDir};
 use crate::logging::Logger;
 use crate::spath;
+use super::bootstrap_repository::{BootstrapRepository, BOOTSTRAP_ANSWERS_FILE};
 
 pub struct BootstrapProject;
 
@@ -61,21 +62,61 @@ impl BootstrapProject {
     } else {
       let repository_path = Path::new(repository_directory.path());
       Self::create_directory(repository_path)?;
-      Self::create_file(repository_path.join(DOT_VARIABLES_PROMPT), Self::VARIABLE_FILE)?;
 
-      let template_files_dir = TemplateFilesDir::from(&repository_directory);
-      let template_files_dir_path = Path::new(template_files_dir.path());
-      Self::create_directory(template_files_dir_path)?;
-      Self::create_file(template_files_dir_path.join("README.md.tmpl"), Self::README_MD_TMPL)?;
-      Self::create_file(template_files_dir_path.join("$project__underscore$_config.conf"), Self::PROJECT_CONFIG_CONF)?;
+      match bootstrap_project_args.kind {
+        BootstrapKind::Minimal => Self::create_minimal_repository(&repository_directory)?,
+        _ => Self::create_repository(&bootstrap_project_args.kind, repository_path)?
+      }
 
       Logger::info(&s!("Zat created a bootstrap repository at `{}`.", spath!(&repository_path)));
       Logger::info(&s!("Process the bootstrap repository with: `zat process --repository-dir {} --target-dir <YOUR_TARGET_DIRECTORY>`", spath!(&repository_path)));
+      Logger::info(&s!("Or process it with the sample answers: `zat process --repository-dir {} --target-dir <YOUR_TARGET_DIRECTORY> --choice-menu-style numbered < {}`", spath!(&repository_path), spath!(repository_path.join(BOOTSTRAP_ANSWERS_FILE))));
 
       Ok(())
     }
   }
 
+  fn create_minimal_repository(repository_directory: &RepositoryDir) -> ZatAction {
+    let repository_path = Path::new(repository_directory.path());
+    Self::create_file(repository_path.join(DOT_VARIABLES_PROMPT), Self::VARIABLE_FILE)?;
+    Self::create_file(repository_path.join(BOOTSTRAP_ANSWERS_FILE), BootstrapRepository::answers(&BootstrapKind::Minimal).join("\n"))?;
+
+    let template_files_dir = TemplateFilesDir::from(repository_directory);
+    let template_files_dir_path = Path::new(template_files_dir.path());
+    Self::create_directory(template_files_dir_path)?;
+    Self::create_file(template_files_dir_path.join("README.md.tmpl"), Self::README_MD_TMPL)?;
+    Self::create_file(template_files_dir_path.join("$project__underscore$_config.conf"), Self::PROJECT_CONFIG_CONF)
+  }
+
+  fn create_repository(kind: &BootstrapKind, repository_path: &Path) -> ZatAction {
+    for file in BootstrapRepository::files(kind) {
+      let file_path = repository_path.join(&file.path);
+
+      if let Some(parent) = file_path.parent() {
+        Self::create_directory(parent)?
+      }
+
+      Self::create_file(&file_path, &file.content)?;
+
+      if file.executable {
+        Self::make_executable(&file_path)?
+      }
+    }
+
+    Ok(())
+  }
+
+  #[cfg(unix)]
+  fn make_executable(file_path: &Path) -> ZatAction {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(file_path, fs::Permissions::from_mode(0o755)).map_err(|e| ZatError::could_not_create_bootstrap_file(e, &file_path.to_string_lossy()))
+  }
+
+  #[cfg(not(unix))]
+  fn make_executable(_file_path: &Path) -> ZatAction {
+    Ok(())
+  }
+
   fn create_directory<P: AsRef<Path> + Clone>(path: P) -> ZatAction {
     fs::create_dir_all(path.clone()).map_err(|e| ZatError::could_not_create_bootstrap_repository(e, &AsRef::<Path>::as_ref(&path).to_string_lossy()))
   }
@@ -0,0 +1,294 @@
+use std::format as s;
+
+use crate::args::BootstrapKind;
+use crate::config::{DOT_VARIABLES_PROMPT_YAML, PLUGINS_DIR, SHELL_HOOK_FILE, TEMPLATE_FILES_DIR};
+
+/// The file of answers to the prompts of a bootstrap repository, one per line. Supply it through stdin to process the
+/// repository without prompting.
+pub const BOOTSTRAP_ANSWERS_FILE: &str = "bootstrap-answers.txt";
+
+/// A file within a bootstrap repository, relative to the repository directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BootstrapFile {
+  pub path: String,
+  pub content: String,
+  pub executable: bool,
+}
+
+impl BootstrapFile {
+  fn new(path: &str, content: &str) -> Self {
+    Self {
+      path: path.to_owned(),
+      content: content.to_owned(),
+      executable: false
+    }
+  }
+
+  fn template(path: &str, content: &str) -> Self {
+    Self::new(&s!("{}/{}", TEMPLATE_FILES_DIR, path), content)
+  }
+
+  fn executable(path: &str, content: &str) -> Self {
+    Self {
+      executable: true,
+      ..Self::new(path, content)
+    }
+  }
+}
+
+/// The features demonstrated by a bootstrap repository.
+struct BootstrapFeatures {
+  choices: bool,
+  plugin: bool,
+  shell_hook: bool,
+}
+
+/// The files of a bootstrap repository that demonstrates choices, scopes, plugins and shell hooks, depending on its
+/// kind. The minimal kind is written by BootstrapProject itself.
+pub struct BootstrapRepository;
+
+impl BootstrapRepository {
+
+  const PLUGIN_ID: &'static str = "author.sh";
+
+  const PLUGIN_SH: &'static str =
+r#"#!/bin/bash
+
+# A sample plugin. Plugins can be any executable that writes a result in the format below to stdout.
+# The result is offered as the default value of the variable that runs the plugin.
+#
+# On failure, write an error instead and exit successfully:
+# {"error":{"plugin_name":"author","error":"What went wrong","fix":"How to fix it"}}
+
+AUTHOR="${1:-$(whoami)}"
+echo "{\"success\":{\"result\":\"$AUTHOR\"}}"
+"#;
+
+  const SHELL_HOOK: &'static str =
+r#"#!/bin/bash
+
+# A sample shell hook. It runs once all templates have been written, and receives the target directory as its only
+# argument. Use it to run commands on the generated project, such as 'git init' or 'cargo build'.
+
+TARGET_DIR="$1"
+
+echo "The shell hook received the target directory: $TARGET_DIR"
+ls -1 "$TARGET_DIR"
+"#;
+
+  const CONFIG_CONF: &'static str =
+r#"// The $project__underscore$ token is replaced in this file's name when the template is processed. This is not a
+// template file, as it does not have the '.tmpl' extension, so any tokens in its content are left as is.
+"#;
+
+  /// Returns the files of the bootstrap repository, with paths relative to the repository.
+  pub fn files(kind: &BootstrapKind) -> Vec<BootstrapFile> {
+    let features = Self::features(kind);
+
+    let mut files =
+      vec![
+        BootstrapFile::new(DOT_VARIABLES_PROMPT_YAML, &Self::variables_file(&features)),
+        BootstrapFile::template("README.md.tmpl", &Self::readme(&features)),
+        BootstrapFile::template("$project__underscore$_config.conf", Self::CONFIG_CONF),
+        BootstrapFile::new(BOOTSTRAP_ANSWERS_FILE, &Self::answers(kind).join("\n")),
+      ];
+
+    if features.plugin {
+      files.push(BootstrapFile::executable(&s!("{}/{}", PLUGINS_DIR, Self::PLUGIN_ID), Self::PLUGIN_SH))
+    }
+
+    if features.shell_hook {
+      files.push(BootstrapFile::executable(SHELL_HOOK_FILE, Self::SHELL_HOOK))
+    }
+
+    files
+  }
+
+  /// The answers to each prompt, in the order they are prompted for with the numbered choice menu style. Choices are
+  /// prompted for first, then variables and finally the confirmation.
+  pub fn answers(kind: &BootstrapKind) -> Vec<&'static str> {
+    let features = Self::features(kind);
+    let mut answers = vec![];
+
+    if features.choices {
+      answers.push("2") // The 'Long' README
+    }
+
+    answers.push("My Cool Project");
+    answers.push(""); // Use the default description
+
+    if features.choices {
+      answers.push("A longer summary of my cool project")
+    }
+
+    if features.plugin {
+      answers.push("") // Use the author returned by the plugin
+    }
+
+    answers.push("y");
+    answers.push(""); // Ends the last answer with a newline
+    answers
+  }
+
+  fn features(kind: &BootstrapKind) -> BootstrapFeatures {
+    match kind {
+      BootstrapKind::Minimal   => BootstrapFeatures { choices: false, plugin: false, shell_hook: false },
+      BootstrapKind::Choices   => BootstrapFeatures { choices: true,  plugin: false, shell_hook: false },
+      BootstrapKind::Plugin    => BootstrapFeatures { choices: false, plugin: true,  shell_hook: false },
+      BootstrapKind::ShellHook => BootstrapFeatures { choices: false, plugin: false, shell_hook: true  },
+      BootstrapKind::Full      => BootstrapFeatures { choices: true,  plugin: true,  shell_hook: true  },
+    }
+  }
+
+  fn variables_file(features: &BootstrapFeatures) -> String {
+    let mut variables =
+      vec![
+r#"# Variables are prompted for in the order they are defined, after any choices.
+# See: https://github.com/ssanj/zat/blob/main/docs/user-manual/defining-a-template/defining-tokens.md
+
+# Referenced in templates and file names as $project$, or $project__underscore$ for the 'underscore' filter.
+- variable_name: project
+  description: Name of project
+  prompt: Please enter your project name
+  filters:
+    # The __default__ filter is applied to $project$
+    - name: __default__
+      filter: Noop
+    - name: underscore
+      filter: Snake
+"#.to_owned(),
+
+r#"
+# The default value is used when no value is entered.
+- variable_name: description
+  description: What your project is about
+  prompt: Please enter a description of your project
+  default_value: Some project description
+"#.to_owned(),
+      ];
+
+    if features.choices {
+      variables.push(
+r#"
+# A choice is selected from a menu and can be used in Tera 'if' conditions within template files.
+- variable_name: readme_type
+  description: Type of README
+  prompt: Please choose your type of README
+  choices:
+    - display: Short
+      description: A shorter README
+      value: short
+    - display: Long
+      description: A longer README with a summary
+      value: long
+
+# Scopes only prompt for a variable when a choice was selected. This is only prompted for with a 'Long' README.
+- variable_name: summary
+  description: Summary for a longer README
+  prompt: Please enter your summary
+  scopes:
+    - choice: readme_type
+      value: long
+"#.to_owned()
+      )
+    }
+
+    if features.plugin {
+      variables.push(
+        s!(
+r#"
+# A plugin is run before prompting, and its result is offered as the default value.
+# Plugins in the `plugins` directory of this repository are found by their file name, before any on your PATH.
+- variable_name: author
+  description: Author of the project
+  prompt: Please enter the author of the project
+  plugin:
+    id: {}
+"#,
+          Self::PLUGIN_ID
+        )
+      )
+    }
+
+    variables.concat()
+  }
+
+  fn readme(features: &BootstrapFeatures) -> String {
+    let mut sections =
+      vec![
+r#"# $project$
+
+Welcome to your bootstrap project. This is a template file, because it has the `.tmpl` extension. A template file will have any tokens it references replaced by values supplied by the user when this template is processed. __project__ and __description__ are tokens used in this file. They are defined in the `.variables.zat-prompt.yaml` file at the root of this repository.
+
+## Summary
+
+$description$
+"#.to_owned()
+      ];
+
+    if features.choices {
+      sections.push(
+r#"
+{% if readme_type == "long" %}
+## Details
+
+This section is only included when the 'Long' README is chosen.
+
+$summary$
+{% endif %}
+"#.to_owned()
+      )
+    }
+
+    if features.plugin {
+      sections.push(
+r#"
+## Author
+
+$author$. The default value was supplied by the plugin in the `plugins` directory.
+"#.to_owned()
+      )
+    }
+
+    sections.concat()
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  fn file_paths(kind: &BootstrapKind) -> Vec<String> {
+    BootstrapRepository::files(kind)
+      .into_iter()
+      .map(|file| file.path)
+      .collect()
+  }
+
+  #[test]
+  fn choices_bootstrap_includes_choices_and_scopes() {
+    let files = BootstrapRepository::files(&BootstrapKind::Choices);
+
+    assert!(files[0].content.contains("choices:"));
+    assert!(files[0].content.contains("scopes:"));
+    assert!(files[1].content.contains(r#"{% if readme_type == "long" %}"#));
+    assert_eq!(file_paths(&BootstrapKind::Choices).len(), 4)
+  }
+
+  #[test]
+  fn full_bootstrap_includes_an_executable_plugin_and_shell_hook() {
+    let files = BootstrapRepository::files(&BootstrapKind::Full);
+    let executables: Vec<&str> = files.iter().filter(|file| file.executable).map(|file| file.path.as_str()).collect();
+
+    assert_eq!(executables, vec!["plugins/author.sh", "shell-hook.zat-exec"]);
+    assert!(files[0].content.contains("id: author.sh"))
+  }
+
+  #[test]
+  fn answers_match_the_prompts_of_each_kind() {
+    assert_eq!(BootstrapRepository::answers(&BootstrapKind::ShellHook), vec!["My Cool Project", "", "y", ""]);
+    assert_eq!(BootstrapRepository::answers(&BootstrapKind::Full), vec!["2", "My Cool Project", "", "A longer summary of my cool project", "", "y", ""])
+  }
+}
@@ -0,0 +1,428 @@
+use std::fs;
+use std::format as s;
+use regex::Regex;
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use crate::args::{DescribeArgs, DescribeFormat};
+use crate::config::{HookPhase, IgnoredFiles, ManifestFile, RemoteRepository, RepositoryDir, RepositoryHooks, RepositoryManifest, TemplateFilesDir, UserConfig};
+use crate::error::{ZatAction, ZatError, ZatResult};
+use crate::inheritance::ParentRepositories;
+use crate::logging::{Logger, Printer};
+use crate::plugin::DefaultPluginRunner;
+use crate::processor::string_token_replacer::EchoingStringTokenReplacer;
+use crate::registry::TemplateResolver;
+use crate::templates::{DefaultTemplateVariableProvider, TemplateVariable, TemplateVariables};
+use super::ProcessRemoteTemplates;
+
+/// A description of what processing a Zat repository would do, without prompting or writing any files.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RepositoryDescription {
+  pub repository: String,
+  pub manifest: Option<ManifestDescription>,
+  pub variables: Vec<VariableDescription>,
+  pub plugins: Vec<PluginDescription>,
+  pub shell_hook: Option<String>,
+
+  /// The phase and command line of each lifecycle hook of the manifest, in the order they would be run.
+  pub lifecycle_hooks: Vec<String>,
+
+  /// The post-processing actions of the manifest, in the order they would be run.
+  pub actions: Vec<String>,
+  pub ignores: Vec<String>,
+  pub ignored_files: Vec<String>,
+
+  /// Files and directories of the templates, relative to the template files directory. Directories end with a '/'.
+  pub files: Vec<String>,
+
+  #[serde(skip)]
+  template_variables: TemplateVariables,
+
+  #[serde(skip)]
+  manifest_lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ManifestDescription {
+  pub name: String,
+  pub description: Option<String>,
+  pub version: Option<String>,
+  pub authors: Vec<String>,
+  pub min_zat_version: Option<String>,
+  pub tags: Vec<String>,
+  pub parent: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VariableDescription {
+  pub variable_name: String,
+  pub description: String,
+  pub prompt: String,
+  pub default_value: Option<String>,
+  pub filters: Vec<FilterDescription>,
+  pub choices: Vec<ChoiceDescription>,
+  pub scopes: Vec<String>,
+  pub plugin: Option<PluginDescription>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FilterDescription {
+  pub name: String,
+  pub filter: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChoiceDescription {
+  pub display: String,
+  pub description: String,
+  pub value: String,
+}
+
+/// A plugin that would be run before prompting, and the command line it would be run with.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PluginDescription {
+  pub variable_name: String,
+  pub id: String,
+  pub command: String,
+}
+
+impl From<&RepositoryManifest> for ManifestDescription {
+  fn from(manifest: &RepositoryManifest) -> Self {
+    Self {
+      name: manifest.name.clone(),
+      description: manifest.description.clone(),
+      version: manifest.version.clone(),
+      authors: manifest.authors.clone(),
+      min_zat_version: manifest.min_zat_version.clone(),
+      tags: manifest.tags.clone(),
+      parent: manifest.parent.as_ref().map(|parent| parent.repository.clone()),
+    }
+  }
+}
+
+impl From<&TemplateVariable> for VariableDescription {
+  fn from(variable: &TemplateVariable) -> Self {
+    Self {
+      variable_name: variable.variable_name.clone(),
+      description: variable.description.clone(),
+      prompt: variable.prompt.clone(),
+      default_value: variable.default_value.clone(),
+      filters:
+        variable
+          .filters
+          .iter()
+          .map(|filter| FilterDescription { name: filter.name.clone(), filter: s!("{:?}", filter.filter) })
+          .collect(),
+      choices:
+        variable
+          .choices
+          .iter()
+          .map(|choice| ChoiceDescription { display: choice.display.clone(), description: choice.description.clone(), value: choice.value.clone() })
+          .collect(),
+      scopes:
+        variable
+          .scopes
+          .iter()
+          .flatten()
+          .map(|scope| scope.to_string())
+          .collect(),
+      plugin: PluginDescription::from_variable(variable),
+    }
+  }
+}
+
+impl PluginDescription {
+  fn from_variable(variable: &TemplateVariable) -> Option<Self> {
+    variable
+      .plugin
+      .as_ref()
+      .map(|plugin| {
+        Self {
+          variable_name: variable.variable_name.clone(),
+          id: plugin.id.clone(),
+          command: DefaultPluginRunner::generate_command_string(plugin)
+        }
+      })
+  }
+}
+
+
+pub struct DescribeTemplate;
+
+impl DescribeTemplate {
+
+  /// Prints the variables, plugins, shell hook, lifecycle hooks, post-processing actions and files of a Zat repository.
+  /// Nothing is prompted for and no plugins or hooks are run.
+  pub fn process_describe(describe_args: DescribeArgs) -> ZatAction {
+    // Only the description is written to stdout, so that it can be read by tools
+    if let DescribeFormat::Json = describe_args.format {
+      Logger::quiet()
+    }
+
+    let description =
+      match &describe_args.repository_url {
+        Some(repository_url) => {
+          let remote_repository =
+            RemoteRepository {
+              url: TemplateResolver::expand_url(repository_url)?,
+              git_ref: describe_args.git_ref.clone(),
+              subdir: describe_args.subdir.clone(),
+            };
+
+          let (checkout_directory, repository_dir) = ProcessRemoteTemplates::checkout(&remote_repository)?;
+          let description = Self::describe(&repository_dir, &describe_args.ignores);
+          ProcessRemoteTemplates::remove_temporary_directory(checkout_directory);
+
+          description.map(|description| RepositoryDescription { repository: remote_repository.url, ..description })?
+        },
+
+        None => {
+          let repository_dir = RepositoryDir::new(describe_args.repository_dir.as_deref().unwrap_or("."));
+
+          if !repository_dir.does_exist() {
+            return Err(ZatError::template_dir_does_not_exist(repository_dir.path()))
+          }
+
+          Self::describe(&repository_dir, &describe_args.ignores)?
+        }
+      };
+
+    match describe_args.format {
+      DescribeFormat::Text => Self::print_text(&description),
+      DescribeFormat::Json => Self::print_json(&description)?,
+    }
+
+    Ok(())
+  }
+
+  pub fn describe(repository_dir: &RepositoryDir, cli_ignores: &[String]) -> ZatResult<RepositoryDescription> {
+    let manifest_file = ManifestFile::from(repository_dir.clone());
+    let manifest = manifest_file.load()?;
+
+    let ignores: Vec<String> =
+      IgnoredFiles::default_ignores()
+        .into_iter()
+        .chain(cli_ignores.iter().cloned())
+        .collect();
+
+    let user_config =
+      UserConfig {
+        ignores: IgnoredFiles::from(ignores.clone().into_iter()),
+        ..UserConfig::for_repository(repository_dir)
+      };
+
+    let mut parent_repositories = ParentRepositories::load(repository_dir, manifest.as_ref())?;
+
+    let result =
+      parent_repositories
+        .template_variables(&DefaultTemplateVariableProvider::new(), &user_config)
+        .and_then(|template_variables| {
+          let layered_config = parent_repositories.layer_templates(user_config.clone(), &EchoingStringTokenReplacer)?;
+          let (files, ignored_files) = Self::template_files(&layered_config.template_files_dir, &ignores)?;
+          let variables: Vec<VariableDescription> = template_variables.tokens.iter().map(VariableDescription::from).collect();
+
+          Ok(
+            RepositoryDescription {
+              repository: repository_dir.path().to_owned(),
+              manifest: manifest.as_ref().map(ManifestDescription::from),
+              plugins: variables.iter().filter_map(|variable| variable.plugin.clone()).collect(),
+              variables,
+              shell_hook: Self::shell_hook(repository_dir)?,
+              lifecycle_hooks: manifest.as_ref().map(|manifest| Self::lifecycle_hooks(&manifest.hooks)).unwrap_or_default(),
+              actions: manifest.iter().flat_map(|manifest| manifest.actions.iter().map(|action| action.to_string())).collect(),
+              ignores: ignores.clone(),
+              ignored_files,
+              files,
+              manifest_lines: manifest.as_ref().map(|manifest| manifest.lines()).unwrap_or_default(),
+              template_variables,
+            }
+          )
+        });
+
+    parent_repositories.remove();
+
+    result
+  }
+
+  /// Each lifecycle hook as its phase and command line, followed by any scopes it is only run in.
+  fn lifecycle_hooks(hooks: &RepositoryHooks) -> Vec<String> {
+    HookPhase::ALL
+      .into_iter()
+      .flat_map(|phase| {
+        hooks
+          .phase(phase)
+          .iter()
+          .map(move |hook| {
+            let scopes: Vec<String> = hook.scopes.iter().flatten().map(|scope| scope.to_string()).collect();
+
+            if scopes.is_empty() {
+              s!("{}: {}", phase, hook.command_string())
+            } else {
+              s!("{}: {} ({})", phase, hook.command_string(), scopes.join(", "))
+            }
+          })
+      })
+      .collect()
+  }
+
+  fn shell_hook(repository_dir: &RepositoryDir) -> ZatResult<Option<String>> {
+    let shell_hook_file = repository_dir.shell_hook_file();
+
+    if shell_hook_file.exists() {
+      fs::read_to_string(&shell_hook_file)
+        .map(Some)
+        .map_err(|e| ZatError::could_not_read_describe_shell_hook(&shell_hook_file.to_string_lossy(), e.to_string()))
+    } else {
+      Ok(None)
+    }
+  }
+
+  /// Returns the files that would be processed and the files that would be ignored, relative to the template files
+  /// directory and sorted by path.
+  fn template_files(template_files_dir: &TemplateFilesDir, ignores: &[String]) -> ZatResult<(Vec<String>, Vec<String>)> {
+    let ignore_regexes =
+      ignores
+        .iter()
+        .map(|ignore| Regex::new(ignore).map_err(|e| ZatError::invalid_describe_ignore(ignore, e.to_string())))
+        .collect::<ZatResult<Vec<_>>>()?;
+
+    let mut files = vec![];
+    let mut ignored_files = vec![];
+
+    let entries =
+      WalkDir::new(template_files_dir.path())
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .flatten();
+
+    for entry in entries {
+      let relative_path = template_files_dir.relative_path(&entry.path().to_string_lossy());
+      let display_path = if entry.file_type().is_dir() { s!("{}/", relative_path) } else { relative_path.clone() };
+
+      if ignore_regexes.iter().any(|regex| regex.is_match(&relative_path)) {
+        ignored_files.push(display_path)
+      } else {
+        files.push(display_path)
+      }
+    }
+
+    Ok((files, ignored_files))
+  }
+
+  fn print_text(description: &RepositoryDescription) {
+    Printer::print_verbose_strings("Repository", vec![description.repository.clone()]);
+
+    if !description.manifest_lines.is_empty() {
+      Printer::print_verbose_strings("Manifest", description.manifest_lines.clone())
+    }
+
+    Printer::print_verbose("Variables", &description.template_variables);
+
+    Printer::print_verbose_strings("Plugins", Self::or_none(description.plugins.iter().map(|plugin| s!("{}: {}", plugin.variable_name, plugin.command)).collect()));
+
+    let shell_hook_lines =
+      description
+        .shell_hook
+        .as_ref()
+        .map(|shell_hook| shell_hook.lines().map(|line| line.to_owned()).collect())
+        .unwrap_or_default();
+
+    Printer::print_verbose_strings("Shell hook", Self::or_none(shell_hook_lines));
+    Printer::print_verbose_strings("Lifecycle hooks", Self::or_none(description.lifecycle_hooks.clone()));
+    Printer::print_verbose_strings("Post-processing actions", Self::or_none(description.actions.clone()));
+    Printer::print_verbose_strings("Ignores", description.ignores.clone());
+    Printer::print_verbose_strings("Ignored files", Self::or_none(description.ignored_files.clone()));
+    Printer::print_verbose_strings("Files", Self::or_none(description.files.iter().map(|file| Self::tree_line(file)).collect()));
+  }
+
+  fn print_json(description: &RepositoryDescription) -> ZatAction {
+    let json =
+      serde_json::to_string_pretty(description)
+        .map_err(|e| ZatError::could_not_write_description(e.to_string()))?;
+
+    println!("{}", json);
+    Ok(())
+  }
+
+  /// Indents a file by the depth of its directory, showing only its name.
+  fn tree_line(file: &str) -> String {
+    let path = file.trim_end_matches('/');
+    let depth = path.matches('/').count();
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let suffix = if file.ends_with('/') { "/" } else { "" };
+
+    s!("{}{}{}", "  ".repeat(depth), name, suffix)
+  }
+
+  fn or_none(lines: Vec<String>) -> Vec<String> {
+    if lines.is_empty() {
+      vec!["-".to_owned()]
+    } else {
+      lines
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn tree_line_indents_files_by_depth() {
+    assert_eq!(DescribeTemplate::tree_line("README.md"), "README.md");
+    assert_eq!(DescribeTemplate::tree_line("src/"), "src/");
+    assert_eq!(DescribeTemplate::tree_line("src/main/app.rs.tmpl"), "    app.rs.tmpl")
+  }
+
+  #[test]
+  fn describes_the_files_that_would_be_processed_and_ignored() {
+    let repository_dir = RepositoryDir::new("./tests/examples/template-with-ignores/source");
+    let description = DescribeTemplate::describe(&repository_dir, &["^do-not-include.txt".to_owned(), "^wip/.*.txt".to_owned()]).unwrap();
+
+    assert_eq!(description.ignores, vec![".git/.*", "^do-not-include.txt", "^wip/.*.txt"]);
+    assert_eq!(description.ignored_files, vec!["do-not-include.txt", "wip/sample.txt", "wip/scratch.txt"]);
+    assert_eq!(description.files, vec!["README.md.tmpl", "wip/", "wip/checklist.md"]);
+    assert_eq!(description.shell_hook, None)
+  }
+
+  #[test]
+  fn describes_choices_and_scopes_of_variables() {
+    let repository_dir = RepositoryDir::new("./tests/examples/simple-with-scopes/source");
+    let description = DescribeTemplate::describe(&repository_dir, &[]).unwrap();
+
+    assert!(description.variables.iter().any(|variable| !variable.choices.is_empty()));
+    assert!(description.variables.iter().any(|variable| !variable.scopes.is_empty()))
+  }
+
+  #[test]
+  fn describes_lifecycle_hooks_and_actions() {
+    let repository_dir = RepositoryDir::new("./tests/examples/simple-with-lifecycle-hooks/source");
+    let description = DescribeTemplate::describe(&repository_dir, &[]).unwrap();
+
+    assert_eq!(
+      description.lifecycle_hooks,
+      vec![
+        "pre-generation: hooks/check-project.sh",
+        "post-generation: hooks/write-file.sh generated-by.txt",
+        "post-generation: hooks/write-file.sh long-readme.txt (include if choice: readme_type has value: long)",
+        "post-generation: hooks/write-file.sh short-readme.txt (include if choice: readme_type has value: short)",
+      ]
+    );
+
+    let repository_dir = RepositoryDir::new("./tests/examples/simple-with-post-processing-actions/source");
+    let description = DescribeTemplate::describe(&repository_dir, &[]).unwrap();
+
+    assert_eq!(description.actions.first().map(|action| action.as_str()), Some("chmod 755 bin/run.sh"))
+  }
+
+  #[test]
+  fn describe_fails_on_an_invalid_ignore() {
+    let repository_dir = RepositoryDir::new("./tests/examples/template-with-ignores/source");
+    let result = DescribeTemplate::describe(&repository_dir, &["[".to_owned()]);
+
+    assert!(matches!(result, Err(ZatError::DescribeCommandError(_))))
+  }
+}
@@ -0,0 +1,26 @@
+use crate::args::{GenerateArgs, UserConfigProvider};
+use crate::error::{ZatAction, ZatError};
+use crate::logging::Logger;
+use std::fs;
+use std::format as s;
+use super::ProcessTemplates;
+
+
+pub struct GenerateTemplates;
+
+impl GenerateTemplates {
+
+  /// Runs a generator of a Zat repository against an existing project. A generator is processed like a Zat repository,
+  /// except that its files are written into the existing project.
+  pub fn process_generator(config_provider: impl UserConfigProvider, generate_args: GenerateArgs) -> ZatAction {
+    let generator = generate_args.generator.clone();
+    let user_config = config_provider.get_generator_config(generate_args)?;
+
+    // The path prefix may not exist within the project yet
+    fs::create_dir_all(&user_config.target_dir.path)
+      .map_err(|e| ZatError::could_not_create_path_prefix(&user_config.target_dir.path, e.to_string()))?;
+
+    Logger::info(&s!("Running generator: {}", generator));
+    ProcessTemplates::process(user_config)
+  }
+}
@@ -0,0 +1,87 @@
+use std::format as s;
+use ansi_term::Colour::{Blue, Green};
+
+use crate::args::{AddTemplateArgs, RemoveTemplateArgs, TemplatesArgs, TemplatesCommand};
+use crate::config::ZatConfigDir;
+use crate::error::{ZatAction, ZatError};
+use crate::logging::Logger;
+use crate::registry::{RegisteredTemplate, TemplateRegistryFile, TemplateResolver};
+
+pub struct ManageTemplates;
+
+impl ManageTemplates {
+
+  pub fn process_templates(templates_args: TemplatesArgs) -> ZatAction {
+    let registry_file = TemplateRegistryFile::from(&ZatConfigDir::new()?);
+
+    match templates_args.command {
+      TemplatesCommand::List => Self::list(&registry_file),
+      TemplatesCommand::Add(add_template_args) => Self::add(&registry_file, add_template_args),
+      TemplatesCommand::Remove(remove_template_args) => Self::remove(&registry_file, remove_template_args),
+    }
+  }
+
+  fn list(registry_file: &TemplateRegistryFile) -> ZatAction {
+    let registry = registry_file.load()?;
+    let registry_path = registry_file.path().to_string_lossy();
+
+    if registry.templates.is_empty() {
+      Logger::info(&s!("There are no templates registered in '{}'. Add one with `zat templates add --name <NAME> --url <REPOSITORY_URL>`.", registry_path));
+    } else {
+      Logger::info(&s!("Templates registered in '{}':", registry_path));
+
+      let templates: Vec<String> =
+        registry
+          .templates
+          .iter()
+          .map(|(name, template)| s!("  {} -> {}{}", Blue.paint(name), Green.paint(&template.url), template_details(template)))
+          .collect();
+
+      Logger::coloured(&templates.join("\n"));
+    }
+
+    Ok(())
+  }
+
+  fn add(registry_file: &TemplateRegistryFile, add_template_args: AddTemplateArgs) -> ZatAction {
+    let mut registry = registry_file.load()?;
+    let name = add_template_args.name;
+
+    if registry.templates.contains_key(&name) {
+      return Err(ZatError::template_already_exists_in_registry(&name, &registry_file.path().to_string_lossy()))
+    }
+
+    // Validate shorthands up front, instead of when the template is used
+    let _ = TemplateResolver::expand_url(&add_template_args.url)?;
+
+    let template = RegisteredTemplate::new(&add_template_args.url, add_template_args.git_ref.as_deref(), add_template_args.subdir.as_deref());
+
+    let registered = s!("Registered template '{}' -> {}{}", name, template.url, template_details(&template));
+
+    registry.templates.insert(name, template);
+    registry_file.save(&registry)?;
+    Logger::info(&registered);
+    Ok(())
+  }
+
+  fn remove(registry_file: &TemplateRegistryFile, remove_template_args: RemoveTemplateArgs) -> ZatAction {
+    let mut registry = registry_file.load()?;
+    let name = remove_template_args.name;
+
+    match registry.templates.remove(&name) {
+      Some(_) => {
+        registry_file.save(&registry)?;
+        Logger::info(&s!("Removed template '{}'", name));
+        Ok(())
+      },
+      None => Err(ZatError::template_not_found_in_registry(&name, &registry_file.path().to_string_lossy()))
+    }
+  }
+}
+
+fn template_details(template: &RegisteredTemplate) -> String {
+  let git_ref = template.git_ref.as_ref().map(|r| s!(" ref={}", r)).unwrap_or_default();
+  let subdir = template.subdir.as_ref().map(|d| s!(" subdir={}", d)).unwrap_or_default();
+
+  s!("{}{}", git_ref, subdir)
+}
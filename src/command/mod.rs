@@ -1,7 +1,24 @@
 pub mod bootstrap_project;
+pub mod bootstrap_repository;
 pub mod process_templates;
 pub mod process_remote_templates;
+pub mod process_archive_templates;
+pub mod generate_templates;
+pub mod templatize_project;
+pub mod test_templates;
+pub mod describe_template;
+pub mod validate_template;
+pub mod manage_templates;
+pub mod show_config;
 
 pub use bootstrap_project::BootstrapProject;
 pub use process_templates::ProcessTemplates;
 pub use process_remote_templates::ProcessRemoteTemplates;
+pub use process_archive_templates::ProcessArchiveTemplates;
+pub use generate_templates::GenerateTemplates;
+pub use templatize_project::TemplatizeProject;
+pub use test_templates::TestTemplates;
+pub use describe_template::DescribeTemplate;
+pub use validate_template::ValidateTemplate;
+pub use manage_templates::ManageTemplates;
+pub use show_config::ShowConfig;
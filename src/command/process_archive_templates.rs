@@ -0,0 +1,406 @@
+use crate::config::{RepositoryDir, RepositoryOrigin, UserConfig};
+use crate::error::{ZatAction, ZatError, ZatResult};
+use crate::args::{ProcessArchiveTemplatesArgs, ProcessTemplatesArgs, UserConfigProvider};
+use crate::logging::Logger;
+use crate::plugin::BundledPlugins;
+use flate2::read::GzDecoder;
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::format as s;
+use tempfile::TempDir;
+use url::Url;
+use super::ProcessTemplates;
+
+
+pub struct ProcessArchiveTemplates;
+
+#[derive(Debug, Clone, PartialEq)]
+enum ArchiveType {
+  TarGz,
+  Zip
+}
+
+impl ProcessArchiveTemplates {
+
+  pub fn process_archive(config_provider: impl UserConfigProvider, process_archive_template_args: ProcessArchiveTemplatesArgs) -> ZatAction {
+    let archive_path = Self::get_archive_path(&process_archive_template_args.archive)?;
+    let archive_type = Self::get_archive_type(&archive_path)?;
+    let origin = Self::get_archive_origin(&archive_path)?;
+    let extraction_directory: TempDir = Self::create_extraction_directory(&archive_path)?;
+
+    let extraction_directory_path = extraction_directory.path().to_string_lossy().to_string();
+    extract_archive(&archive_path, &archive_type, extraction_directory.path())?;
+    let repository_directory = find_repository_directory(extraction_directory.path())?;
+    BundledPlugins::make_executable(&repository_directory);
+
+    // Invoke the regular ProcessTemplates::process at this point
+    let process_template_args = create_process_templates_args(repository_directory, process_archive_template_args);
+    let result =
+      config_provider
+        .get_user_config(process_template_args)
+        // Plugins and shell hooks are only run once the user trusts the content of this archive
+        .and_then(|user_config| ProcessTemplates::process(UserConfig { origin, ..user_config }));
+
+    extraction_directory
+      .close()
+      .unwrap_or_else(|e| Logger::warn(&s!("Could not remove temporary folder '{}', reason: {}", extraction_directory_path, e)));
+
+    result
+  }
+
+
+  /// Archives can be supplied as a local path or as a 'file://' URL.
+  fn get_archive_path(archive: &str) -> ZatResult<PathBuf> {
+    let archive_path =
+      if archive.starts_with("file://") {
+        let url = Url::parse(archive)
+          .map_err(|e| ZatError::invalid_archive_url(e.to_string(), archive))?;
+
+        url
+          .to_file_path()
+          .map_err(|_| ZatError::invalid_archive_url("The URL does not have a valid local file path.".to_owned(), archive))?
+      } else {
+        PathBuf::from(archive)
+      };
+
+    if archive_path.is_file() {
+      Ok(archive_path)
+    } else {
+      Err(ZatError::archive_does_not_exist(&archive_path.to_string_lossy()))
+    }
+  }
+
+
+  fn get_archive_type(archive_path: &Path) -> ZatResult<ArchiveType> {
+    let file_name =
+      archive_path
+        .file_name()
+        .map(|f| f.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    if file_name.ends_with(".tar.gz") || file_name.ends_with(".tgz") {
+      Ok(ArchiveType::TarGz)
+    } else if file_name.ends_with(".zip") {
+      Ok(ArchiveType::Zip)
+    } else {
+      Err(ZatError::unsupported_archive_format(&archive_path.to_string_lossy()))
+    }
+  }
+
+
+  /// Archives are identified by their path and the digest of their content, so a changed archive is reviewed again.
+  fn get_archive_origin(archive_path: &Path) -> ZatResult<RepositoryOrigin> {
+    let archive = archive_path.to_string_lossy().to_string();
+    let content = fs::read(archive_path).map_err(|e| ZatError::could_not_read_archive(e.to_string(), &archive))?;
+    let sha256 = Sha256::digest(&content).iter().map(|byte| s!("{:02x}", byte)).collect();
+
+    let path =
+      fs::canonicalize(archive_path)
+        .map(|path| path.to_string_lossy().to_string())
+        .unwrap_or(archive);
+
+    Ok(RepositoryOrigin::Archive { path, sha256 })
+  }
+
+
+  fn create_extraction_directory(archive_path: &Path) -> ZatResult<TempDir> {
+    let archive_name =
+      archive_path
+        .file_name()
+        .map(|f| f.to_string_lossy().replace('.', "_"))
+        .unwrap_or_default();
+
+    let extraction_path = s!("zat-archive-{}_", &archive_name);
+
+    tempfile::Builder::new()
+      .prefix(&extraction_path)
+      .tempdir()
+      .map_err(|e| ZatError::could_not_create_extraction_directory(e.to_string()))
+  }
+}
+
+fn create_process_templates_args(repository_directory: RepositoryDir, process_archive_templates_args: ProcessArchiveTemplatesArgs) -> ProcessTemplatesArgs {
+  ProcessTemplatesArgs {
+    repository_dir: vec![repository_directory.path().to_owned()],
+    target_dir: process_archive_templates_args.target_dir,
+    ignores: process_archive_templates_args.ignores,
+    verbose: process_archive_templates_args.verbose,
+    no_verbose: process_archive_templates_args.no_verbose,
+    choice_menu_style: process_archive_templates_args.choice_menu_style,
+    on_collision: None,
+    no_plugins: process_archive_templates_args.no_plugins,
+    no_plugin_cache: process_archive_templates_args.no_plugin_cache,
+    no_hooks: process_archive_templates_args.no_hooks,
+    hook_timeout: process_archive_templates_args.hook_timeout
+  }
+}
+
+/// Verifies every entry of the archive stays within the extraction directory before extracting anything.
+fn extract_archive(archive_path: &Path, archive_type: &ArchiveType, extraction_directory: &Path) -> ZatAction {
+  let archive = archive_path.to_string_lossy().to_string();
+  let extraction_path = extraction_directory.to_string_lossy().to_string();
+
+  let open_archive = || File::open(archive_path).map_err(|e| ZatError::could_not_read_archive(e.to_string(), &archive));
+
+  match archive_type {
+    ArchiveType::TarGz => {
+      let mut tar_archive = tar::Archive::new(GzDecoder::new(open_archive()?));
+      let entries = tar_archive.entries().map_err(|e| ZatError::could_not_read_archive(e.to_string(), &archive))?;
+
+      for entry_result in entries {
+        let entry = entry_result.map_err(|e| ZatError::could_not_read_archive(e.to_string(), &archive))?;
+        let entry_path = entry.path().map_err(|e| ZatError::could_not_read_archive(e.to_string(), &archive))?.to_path_buf();
+        verify_entry_path(&entry_path, &archive)?;
+
+        if let Some(link_path) = entry.link_name().map_err(|e| ZatError::could_not_read_archive(e.to_string(), &archive))? {
+          verify_link_path(&entry_path, &link_path, &archive)?;
+        }
+      }
+
+      tar::Archive::new(GzDecoder::new(open_archive()?))
+        .unpack(extraction_directory)
+        .map_err(|e| ZatError::could_not_extract_archive(e.to_string(), &archive, &extraction_path))
+    },
+
+    ArchiveType::Zip => {
+      let mut zip_archive = zip::ZipArchive::new(open_archive()?).map_err(|e| ZatError::could_not_read_archive(e.to_string(), &archive))?;
+
+      for index in 0..zip_archive.len() {
+        let mut entry = zip_archive.by_index(index).map_err(|e| ZatError::could_not_read_archive(e.to_string(), &archive))?;
+        let entry_path = PathBuf::from(entry.name());
+        verify_entry_path(&entry_path, &archive)?;
+
+        // The target of a symlink is stored as the content of its entry
+        if entry.is_symlink() {
+          let mut link_path = String::new();
+          entry.read_to_string(&mut link_path).map_err(|e| ZatError::could_not_read_archive(e.to_string(), &archive))?;
+          verify_link_path(&entry_path, Path::new(&link_path), &archive)?;
+        }
+      }
+
+      zip_archive
+        .extract(extraction_directory)
+        .map_err(|e| ZatError::could_not_extract_archive(e.to_string(), &archive, &extraction_path))
+    },
+  }
+}
+
+fn verify_entry_path(entry_path: &Path, archive: &str) -> ZatAction {
+  let escapes =
+    entry_path
+      .components()
+      .any(|c| matches!(c, Component::ParentDir | Component::RootDir | Component::Prefix(_)));
+
+  if escapes {
+    Err(ZatError::archive_entry_escapes_extraction_directory(&entry_path.to_string_lossy(), archive))
+  } else {
+    Ok(())
+  }
+}
+
+/// Links are resolved relative to the directory of the entry, so we track how deep the link stays.
+fn verify_link_path(entry_path: &Path, link_path: &Path, archive: &str) -> ZatAction {
+  let entry_depth = entry_path.components().count().saturating_sub(1) as i64;
+
+  let link_depth =
+    link_path
+      .components()
+      .try_fold(entry_depth, |depth, component| {
+        match component {
+          Component::Normal(_) => Some(depth + 1),
+          Component::CurDir => Some(depth),
+          Component::ParentDir if depth > 0 => Some(depth - 1),
+          _ => None,
+        }
+      });
+
+  match link_depth {
+    Some(_) => Ok(()),
+    None => Err(ZatError::archive_entry_escapes_extraction_directory(&s!("{} -> {}", entry_path.to_string_lossy(), link_path.to_string_lossy()), archive))
+  }
+}
+
+/// Archives of a repository usually contain a single top-level folder (eg. 'my-template-1.0.0/'), which is skipped.
+fn find_repository_directory(extraction_directory: &Path) -> ZatResult<RepositoryDir> {
+  let extraction_path = extraction_directory.to_string_lossy().to_string();
+
+  let entries =
+    fs::read_dir(extraction_directory)
+      .and_then(|entries| entries.collect::<Result<Vec<_>, _>>())
+      .map_err(|e| ZatError::could_not_extract_archive(e.to_string(), &extraction_path, &extraction_path))?;
+
+  match entries.as_slice() {
+    [single_entry] if single_entry.path().is_dir() => Ok(RepositoryDir::from(single_entry.path().as_path())),
+    _ => Ok(RepositoryDir::from(extraction_directory))
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::assert_error_with;
+  use crate::error::process_archive_command_error_reason::ProcessArchiveCommandErrorReason;
+  use flate2::write::GzEncoder;
+  use flate2::Compression;
+  use pretty_assertions::assert_eq;
+  use std::io::Write;
+  use tempfile::TempDir;
+
+  #[test]
+  fn get_archive_type_supports_tar_gz_and_zip() {
+    assert_eq!(ProcessArchiveTemplates::get_archive_type(Path::new("/tmp/template.tar.gz")), Ok(ArchiveType::TarGz));
+    assert_eq!(ProcessArchiveTemplates::get_archive_type(Path::new("/tmp/template.TGZ")), Ok(ArchiveType::TarGz));
+    assert_eq!(ProcessArchiveTemplates::get_archive_type(Path::new("/tmp/template.zip")), Ok(ArchiveType::Zip));
+  }
+
+  #[test]
+  fn get_archive_type_fails_on_unsupported_formats() {
+    assert_error_with!(
+      ProcessArchiveTemplates::get_archive_type(Path::new("/tmp/template.tar.bz2")),
+      Err(ZatError::ProcessArchiveCommandError(ProcessArchiveCommandErrorReason::UnsupportedArchiveFormat(error, _))) => error,
+      |error: String| assert!(error.starts_with("The archive '/tmp/template.tar.bz2' is not in a supported format."), "unexpected error: {}", error)
+    )
+  }
+
+  #[test]
+  fn get_archive_path_accepts_file_urls() {
+    let archive_dir = TempDir::new().unwrap();
+    let archive_path = archive_dir.path().join("template.zip");
+    fs::write(&archive_path, b"").unwrap();
+
+    let url = Url::from_file_path(&archive_path).unwrap();
+
+    assert_eq!(ProcessArchiveTemplates::get_archive_path(url.as_str()), Ok(archive_path.clone()));
+    assert_eq!(ProcessArchiveTemplates::get_archive_path(&archive_path.to_string_lossy()), Ok(archive_path))
+  }
+
+  #[test]
+  fn get_archive_path_fails_if_archive_does_not_exist() {
+    assert_error_with!(
+      ProcessArchiveTemplates::get_archive_path("file:///does/not/exist.zip"),
+      Err(ZatError::ProcessArchiveCommandError(ProcessArchiveCommandErrorReason::ArchiveDoesNotExist(error, _))) => error,
+      |error: String| assert!(error.starts_with("The archive '/does/not/exist.zip' does not exist."), "unexpected error: {}", error)
+    )
+  }
+
+  #[test]
+  fn extracts_tar_gz_and_strips_top_level_folder() {
+    let archive_dir = TempDir::new().unwrap();
+    let archive_path = archive_dir.path().join("template.tar.gz");
+    write_tar_gz(&archive_path, &[("my-template/.variables.zat-prompt", "[]"), ("my-template/template/README.md", "readme")]);
+
+    let extraction_dir = TempDir::new().unwrap();
+    extract_archive(&archive_path, &ArchiveType::TarGz, extraction_dir.path()).unwrap();
+    let repository_dir = find_repository_directory(extraction_dir.path()).unwrap();
+
+    assert_eq!(repository_dir, RepositoryDir::from(extraction_dir.path().join("my-template").as_path()));
+    assert_eq!(fs::read_to_string(repository_dir.join("template").join("README.md")).unwrap(), "readme")
+  }
+
+  #[test]
+  fn extracts_zip_without_top_level_folder() {
+    let archive_dir = TempDir::new().unwrap();
+    let archive_path = archive_dir.path().join("template.zip");
+    write_zip(&archive_path, &[(".variables.zat-prompt", "[]"), ("template/README.md", "readme")]);
+
+    let extraction_dir = TempDir::new().unwrap();
+    extract_archive(&archive_path, &ArchiveType::Zip, extraction_dir.path()).unwrap();
+    let repository_dir = find_repository_directory(extraction_dir.path()).unwrap();
+
+    assert_eq!(repository_dir, RepositoryDir::from(extraction_dir.path()));
+    assert_eq!(fs::read_to_string(repository_dir.join("template").join("README.md")).unwrap(), "readme")
+  }
+
+  #[test]
+  fn rejects_zip_entries_that_escape_the_extraction_directory() {
+    let archive_dir = TempDir::new().unwrap();
+    let archive_path = archive_dir.path().join("template.zip");
+    write_zip(&archive_path, &[("template/README.md", "readme"), ("../escaped.txt", "gotcha")]);
+
+    let extraction_dir = TempDir::new().unwrap();
+
+    assert_error_with!(
+      extract_archive(&archive_path, &ArchiveType::Zip, extraction_dir.path()),
+      Err(ZatError::ProcessArchiveCommandError(ProcessArchiveCommandErrorReason::ArchiveEntryEscapesExtractionDirectory(error, _))) => error,
+      |error: String| assert!(error.contains("has an entry '../escaped.txt'"), "unexpected error: {}", error)
+    );
+
+    // Nothing should be extracted when any entry is invalid
+    assert!(!extraction_dir.path().join("template").exists())
+  }
+
+  #[test]
+  fn rejects_tar_gz_entries_that_escape_the_extraction_directory() {
+    let archive_dir = TempDir::new().unwrap();
+    let archive_path = archive_dir.path().join("template.tar.gz");
+    write_tar_gz(&archive_path, &[("template/README.md", "readme"), ("../escaped.txt", "gotcha")]);
+
+    let extraction_dir = TempDir::new().unwrap();
+
+    assert_error_with!(
+      extract_archive(&archive_path, &ArchiveType::TarGz, extraction_dir.path()),
+      Err(ZatError::ProcessArchiveCommandError(ProcessArchiveCommandErrorReason::ArchiveEntryEscapesExtractionDirectory(error, _))) => error,
+      |error: String| assert!(error.contains("has an entry '../escaped.txt'"), "unexpected error: {}", error)
+    )
+  }
+
+  #[test]
+  fn rejects_zip_symlinks_that_escape_the_extraction_directory() {
+    let archive_dir = TempDir::new().unwrap();
+    let archive_path = archive_dir.path().join("template.zip");
+
+    let mut writer = zip::ZipWriter::new(File::create(&archive_path).unwrap());
+    writer.start_file("template/README.md", zip::write::SimpleFileOptions::default()).unwrap();
+    writer.write_all(b"readme").unwrap();
+    writer.add_symlink("template/passwd", "../../etc/passwd", zip::write::SimpleFileOptions::default()).unwrap();
+    writer.finish().unwrap();
+
+    let extraction_dir = TempDir::new().unwrap();
+
+    assert_error_with!(
+      extract_archive(&archive_path, &ArchiveType::Zip, extraction_dir.path()),
+      Err(ZatError::ProcessArchiveCommandError(ProcessArchiveCommandErrorReason::ArchiveEntryEscapesExtractionDirectory(error, _))) => error,
+      |error: String| assert!(error.contains("has an entry 'template/passwd -> ../../etc/passwd'"), "unexpected error: {}", error)
+    );
+
+    assert!(!extraction_dir.path().join("template").exists())
+  }
+
+  #[test]
+  fn verify_link_path_rejects_links_outside_the_extraction_directory() {
+    assert_eq!(verify_link_path(Path::new("template/link"), Path::new("../README.md"), "archive.tar.gz"), Ok(()));
+    assert!(verify_link_path(Path::new("template/link"), Path::new("../../etc/passwd"), "archive.tar.gz").is_err());
+    assert!(verify_link_path(Path::new("link"), Path::new("/etc/passwd"), "archive.tar.gz").is_err())
+  }
+
+  fn write_tar_gz(archive_path: &Path, files: &[(&str, &str)]) {
+    let encoder = GzEncoder::new(File::create(archive_path).unwrap(), Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+
+    for (name, content) in files {
+      let mut header = tar::Header::new_gnu();
+      // Write the name directly, as `Header::set_path` refuses paths with '..'
+      header.as_gnu_mut().unwrap().name[..name.len()].copy_from_slice(name.as_bytes());
+      header.set_size(content.len() as u64);
+      header.set_mode(0o644);
+      header.set_cksum();
+      builder.append(&header, content.as_bytes()).unwrap();
+    }
+
+    builder.into_inner().unwrap().finish().unwrap();
+  }
+
+  fn write_zip(archive_path: &Path, files: &[(&str, &str)]) {
+    let mut writer = zip::ZipWriter::new(File::create(archive_path).unwrap());
+
+    for (name, content) in files {
+      writer.start_file(*name, zip::write::SimpleFileOptions::default()).unwrap();
+      writer.write_all(content.as_bytes()).unwrap();
+    }
+
+    writer.finish().unwrap();
+  }
+}
@@ -1,50 +1,134 @@
-use crate::config::RepositoryDir;
+use crate::config::{RemoteRepository, RepositoryDir, RepositoryOrigin, UserConfig};
 use crate::error::{ZatAction, ZatError, ZatResult};
 use crate::args::{ProcessRemoteTemplatesArgs, ProcessTemplatesArgs, UserConfigProvider};
 use crate::logging::Logger;
+use crate::plugin::BundledPlugins;
 use std::process::Command;
 use std::format as s;
 use tempfile::TempDir;
 use url::Url;
+use regex::Regex;
 use std::fs;
+use std::path::{Component, Path};
 use super::ProcessTemplates;
 
 
 pub struct ProcessRemoteTemplates;
 
+const SSH_BATCH_MODE_COMMAND: &str = "ssh -o BatchMode=yes";
 
-impl ProcessRemoteTemplates {
 
-  pub fn process_remote(config_provider: impl UserConfigProvider, process_remote_template_args : ProcessRemoteTemplatesArgs) -> ZatAction {
-    let checkout_directory: TempDir = Self::create_checkout_directory(&process_remote_template_args.repository_url)?;
+impl ProcessRemoteTemplates {
 
-    let checkout_directory_path = checkout_directory.path().to_string_lossy().to_string();
-    let repository_directory = RepositoryDir::new(&checkout_directory_path);
-    clone_git_repository(&process_remote_template_args, &repository_directory)?;
+  pub fn process_remote(config_provider: impl UserConfigProvider, remote_repository: RemoteRepository, process_remote_template_args : ProcessRemoteTemplatesArgs) -> ZatAction {
+    let (checkout_directory, repository_directory) = Self::checkout(&remote_repository)?;
 
     // Invoke the regular ProcessTemplates::process at this point
-    let process_template_args = create_process_templates_args(repository_directory, process_remote_template_args);
-    let user_config = config_provider.get_user_config(process_template_args)?;
-    let result = ProcessTemplates::process(user_config);
+    let result =
+      Self::head_commit(&remote_repository.url, &RepositoryDir::from(checkout_directory.path()))
+        .and_then(|commit| {
+          let process_template_args = create_process_templates_args(repository_directory, process_remote_template_args);
+          let user_config = config_provider.get_user_config(process_template_args)?;
 
-    checkout_directory
-      .close()
-      .unwrap_or_else(|e| Logger::warn(&s!("Could not remove temporary folder '{}', reason: {}", checkout_directory_path, e)));
+          // Plugins and shell hooks are only run once the user trusts this commit of the repository
+          let origin = RepositoryOrigin::Remote { url: remote_repository.url.clone(), commit };
+          ProcessTemplates::process(UserConfig { origin, ..user_config })
+        });
+
+    Self::remove_temporary_directory(checkout_directory);
 
     result
   }
 
+  /// Clones the remote repository into a temporary directory and returns it, along with the directory of the Zat
+  /// repository within it. The checkout is removed when the temporary directory is dropped.
+  pub fn checkout(remote_repository: &RemoteRepository) -> ZatResult<(TempDir, RepositoryDir)> {
+    if let Some(subdir) = &remote_repository.subdir {
+      Self::verify_subdir(subdir, &remote_repository.url)?
+    }
 
-  fn create_checkout_directory(repository_url: &str) -> ZatResult<TempDir> {
-    let url = Url::parse(repository_url)
-      .map_err(|e| ZatError::invalid_remote_repository_url(e.to_string(), repository_url))?;
+    if let Some(git_ref) = &remote_repository.git_ref {
+      Self::verify_git_ref(git_ref, &remote_repository.url)?
+    }
 
-    let hostname = &url.host_str().ok_or_else(|| ZatError::unsupported_hostname(url.as_str()))?;
-    let path = &url.path();
+    let checkout_directory: TempDir = Self::create_checkout_directory(&remote_repository.url)?;
 
-    // We can't use Path to join the pieces here, because the 'path' segment has a leading '/' which
-    // clears the rest of the path. This is documented in Path.join.
-    let repository_path = s!("zat-{}{}_", &hostname, &path.replace('/', "_"));
+    let checkout_directory_path = checkout_directory.path().to_string_lossy().to_string();
+    let checkout_repository_directory = RepositoryDir::new(&checkout_directory_path);
+    clone_git_repository(&remote_repository.url, &checkout_repository_directory)?;
+
+    if let Some(git_ref) = &remote_repository.git_ref {
+      checkout_git_ref(&remote_repository.url, git_ref, &checkout_repository_directory)?
+    }
+
+    // The Zat repository may live in a folder within the Git repository
+    let repository_directory =
+      match &remote_repository.subdir {
+        Some(subdir) => RepositoryDir::new(&checkout_directory.path().join(subdir).to_string_lossy()),
+        None => checkout_repository_directory
+      };
+
+    BundledPlugins::make_executable(&repository_directory);
+
+    Ok((checkout_directory, repository_directory))
+  }
+
+  /// The subdirectory is joined to the checkout directory, so it can't be absolute or contain '..', which would point
+  /// outside the clone.
+  fn verify_subdir(subdir: &str, repository_url: &str) -> ZatAction {
+    let is_within_repository =
+      Path::new(subdir)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+
+    if is_within_repository {
+      Ok(())
+    } else {
+      Err(ZatError::remote_repository_subdir_is_outside_repository(subdir, repository_url))
+    }
+  }
+
+  /// The ref is passed to `git checkout`, so it can't start with a '-', which Git would read as an option.
+  fn verify_git_ref(git_ref: &str, repository_url: &str) -> ZatAction {
+    if git_ref.starts_with('-') {
+      Err(ZatError::remote_repository_git_ref_is_invalid(git_ref, repository_url))
+    } else {
+      Ok(())
+    }
+  }
+
+  /// The commit checked out in a clone of the remote repository.
+  pub fn head_commit(repository_url: &str, checkout_repository_dir: &RepositoryDir) -> ZatResult<String> {
+    let program = s!("git -C {} rev-parse HEAD", checkout_repository_dir.path());
+
+    let output =
+      Command::new("git")
+        .arg("-C")
+        .arg(checkout_repository_dir.path())
+        .arg("rev-parse")
+        .arg("HEAD")
+        .output()
+        .map_err(|e| ZatError::git_commit_error(e.to_string(), &program, repository_url))?;
+
+    if output.status.success() {
+      Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    } else {
+      Err(ZatError::git_commit_error(String::from_utf8_lossy(&output.stderr).trim().to_owned(), &program, repository_url))
+    }
+  }
+
+  pub fn remove_temporary_directory(temporary_directory: TempDir) {
+    let temporary_directory_path = temporary_directory.path().to_string_lossy().to_string();
+
+    temporary_directory
+      .close()
+      .unwrap_or_else(|e| Logger::warn(&s!("Could not remove temporary folder '{}', reason: {}", temporary_directory_path, e)));
+  }
+
+
+  fn create_checkout_directory(repository_url: &str) -> ZatResult<TempDir> {
+    let remote_url = RemoteRepositoryUrl::parse(repository_url)?;
+    let repository_path = remote_url.checkout_prefix();
 
     let checkout_dir =
       tempfile::Builder::new()
@@ -53,48 +137,275 @@ impl ProcessRemoteTemplates {
         .map_err(|e| ZatError::could_not_create_checkout_directory(e.to_string()))?;
 
     fs::create_dir_all(checkout_dir.path())
-      .map_err(|e| ZatError::could_not_create_checkout_directory_structure(e.to_string(), &repository_path, &url))
+      .map_err(|e| ZatError::could_not_create_checkout_directory_structure(e.to_string(), &repository_path, repository_url))
       .map(|_| {
         checkout_dir
       })
   }
 }
 
+
+/// Whether the supplied repository is a Git URL supported by `process-remote`, rather than a local path.
+pub fn is_remote_url(repository: &str) -> bool {
+  RemoteRepositoryUrl::parse(repository).is_ok()
+}
+
+
+/// The forms of Git URL supported by `process-remote`.
+#[derive(Debug, Clone, PartialEq)]
+enum RemoteRepositoryUrl {
+  /// URLs with a hostname. Eg. https://github.com/ssanj/zat or ssh://git@github.com/ssanj/zat.git
+  Hosted { scheme: String, hostname: String, path: String },
+
+  /// scp-style SSH URLs with a user. Eg. git@github.com:ssanj/zat.git
+  ScpLike { hostname: String, path: String },
+
+  /// Local repositories, usually bare. Eg. file:///srv/templates/tmpl.git
+  File { path: String },
+}
+
+impl RemoteRepositoryUrl {
+
+  fn parse(repository_url: &str) -> ZatResult<Self> {
+    // scp-style URLs are not valid URLs, so they need to be matched before parsing. The user is required so that URLs
+    // such as 'data:text/plain' are not mistaken for a hostname and path.
+    let scp_like = Regex::new(r"^[^@/:]+@([^@/:]+):(.+)$").expect("Invalid scp-style URL regex");
+
+    if !repository_url.contains("://") {
+      if let Some(captures) = scp_like.captures(repository_url) {
+        return Ok(
+          RemoteRepositoryUrl::ScpLike {
+            hostname: captures[1].to_owned(),
+            path: captures[2].to_owned()
+          }
+        )
+      }
+    }
+
+    let url = Url::parse(repository_url)
+      .map_err(|e| ZatError::invalid_remote_repository_url(e.to_string(), repository_url))?;
+
+    if url.scheme() == "file" {
+      Ok(RemoteRepositoryUrl::File { path: url.path().to_owned() })
+    } else {
+      let hostname = url.host_str().ok_or_else(|| ZatError::unsupported_hostname(url.as_str()))?;
+
+      Ok(
+        RemoteRepositoryUrl::Hosted {
+          scheme: url.scheme().to_owned(),
+          hostname: hostname.to_owned(),
+          path: url.path().to_owned()
+        }
+      )
+    }
+  }
+
+  fn checkout_prefix(&self) -> String {
+    // We can't use Path to join the pieces here, because the 'path' segment has a leading '/' which
+    // clears the rest of the path. This is documented in Path.join.
+    let (origin, path) = match self {
+      RemoteRepositoryUrl::Hosted { hostname, path, .. } => (hostname.as_str(), path.clone()),
+      RemoteRepositoryUrl::ScpLike { hostname, path } => (hostname.as_str(), s!("/{}", path.trim_start_matches('/'))),
+      RemoteRepositoryUrl::File { path } => ("file", path.clone()),
+    };
+
+    let sanitised_path: String =
+      path
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+
+    s!("zat-{}{}_", origin, sanitised_path)
+  }
+
+  fn uses_ssh(&self) -> bool {
+    match self {
+      RemoteRepositoryUrl::Hosted { scheme, .. } => scheme == "ssh" || scheme == "git+ssh",
+      RemoteRepositoryUrl::ScpLike { .. } => true,
+      RemoteRepositoryUrl::File { .. } => false,
+    }
+  }
+}
+
 fn create_process_templates_args(repository_directory: RepositoryDir, process_remote_templates_args: ProcessRemoteTemplatesArgs) -> ProcessTemplatesArgs {
   ProcessTemplatesArgs {
-    repository_dir: repository_directory.path().to_owned(),
+    repository_dir: vec![repository_directory.path().to_owned()],
     target_dir: process_remote_templates_args.target_dir,
     ignores: process_remote_templates_args.ignores,
     verbose: process_remote_templates_args.verbose,
-    choice_menu_style: process_remote_templates_args.choice_menu_style
+    no_verbose: process_remote_templates_args.no_verbose,
+    choice_menu_style: process_remote_templates_args.choice_menu_style,
+    on_collision: None,
+    no_plugins: process_remote_templates_args.no_plugins,
+    no_plugin_cache: process_remote_templates_args.no_plugin_cache,
+    no_hooks: process_remote_templates_args.no_hooks,
+    hook_timeout: process_remote_templates_args.hook_timeout
   }
 }
 
-fn clone_git_repository(process_remote_template_args: &ProcessRemoteTemplatesArgs, repository_dir: &RepositoryDir) -> ZatAction {
+fn clone_git_repository(repository_url: &str, repository_dir: &RepositoryDir) -> ZatAction {
+  // GIT_TERMINAL_PROMPT only stops Git from prompting. SSH prompts for passwords and passphrases itself, so we also run
+  // it in batch mode, unless the user has supplied their own SSH command.
+  let ssh_batch_mode =
+    RemoteRepositoryUrl::parse(repository_url)?.uses_ssh() && std::env::var_os("GIT_SSH_COMMAND").is_none();
 
-  let status_result =
-    Command::new("git")
-      .env("GIT_TERMINAL_PROMPT" , "0")
-      .arg("clone")
-      .arg(&process_remote_template_args.repository_url)
-      .arg(repository_dir.path())
-      .status();
+  let env_string =
+    if ssh_batch_mode {
+      s!("GIT_TERMINAL_PROMPT=0 GIT_SSH_COMMAND=\"{}\"", SSH_BATCH_MODE_COMMAND)
+    } else {
+      "GIT_TERMINAL_PROMPT=0".to_owned()
+    };
+
+  let mut command = Command::new("git");
 
-  let program = s!("GIT_TERMINAL_PROMPT=0 git clone {} {}", &process_remote_template_args.repository_url, &repository_dir.path());
+  command
+    .env("GIT_TERMINAL_PROMPT" , "0")
+    .arg("clone")
+    .arg(repository_url)
+    .arg(repository_dir.path());
+
+  if ssh_batch_mode {
+    command.env("GIT_SSH_COMMAND", SSH_BATCH_MODE_COMMAND);
+  }
+
+  let status_result = command.status();
+
+  let program = s!("{} git clone {} {}", &env_string, repository_url, &repository_dir.path());
 
   let status = status_result.map_err(|e| {
-    ZatError::git_clone_error(e.to_string(), &program, &process_remote_template_args.repository_url, repository_dir.path())
+    ZatError::git_clone_error(e.to_string(), &program, repository_url, repository_dir.path())
   })?;
 
   // TODO: Write a function to generate this from Command.
-  let program_2 = s!("GIT_TERMINAL_PROMPT=0 git clone {}", &process_remote_template_args.repository_url);
+  let program_2 = s!("{} git clone {}", &env_string, repository_url);
 
   if !status.success() {
     Err(
-      ZatError::git_clone_status_error(status.code(), &program_2, &process_remote_template_args.repository_url)
+      ZatError::git_clone_status_error(status.code(), &program_2, repository_url)
     )
   } else {
     Ok(())
   }
 }
 
+fn checkout_git_ref(repository_url: &str, git_ref: &str, repository_dir: &RepositoryDir) -> ZatAction {
+  let program = s!("git -C {} checkout --quiet {}", repository_dir.path(), git_ref);
+
+  let status =
+    Command::new("git")
+      .arg("-C")
+      .arg(repository_dir.path())
+      .arg("checkout")
+      .arg("--quiet")
+      .arg(git_ref)
+      .status()
+      .map_err(|e| ZatError::git_checkout_error(e.to_string(), &program, git_ref))?;
+
+  if !status.success() {
+    Err(
+      ZatError::git_checkout_status_error(status.code(), git_ref, repository_url)
+    )
+  } else {
+    Ok(())
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::assert_error_with;
+  use crate::error::process_remote_command_error_reason::ProcessRemoteCommandErrorReason;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn only_accepts_subdirs_within_the_repository() {
+    let url = "https://github.com/ssanj/zat";
+
+    assert_eq!(ProcessRemoteTemplates::verify_subdir("templates/cli", url), Ok(()));
+    assert_eq!(ProcessRemoteTemplates::verify_subdir("./cli", url), Ok(()));
+
+    for subdir in ["../..", "cli/../../other", "/etc"] {
+      assert_error_with!(
+        ProcessRemoteTemplates::verify_subdir(subdir, url),
+        Err(ZatError::ProcessRemoteCommandError(ProcessRemoteCommandErrorReason::SubdirIsOutsideRepository(error, _))) => error,
+        |error: String| assert!(error.contains(subdir), "{}", error)
+      )
+    }
+  }
+
+  #[test]
+  fn only_accepts_refs_that_are_not_options() {
+    let url = "https://github.com/ssanj/zat";
+
+    assert_eq!(ProcessRemoteTemplates::verify_git_ref("v1.2.0", url), Ok(()));
+    assert_eq!(ProcessRemoteTemplates::verify_git_ref("feature/new-cli", url), Ok(()));
+
+    for git_ref in ["--orphan=main", "-b"] {
+      assert_error_with!(
+        ProcessRemoteTemplates::verify_git_ref(git_ref, url),
+        Err(ZatError::ProcessRemoteCommandError(ProcessRemoteCommandErrorReason::GitRefIsInvalid(error, _))) => error,
+        |error: String| assert!(error.contains(git_ref), "{}", error)
+      )
+    }
+  }
+
+  #[test]
+  fn parses_https_urls() {
+    let remote_url = RemoteRepositoryUrl::parse("https://github.com/ssanj/zat").unwrap();
+
+    assert_eq!(remote_url.checkout_prefix(), "zat-github.com_ssanj_zat_");
+    assert!(!remote_url.uses_ssh())
+  }
+
+  #[test]
+  fn parses_ssh_urls() {
+    let remote_url = RemoteRepositoryUrl::parse("ssh://git@github.com/org/tmpl.git").unwrap();
+
+    assert_eq!(remote_url.checkout_prefix(), "zat-github.com_org_tmpl.git_");
+    assert!(remote_url.uses_ssh())
+  }
+
+  #[test]
+  fn parses_scp_like_urls() {
+    let remote_url = RemoteRepositoryUrl::parse("git@github.com:org/tmpl.git").unwrap();
+
+    assert_eq!(remote_url, RemoteRepositoryUrl::ScpLike { hostname: "github.com".to_owned(), path: "org/tmpl.git".to_owned() });
+    assert_eq!(remote_url.checkout_prefix(), "zat-github.com_org_tmpl.git_");
+    assert!(remote_url.uses_ssh())
+  }
+
+  #[test]
+  fn parses_scp_like_urls_with_other_users() {
+    let remote_url = RemoteRepositoryUrl::parse("deploy@git.example.com:templates/tmpl.git").unwrap();
+
+    assert_eq!(remote_url, RemoteRepositoryUrl::ScpLike { hostname: "git.example.com".to_owned(), path: "templates/tmpl.git".to_owned() })
+  }
+
+  #[test]
+  fn parses_file_urls() {
+    let remote_url = RemoteRepositoryUrl::parse("file:///srv/templates/tmpl.git").unwrap();
+
+    assert_eq!(remote_url, RemoteRepositoryUrl::File { path: "/srv/templates/tmpl.git".to_owned() });
+    assert_eq!(remote_url.checkout_prefix(), "zat-file_srv_templates_tmpl.git_");
+    assert!(!remote_url.uses_ssh())
+  }
+
+  #[test]
+  fn fails_on_relative_paths() {
+    assert_error_with!(
+      RemoteRepositoryUrl::parse("this/is/not/a/url"),
+      Err(ZatError::ProcessRemoteCommandError(ProcessRemoteCommandErrorReason::RemoteRepositoryUrlIsInvalid(_, exception, _))) => exception,
+      |exception: String| assert_eq!(exception, "relative URL without a base")
+    )
+  }
+
+  #[test]
+  fn fails_on_urls_without_hostnames() {
+    assert_error_with!(
+      RemoteRepositoryUrl::parse("data:text/plain, Stuff"),
+      Err(ZatError::ProcessRemoteCommandError(ProcessRemoteCommandErrorReason::RemoteRepositoryUrlHostnameIsInvalid(..))) => (),
+      |_| ()
+    )
+  }
+}
@@ -1,14 +1,17 @@
 use crate::choice::{ChoiceRunner, DefaultChoiceRunner, DefaultChoiceScopeFilter, ChoiceScopeFilter, SelectedChoices};
 use crate::error::ZatAction;
 use crate::logging::{VerboseLogger, Logger};
-use crate::post_processor::{PostProcessingHook, ShellHook};
-use crate::processor::{ProcessTemplates as TemplateProcessing, DefaultProcessTemplates};
-use crate::templates::{DefaultTemplateVariableProvider, TemplateVariableProvider, TemplateVariables, UserChoices, ValidConfig};
+use crate::post_processor::{HookContext, LifecycleHooks, PostProcessingActions, PostProcessingHook, ShellHook};
+use crate::processor::{ProcessTemplates as TemplateProcessing, DefaultProcessTemplates, AhoCorasickTokenReplacer};
+use crate::templates::{DefaultTemplateVariableProvider, TemplateVariables, UserChoices, ValidConfig};
 use crate::templates::{TemplateConfigValidator, DefaultTemplateConfigValidator};
 use crate::templates::TemplateVariableReview;
 use crate::token_expander::{ExpandFilters, DefaultExpandFilters};
-use crate::plugin::{DefaultPluginRunner, PluginRunnerWorkflow};
-use crate::config::UserConfig;
+use crate::plugin::{DefaultPluginRunner, PluginCache, PluginContext, PluginRunnerWorkflow};
+use crate::config::{HookPhase, ManifestFile, RepositoryManifest, UserConfig, ZAT_VERSION};
+use crate::inheritance::ParentRepositories;
+use crate::trust::RemoteTrust;
+use std::collections::HashMap;
 use std::format as s;
 
 
@@ -19,31 +22,98 @@ impl ProcessTemplates {
   pub fn process(user_config: UserConfig) -> ZatAction {
     VerboseLogger::log_user_config(&user_config);
 
-    // Reads the .variables.zat-prompt file into TemplateVariables
+    let mut repositories = vec![];
+
+    for repository_config in user_config.repository_configs() {
+      // Reads the optional manifest and fails early if this version of Zat is too old for the template
+      let manifest_file = ManifestFile::from(repository_config.repository_dir.clone());
+      let manifest = manifest_file.load()?;
+      if let Some(manifest) = &manifest {
+        manifest.verify_zat_version(manifest_file.get_path(), ZAT_VERSION)?;
+        Self::show_manifest(manifest)
+      }
+
+      // Finds any parent repositories to inherit variables and templates from
+      let parent_repositories = ParentRepositories::load(&repository_config.repository_dir, manifest.as_ref())?;
+
+      // Only the lifecycle hooks and actions of the repository itself are run, and not those of its parents
+      let (hooks, actions) = manifest.map(|manifest| (manifest.hooks, manifest.actions)).unwrap_or_default();
+      repositories.push((UserConfig { hooks, actions, ..repository_config }, parent_repositories));
+    }
+
+    let result = Self::process_repositories(&mut repositories);
+
+    for (_, parent_repositories) in repositories {
+      parent_repositories.remove()
+    }
+
+    result
+  }
+
+  fn process_repositories(repositories: &mut [(UserConfig, ParentRepositories)]) -> ZatAction {
+    // Reads the .variables.zat-prompt file (and those of any parents) of each repository into TemplateVariables
+    // Variables shared between repositories are only prompted for once
     let template_variable_provider = DefaultTemplateVariableProvider::new();
-    let template_variables: TemplateVariables = template_variable_provider.get_tokens(user_config.clone())?;
-    VerboseLogger::log_template_variables(&user_config, &template_variables);
+    let mut template_variables = TemplateVariables::default();
+    let mut repository_configs = vec![];
+    let mut origins = vec![];
+
+    for (repository_config, parent_repositories) in repositories.iter() {
+      let repository_variables = parent_repositories.template_variables(&template_variable_provider, repository_config)?;
+      template_variables = template_variables.compose_with(repository_variables)?;
+      repository_configs.push(repository_config.clone());
+      origins.push(repository_config.origin.clone());
+      origins.extend(parent_repositories.origins().iter().cloned());
+    }
+
+    // Defaults from the user config file replace the defaults of the template, and are replaced by plugin outputs
+    let user_config = &repository_configs[0];
+    let mut template_variables = template_variables.with_user_defaults(&user_config.variable_defaults);
+    VerboseLogger::log_template_variables(user_config, &template_variables);
+    VerboseLogger::log_plugin_paths(user_config, &template_variables);
+
+    // Runs any plugins that provide choices, so their choices can be selected from. Remote repositories, their remote
+    // parents and archives can run any command, so the user confirms their plugins and shell hooks first
+    let has_choice_plugins = !user_config.no_plugins && template_variables.has_choice_plugins();
+    if has_choice_plugins {
+      RemoteTrust::confirm(&origins, &repository_configs, &template_variables)?;
+      let plugin_context = PluginContext::new(user_config, &template_variables, &HashMap::new(), &HashMap::new());
+      PluginRunnerWorkflow::run_choice_plugins(DefaultPluginRunner::new(), &mut template_variables, &plugin_context, &PluginCache::new(user_config))?;
+    }
 
     // Ask for user choices and separate choices from other variables
-    let SelectedChoices { choices, mut variables } = DefaultChoiceRunner::run_choices(template_variables, &user_config)?;
+    let SelectedChoices { choices, mut variables } = DefaultChoiceRunner::run_choices(template_variables, user_config)?;
 
     DefaultChoiceScopeFilter::filter_scopes(&choices, &mut variables);
-    VerboseLogger::log_template_variables_after_scope_filter(&user_config, &variables);
+    VerboseLogger::log_template_variables_after_scope_filter(user_config, &variables);
+
+    // Remote repositories, their remote parents and archives can run any command, so the user confirms their plugins
+    // and shell hooks first
+    if !has_choice_plugins {
+      RemoteTrust::confirm(&origins, &repository_configs, &variables)?;
+    }
 
     // Runs any plugins that have been defined and updates template_variables with results
-    let plugin_runner = DefaultPluginRunner::new();
-    PluginRunnerWorkflow::run_plugins(plugin_runner, &mut variables)?;
-    VerboseLogger::log_template_variables_after_plugins_run(&user_config, &variables);
+    if user_config.no_plugins {
+      if variables.tokens.iter().any(|variable| variable.plugin.is_some()) {
+        Logger::info("Skipping plugins. Variables with plugins are prompted for with their default values.")
+      }
+    } else {
+      let plugin_runner = DefaultPluginRunner::new();
+      let plugin_context = PluginContext::new(user_config, &variables, &HashMap::new(), &choices);
+      PluginRunnerWorkflow::run_plugins(plugin_runner, &mut variables, &plugin_context, &PluginCache::new(user_config))?;
+      VerboseLogger::log_template_variables_after_plugins_run(user_config, &variables);
+    }
 
     // Ask for the user for the value of each variable
     // Then verify all the variables supplied are correct
     let template_config_validator = DefaultTemplateConfigValidator::new();
 
-    let template_variable_review = template_config_validator.validate(&user_config, &SelectedChoices::new(choices, variables.clone().tokens))?;
+    let template_variable_review = template_config_validator.validate(user_config, &SelectedChoices::new(choices, variables.clone().tokens))?;
 
     match template_variable_review {
       TemplateVariableReview::Accepted(vc) => {
-        Self::handle_accepted_variables(&user_config, variables, vc)?
+        Self::handle_accepted_variables(repositories, variables, vc)?
       },
       TemplateVariableReview::Rejected => {
         Logger::warn("The user rejected the input review.")
@@ -53,18 +123,42 @@ impl ProcessTemplates {
     Ok(())
   }
 
-  fn handle_accepted_variables(user_config: &UserConfig, variables: TemplateVariables, valid_config: ValidConfig) -> ZatAction {
+  fn show_manifest(manifest: &RepositoryManifest) {
+    Logger::info(&s!("Processing template: {}", manifest.lines().join("\n")))
+  }
+
+  fn handle_accepted_variables(repositories: &mut [(UserConfig, ParentRepositories)], variables: TemplateVariables, valid_config: ValidConfig) -> ZatAction {
+    let repository_configs: Vec<UserConfig> = repositories.iter().map(|(repository_config, _)| repository_config.clone()).collect();
+    let user_config = &repository_configs[0];
     VerboseLogger::log_user_supplied_variables(user_config, &valid_config);
+    let hook_context = HookContext::new(user_config, &variables, &valid_config.user_variables, &valid_config.user_choices);
     let user_variables = valid_config.user_variables;
     let user_choices = UserChoices::new(valid_config.user_choices);
     let expand_filters = DefaultExpandFilters::new();
     let tokenized_key_expanded_variables = expand_filters.expand_filers(variables, user_variables);
 
     VerboseLogger::expanded_tokens(user_config, &tokenized_key_expanded_variables);
-    DefaultProcessTemplates.process_templates(user_config, tokenized_key_expanded_variables, user_choices)?;
 
-    // Run post-processor if one exists
-    ShellHook.run(user_config)?;
+    for repository_config in &repository_configs {
+      LifecycleHooks(HookPhase::PreGeneration).run(repository_config, &hook_context)?
+    }
+
+    // The templates of parent repositories are layered once the variables are known, as a file of the repository
+    // replaces a file of its parents that is written to the same path
+    let token_replacer = AhoCorasickTokenReplacer::new(tokenized_key_expanded_variables.clone());
+
+    // Each repository is written into the same target directory, in order
+    for (repository_config, parent_repositories) in repositories.iter_mut() {
+      let repository_config = parent_repositories.layer_templates(repository_config.clone(), &token_replacer)?;
+      DefaultProcessTemplates.process_templates(&repository_config, tokenized_key_expanded_variables.clone(), user_choices.clone())?
+    }
+
+    // Run post-processors if they exist, once all repositories have been written
+    for repository_config in &repository_configs {
+      PostProcessingActions.run(repository_config, &hook_context)?;
+      ShellHook.run(repository_config, &hook_context)?;
+      LifecycleHooks(HookPhase::PostGeneration).run(repository_config, &hook_context)?
+    }
 
     Logger::coloured(
       &s!("{}{}{}",
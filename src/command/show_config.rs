@@ -0,0 +1,58 @@
+use std::format as s;
+
+use crate::args::{ConfigArgs, ConfigCommand};
+use crate::config::{IgnoredFiles, UserDefaultsFile, ZatConfigDir};
+use crate::error::ZatAction;
+use crate::logging::Logger;
+
+pub struct ShowConfig;
+
+impl ShowConfig {
+
+  pub fn process_config(config_args: ConfigArgs) -> ZatAction {
+    let user_defaults_file = UserDefaultsFile::from(&ZatConfigDir::new()?);
+
+    match config_args.command {
+      ConfigCommand::Show => Self::show(&user_defaults_file),
+    }
+  }
+
+  /// Prints the values used when no flags are supplied on the command line.
+  fn show(user_defaults_file: &UserDefaultsFile) -> ZatAction {
+    let user_defaults = user_defaults_file.load()?;
+    let user_defaults_path = user_defaults_file.path().to_string_lossy();
+
+    if user_defaults_file.path().exists() {
+      Logger::info(&s!("Using user config file '{}':", user_defaults_path));
+    } else {
+      Logger::info(&s!("There is no user config file at '{}'. Using Zat's built-in defaults:", user_defaults_path));
+    }
+
+    let ignores =
+      IgnoredFiles::from(
+        IgnoredFiles::default_ignores()
+          .into_iter()
+          .chain(user_defaults.ignores(vec![]))
+      );
+
+    let variable_defaults =
+      user_defaults
+        .variables
+        .iter()
+        .map(|(name, value)| s!("{}={}", name, value))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let lines =
+      [
+        s!("Menu Style: {}", user_defaults.menu_style(None)),
+        s!("Ignored files and folders: {}", ignores),
+        s!("Verbose: {}", user_defaults.verbose(None)),
+        s!("Variable defaults: {}", variable_defaults),
+      ];
+
+    Logger::coloured(&lines.join("\n"));
+
+    Ok(())
+  }
+}
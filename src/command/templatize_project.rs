@@ -0,0 +1,111 @@
+use std::fs;
+use std::path::Path;
+use std::format as s;
+use regex::Regex;
+use walkdir::WalkDir;
+
+use crate::args::TemplatizeArgs;
+use crate::config::{IgnoredFiles, RepositoryDir, TemplateFilesDir, DOT_VARIABLES_PROMPT};
+use crate::error::{ZatAction, ZatError, ZatResult};
+use crate::logging::Logger;
+use crate::spath;
+use crate::templatize::{ProjectTemplatizer, TextReplacement};
+
+pub struct TemplatizeProject;
+
+impl TemplatizeProject {
+
+  /// Creates a Zat repository from an existing project. Text supplied through `--replace` is replaced with tokens in
+  /// the names and content of the project's files. Files with replaced content become template files.
+  pub fn process_templatize(templatize_args: TemplatizeArgs) -> ZatAction {
+    let source_dir = Path::new(&templatize_args.source);
+    let repository_directory = RepositoryDir::new(&templatize_args.repository_dir);
+
+    if !source_dir.is_dir() {
+      return Err(ZatError::templatize_source_dir_does_not_exist(&templatize_args.source))
+    }
+
+    if repository_directory.does_exist() {
+      return Err(ZatError::templatize_repository_dir_should_not_exist(&templatize_args.repository_dir))
+    }
+
+    let replacements =
+      templatize_args
+        .replace
+        .iter()
+        .map(|replacement| TextReplacement::parse(replacement))
+        .collect::<ZatResult<Vec<_>>>()?;
+
+    let ignores = Self::ignores(templatize_args.ignores)?;
+    let mut templatizer = ProjectTemplatizer::new(replacements);
+
+    let template_files_dir = TemplateFilesDir::from(&repository_directory);
+    let template_files_dir_path = Path::new(template_files_dir.path());
+    Self::create_directory(template_files_dir_path)?;
+
+    let mut template_file_count = 0;
+
+    let entries =
+      WalkDir::new(source_dir)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+          let relative_path = entry.path().strip_prefix(source_dir).map(|path| path.to_string_lossy().to_string()).unwrap_or_default();
+          // Directories are matched with a trailing slash, so that ignores like '.git/.*' skip the whole directory
+          let relative_dir_path = s!("{}/", relative_path);
+          !ignores.iter().any(|ignore| ignore.is_match(&relative_path) || (entry.file_type().is_dir() && ignore.is_match(&relative_dir_path)))
+        });
+
+    for entry in entries {
+      let entry = entry.map_err(|e| ZatError::could_not_read_templatize_source_file(&templatize_args.source, e.to_string()))?;
+      let source_path = entry.path();
+      let relative_path = source_path.strip_prefix(source_dir).expect("Could not strip the source directory prefix").to_string_lossy().to_string();
+      let tokenised_path = templatizer.templatize(&relative_path).unwrap_or(relative_path);
+      let destination_path = template_files_dir_path.join(tokenised_path);
+
+      if entry.file_type().is_dir() {
+        Self::create_directory(&destination_path)?
+      } else {
+        let content =
+          fs::read(source_path)
+            .map_err(|e| ZatError::could_not_read_templatize_source_file(spath!(source_path), e.to_string()))?;
+
+        // Binary files are copied as is
+        let templatized_content = std::str::from_utf8(&content).ok().and_then(|text| templatizer.templatize(text));
+
+        match templatized_content {
+          Some(templatized_content) => {
+            template_file_count += 1;
+            Self::create_file(s!("{}.tmpl", spath!(destination_path)), templatized_content)?
+          },
+          None => Self::create_file(&destination_path, content)?
+        }
+      }
+    }
+
+    Self::create_file(Path::new(repository_directory.path()).join(DOT_VARIABLES_PROMPT), s!("{}\n", templatizer.variables_file()))?;
+
+    let repository_path = Path::new(repository_directory.path());
+    Logger::info(&s!("Zat created a repository at `{}` with {} template file(s).", spath!(repository_path), template_file_count));
+    Logger::info(&s!("Process the repository with: `zat process --repository-dir {} --target-dir <YOUR_TARGET_DIRECTORY>`", spath!(repository_path)));
+
+    Ok(())
+  }
+
+  fn ignores(cli_ignores: Vec<String>) -> ZatResult<Vec<Regex>> {
+    IgnoredFiles::default_ignores()
+      .into_iter()
+      .chain(cli_ignores)
+      .map(|ignore| Regex::new(&ignore).map_err(|e| ZatError::invalid_templatize_ignore(&ignore, e.to_string())))
+      .collect()
+  }
+
+  fn create_directory<P: AsRef<Path>>(path: P) -> ZatAction {
+    fs::create_dir_all(path.as_ref()).map_err(|e| ZatError::could_not_create_templatize_file(spath!(path.as_ref()), e.to_string()))
+  }
+
+  fn create_file<P: AsRef<Path>, C: AsRef<[u8]>>(file_path: P, contents: C) -> ZatAction {
+    fs::write(file_path.as_ref(), contents).map_err(|e| ZatError::could_not_create_templatize_file(spath!(file_path.as_ref()), e.to_string()))
+  }
+}
@@ -0,0 +1,82 @@
+use std::fs;
+use std::format as s;
+
+use crate::args::TestTemplatesArgs;
+use crate::config::RepositoryDir;
+use crate::error::{ZatAction, ZatError};
+use crate::logging::Logger;
+use crate::template_tests::{DirectoryDiff, TestCase};
+use super::ProcessRemoteTemplates;
+
+pub struct TestTemplates;
+
+impl TestTemplates {
+
+  /// Renders each test case of a Zat repository into a temporary directory and compares it with the expected output of
+  /// the test case. With `--bless` the expected output is replaced with the rendered output instead.
+  pub fn process_tests(test_templates_args: TestTemplatesArgs) -> ZatAction {
+    let repository_dir = RepositoryDir::new(&test_templates_args.repository_dir);
+
+    if !repository_dir.does_exist() {
+      return Err(ZatError::template_dir_does_not_exist(repository_dir.path()))
+    }
+
+    let test_cases = TestCase::find_all(&repository_dir)?;
+
+    if test_cases.is_empty() {
+      return Err(ZatError::no_template_tests(repository_dir.path()))
+    }
+
+    let working_dir =
+      tempfile::Builder::new()
+        .prefix("zat-test-")
+        .tempdir()
+        .map_err(|e| ZatError::could_not_run_test_case(&std::env::temp_dir().to_string_lossy(), e.to_string()))?;
+
+    // An empty config directory, so that the user config file is not used
+    let config_dir = working_dir.path().join(".config");
+    fs::create_dir_all(&config_dir).map_err(|e| ZatError::could_not_run_test_case(&config_dir.to_string_lossy(), e.to_string()))?;
+
+    let mut failed_test_cases = vec![];
+
+    for test_case in &test_cases {
+      let target_dir = working_dir.path().join(&test_case.name);
+
+      if let Err(error) = test_case.render(&repository_dir, &target_dir, &config_dir) {
+        Logger::warn(&s!("FAILED: {}", test_case.name));
+        Logger::coloured(&error.to_string());
+        failed_test_cases.push(test_case.name.clone());
+        continue
+      }
+
+      if test_templates_args.bless {
+        test_case.bless(&target_dir)?;
+        Logger::info(&s!("BLESSED: {}", test_case.name));
+        continue
+      }
+
+      let diff = DirectoryDiff::new(&test_case.expected_dir, &target_dir);
+
+      if diff.is_different() {
+        Logger::warn(&s!("FAILED: {}", test_case.name));
+        Logger::coloured(&diff.lines(&test_case.expected_dir, &target_dir).join("\n"));
+        failed_test_cases.push(test_case.name.clone());
+      } else {
+        Logger::success(&s!("PASSED: {}", test_case.name));
+      }
+    }
+
+    ProcessRemoteTemplates::remove_temporary_directory(working_dir);
+
+    if failed_test_cases.is_empty() {
+      if test_templates_args.bless {
+        Logger::info(&s!("Updated the expected output of {} test case(s).", test_cases.len()));
+      } else {
+        Logger::info(&s!("{} test case(s) passed.", test_cases.len()));
+      }
+      Ok(())
+    } else {
+      Err(ZatError::template_tests_failed(&failed_test_cases, test_cases.len()))
+    }
+  }
+}
@@ -0,0 +1,224 @@
+use std::format as s;
+
+use crate::args::ValidateArgs;
+use crate::config::{ManifestFile, RepositoryDir, RepositoryOrigin, UserConfig, ZAT_VERSION};
+use crate::error::{ZatAction, ZatError};
+use crate::inheritance::ParentRepositories;
+use crate::logging::{Logger, Printer};
+use crate::plugin::{is_supported_protocol_version, DefaultPluginRunner, PluginDescription, DESCRIBE_PLUGIN_ARG, PLUGIN_PROTOCOL_VERSION};
+use crate::templates::{ArgType, DefaultTemplateVariableProvider, Plugin, TemplateVariables};
+use crate::trust::RemoteTrust;
+
+pub struct ValidateTemplate;
+
+impl ValidateTemplate {
+
+  /// Checks the manifest and variables of a Zat repository, and the arguments of its plugins against what each plugin
+  /// declares through DESCRIBE_PLUGIN_ARG. Nothing is prompted for and plugins are only run to describe themselves.
+  /// Plugins of remote parents the user has not trusted are not run at all.
+  pub fn process_validate(validate_args: ValidateArgs) -> ZatAction {
+    let repository_dir = RepositoryDir::new(&validate_args.repository_dir);
+
+    if !repository_dir.does_exist() {
+      return Err(ZatError::template_dir_does_not_exist(repository_dir.path()))
+    }
+
+    let manifest_file = ManifestFile::from(repository_dir.clone());
+    let manifest = manifest_file.load()?;
+    if let Some(manifest) = &manifest {
+      manifest.verify_zat_version(manifest_file.get_path(), ZAT_VERSION)?
+    }
+
+    // Reading the variables validates them against the schema of the variables file
+    let user_config = UserConfig::for_repository(&repository_dir);
+    let parent_repositories = ParentRepositories::load(&repository_dir, manifest.as_ref())?;
+    let template_variables = parent_repositories.template_variables(&DefaultTemplateVariableProvider::new(), &user_config);
+
+    // Plugins of remote parents are run from their checkouts, so these are only removed once the plugins have been checked
+    let problems =
+      template_variables.and_then(|template_variables| {
+        let untrusted = RemoteTrust::untrusted(parent_repositories.origins())?;
+        let untrusted_origin = |plugin: &Plugin| parent_repositories.remote_origin_of(&plugin.repository_dir).filter(|origin| untrusted.contains(origin)).cloned();
+        Ok(Self::plugin_problems(&DefaultPluginRunner::new(), &template_variables, untrusted_origin))
+      });
+    parent_repositories.remove();
+    let problems = problems?;
+
+    if problems.is_empty() {
+      Logger::success(&s!("The repository '{}' is valid.", repository_dir.path()));
+      Ok(())
+    } else {
+      Printer::print_verbose_strings("Problems", problems.clone());
+      Err(ZatError::repository_not_valid(repository_dir.path(), problems.len()))
+    }
+  }
+
+  /// The untrusted origin of a plugin is the remote repository it is bundled with, if the user has not trusted it.
+  fn plugin_problems(plugin_runner: &DefaultPluginRunner, template_variables: &TemplateVariables, untrusted_origin: impl Fn(&Plugin) -> Option<RepositoryOrigin>) -> Vec<String> {
+    let mut problems = vec![];
+
+    for variable in &template_variables.tokens {
+      if let Some(plugin) = &variable.plugin {
+        if let Some(origin) = untrusted_origin(plugin) {
+          Logger::warn(
+            &s!("Plugin '{}' of variable '{}' is defined in the remote repository {}, which you have not trusted, so its arguments were not checked. Process the repository to review and trust it.",
+              plugin.id, variable.variable_name, origin)
+          );
+          continue
+        }
+
+        match plugin_runner.describe_plugin(plugin) {
+          Ok(description) => problems.extend(Self::check_plugin(&variable.variable_name, plugin, &description)),
+          Err(_) => {
+            Logger::warn(
+              &s!("Plugin '{}' of variable '{}' does not describe itself with {}, so its arguments were not checked.", plugin.id, variable.variable_name, DESCRIBE_PLUGIN_ARG)
+            )
+          },
+        }
+      }
+    }
+
+    problems
+  }
+
+  /// Returns a problem for each argument that is supplied but not declared by the plugin, and each required argument
+  /// that is not supplied.
+  pub fn check_plugin(variable_name: &str, plugin: &Plugin, description: &PluginDescription) -> Vec<String> {
+    let mut problems = vec![];
+
+    if !is_supported_protocol_version(description.protocol_version) {
+      problems.push(
+        s!("Plugin '{}' of variable '{}' uses version {} of the plugin protocol, but this version of Zat supports versions 1 to {}.",
+          plugin.id, variable_name, description.protocol_version, PLUGIN_PROTOCOL_VERSION)
+      )
+    }
+
+    let supplied = Self::argument_names(plugin);
+
+    for name in &supplied {
+      if !description.arguments.iter().any(|argument| &argument.name == name) {
+        problems.push(s!("Plugin '{}' of variable '{}' does not declare the argument '{}'.", plugin.id, variable_name, name))
+      }
+    }
+
+    for argument in description.arguments.iter().filter(|argument| argument.required) {
+      if !supplied.contains(&argument.name) {
+        problems.push(s!("Plugin '{}' of variable '{}' requires the argument '{}', which is not supplied.", plugin.id, variable_name, argument.name))
+      }
+    }
+
+    problems
+  }
+
+  /// The names of the arguments supplied to a plugin, without their prefixes. Only arguments that start with a '-' are
+  /// named within an argument line, and any value after an '=' is left out.
+  fn argument_names(plugin: &Plugin) -> Vec<String> {
+    match &plugin.args {
+      None => vec![],
+      Some(ArgType::MutlipleArgs(args)) => args.iter().map(|arg| arg.name.clone()).collect(),
+      Some(ArgType::ArgLine(args)) => {
+        args
+          .iter()
+          .filter(|arg| arg.starts_with('-'))
+          .map(|arg| arg.trim_start_matches('-').split('=').next().unwrap_or_default().to_owned())
+          .collect()
+      },
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::plugin::PluginArgumentDescription;
+  use crate::args::test_util::create_file_in;
+  use crate::templates::{PluginArg, TemplateVariable};
+  use pretty_assertions::assert_eq;
+  use tempfile::TempDir;
+
+  fn description(arguments: &[(&str, bool)]) -> PluginDescription {
+    PluginDescription {
+      protocol_version: PLUGIN_PROTOCOL_VERSION,
+      name: "scala-deps".to_owned(),
+      description: None,
+      arguments:
+        arguments
+          .iter()
+          .map(|(name, required)| PluginArgumentDescription { name: (*name).to_owned(), required: *required, description: None })
+          .collect(),
+    }
+  }
+
+  #[test]
+  fn accepts_declared_arguments() {
+    let plugin = Plugin::new("scala-deps", &["-o", "org.scala-lang", "--group=scala3-library", "3"]);
+
+    assert_eq!(ValidateTemplate::check_plugin("scala_version", &plugin, &description(&[("o", true), ("group", false)])), Vec::<String>::new())
+  }
+
+  #[test]
+  fn reports_undeclared_and_missing_arguments() {
+    let plugin =
+      Plugin {
+        args: Some(ArgType::MutlipleArgs(vec![PluginArg::new("org", "org.scala-lang")])),
+        ..Plugin::new("scala-deps", &[])
+      };
+
+    assert_eq!(
+      ValidateTemplate::check_plugin("scala_version", &plugin, &description(&[("o", true)])),
+      vec![
+        "Plugin 'scala-deps' of variable 'scala_version' does not declare the argument 'org'.",
+        "Plugin 'scala-deps' of variable 'scala_version' requires the argument 'o', which is not supplied.",
+      ]
+    )
+  }
+
+  #[test]
+  fn reports_unsupported_protocol_versions() {
+    let plugin = Plugin::new("scala-deps", &[]);
+    let description = PluginDescription { protocol_version: PLUGIN_PROTOCOL_VERSION + 1, ..description(&[]) };
+
+    assert_eq!(
+      ValidateTemplate::check_plugin("scala_version", &plugin, &description),
+      vec![s!("Plugin 'scala-deps' of variable 'scala_version' uses version {} of the plugin protocol, but this version of Zat supports versions 1 to {}.", PLUGIN_PROTOCOL_VERSION + 1, PLUGIN_PROTOCOL_VERSION)]
+    )
+  }
+
+  #[test]
+  fn does_not_run_plugins_of_untrusted_remote_repositories() {
+    let repository_dir = TempDir::new().unwrap();
+    let repository = RepositoryDir::new(&repository_dir.path().to_string_lossy());
+    let marker = repository_dir.path().join("described");
+    let plugin_script = s!("#!/bin/bash\ntouch '{}'\necho '{{\"protocol_version\": 1, \"name\": \"author\", \"arguments\": []}}'", marker.to_string_lossy());
+    std::fs::create_dir_all(repository.plugins_dir()).unwrap();
+    create_file_in(&repository.plugins_dir(), "author.sh", plugin_script.as_bytes(), Some(0o755));
+
+    let variables =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable {
+            variable_name: "author".to_owned(),
+            description: "Author".to_owned(),
+            prompt: "Please enter the author".to_owned(),
+            filters: vec![],
+            default_value: None,
+            plugin: Some(Plugin::new("author.sh", &[]).defined_in(&repository).unwrap()),
+            choices: vec![],
+            scopes: None,
+          }
+        ]
+      );
+
+    let origin = RepositoryOrigin::Remote { url: "https://github.com/org/base".to_owned(), commit: "abc123".to_owned() };
+    let problems = ValidateTemplate::plugin_problems(&DefaultPluginRunner::new(), &variables, |_| Some(origin.clone()));
+
+    assert_eq!(problems, Vec::<String>::new());
+    assert!(!marker.exists(), "The plugin of an untrusted repository was run");
+
+    let problems = ValidateTemplate::plugin_problems(&DefaultPluginRunner::new(), &variables, |_| None);
+
+    assert_eq!(problems, Vec::<String>::new());
+    assert!(marker.exists(), "The plugin of a trusted repository was not run")
+  }
+}
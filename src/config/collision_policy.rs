@@ -0,0 +1,28 @@
+use std::fmt;
+
+/// What to do when a template file would be written over a file that already exists in the target directory. This
+/// happens when several repositories are processed into the same target directory.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CollisionPolicy {
+  /// Stop processing with an error.
+  Fail,
+
+  /// Replace the existing file. Later repositories win.
+  #[default]
+  Overwrite,
+
+  /// Keep the existing file. Earlier repositories win.
+  Skip,
+}
+
+impl fmt::Display for CollisionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      let label = match self {
+        CollisionPolicy::Fail => "fail",
+        CollisionPolicy::Overwrite => "overwrite",
+        CollisionPolicy::Skip => "skip",
+      };
+
+      write!(f, "{}", label)
+    }
+}
@@ -1,13 +1,7 @@
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum ConfigShellHookStatus {
+  #[default]
   NoShellHook,
   RunShellHook(String)
 }
 
-
-impl Default for ConfigShellHookStatus {
-  fn default() -> Self {
-    Self::NoShellHook
-  }
-}
-
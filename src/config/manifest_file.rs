@@ -0,0 +1,278 @@
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use semver::Version;
+use std::format as s;
+
+use super::{PostProcessingAction, RepositoryDir, RepositoryHooks};
+use crate::error::{ZatAction, ZatError, ZatResult};
+
+pub const DOT_MANIFEST: &str = ".manifest.zat-meta";
+
+/// The version of Zat that is running.
+pub const ZAT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone)]
+pub struct ManifestFile {
+  path: String
+}
+
+impl ManifestFile {
+
+  pub fn does_exist(&self) -> bool {
+    Path::new(&self.path).exists()
+  }
+
+  pub fn get_path(&self) -> &str {
+    self.path.as_str()
+  }
+
+  /// The manifest is optional, so a missing manifest file is not an error.
+  pub fn load(&self) -> ZatResult<Option<RepositoryManifest>> {
+    if self.does_exist() {
+      let content = fs::read_to_string(&self.path).map_err(|e| ZatError::manifest_file_cant_be_read(&self.path, e.to_string()))?;
+      let manifest: RepositoryManifest = serde_json::from_str(&content).map_err(|e| ZatError::manifest_file_cant_be_decoded(&self.path, e.to_string()))?;
+
+      Ok(Some(manifest))
+    } else {
+      Ok(None)
+    }
+  }
+}
+
+impl From<RepositoryDir> for ManifestFile {
+  fn from(repository_dir: RepositoryDir) -> Self {
+      let manifest_file = repository_dir.join(DOT_MANIFEST);
+      ManifestFile {
+        path: manifest_file.display().to_string()
+      }
+  }
+}
+
+
+/// Metadata about a Zat repository.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RepositoryManifest {
+  pub name: String,
+
+  #[serde(default)]
+  pub description: Option<String>,
+
+  #[serde(default)]
+  pub version: Option<String>,
+
+  #[serde(default)]
+  pub authors: Vec<String>,
+
+  #[serde(default)]
+  pub min_zat_version: Option<String>,
+
+  #[serde(default)]
+  pub tags: Vec<String>,
+
+  /// A repository whose variables and templates this repository builds upon.
+  #[serde(default)]
+  pub parent: Option<ParentRepository>,
+
+  /// Commands to run before and after the templates of this repository are written.
+  #[serde(default)]
+  pub hooks: RepositoryHooks,
+
+  /// Built-in actions to run on the target directory once the templates of this repository are written.
+  #[serde(default)]
+  pub actions: Vec<PostProcessingAction>,
+}
+
+
+/// The parent of a Zat repository. Either a local path, relative to the repository that declares it, or a remote URL.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ParentRepository {
+  pub repository: String,
+
+  /// Branch, tag or commit to checkout for remote parents.
+  #[serde(default, rename = "ref")]
+  pub git_ref: Option<String>,
+
+  /// Directory within a remote parent that holds the Zat repository.
+  #[serde(default)]
+  pub subdir: Option<String>,
+}
+
+impl RepositoryManifest {
+
+  /// Fails if the `min_zat_version` is later than the supplied Zat version.
+  pub fn verify_zat_version(&self, manifest_path: &str, zat_version: &str) -> ZatAction {
+    match &self.min_zat_version {
+      Some(min_zat_version) => {
+        let min_version = Version::parse(min_zat_version).map_err(|e| ZatError::invalid_min_zat_version(manifest_path, min_zat_version, e.to_string()))?;
+        let current_version = Version::parse(zat_version).map_err(|e| ZatError::generic_error(&s!("Could not parse the Zat version '{}'. ERROR_ID: 1003", zat_version), e.to_string()))?;
+
+        if current_version < min_version {
+          Err(ZatError::zat_version_too_old(&self.name, min_zat_version, zat_version))
+        } else {
+          Ok(())
+        }
+      },
+      None => Ok(())
+    }
+  }
+
+  pub fn lines(&self) -> Vec<String> {
+    let heading =
+      match &self.version {
+        Some(version) => s!("{} ({})", self.name, version),
+        None => self.name.clone(),
+      };
+
+    let mut lines = vec![heading];
+
+    if let Some(description) = &self.description {
+      lines.push(s!("  {}", description))
+    }
+
+    if !self.authors.is_empty() {
+      lines.push(s!("  Authors: {}", self.authors.join(", ")))
+    }
+
+    if !self.tags.is_empty() {
+      lines.push(s!("  Tags: {}", self.tags.join(", ")))
+    }
+
+    if let Some(parent) = &self.parent {
+      lines.push(s!("  Parent: {}", parent.repository))
+    }
+
+    lines
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::assert_error_with;
+  use crate::error::ProcessCommandErrorReason;
+  use crate::error::manifest_file_error_reason::ManifestFileErrorReason;
+  use crate::config::{ActionKind, LifecycleHook};
+  use crate::templates::Scope;
+  use pretty_assertions::assert_eq;
+  use tempfile::TempDir;
+
+  fn manifest_with_min_zat_version(min_zat_version: &str) -> RepositoryManifest {
+    RepositoryManifest {
+      name: "rust-cli".to_owned(),
+      min_zat_version: Some(min_zat_version.to_owned()),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn loads_nothing_if_the_manifest_does_not_exist() {
+    let repository_dir = TempDir::new().unwrap();
+    let manifest_file = ManifestFile::from(RepositoryDir::from(repository_dir.path()));
+
+    assert_eq!(manifest_file.load(), Ok(None))
+  }
+
+  #[test]
+  fn loads_the_manifest() {
+    let repository_dir = TempDir::new().unwrap();
+    let manifest_file = ManifestFile::from(RepositoryDir::from(repository_dir.path()));
+
+    let content = r#"
+      {
+        "name": "rust-cli",
+        "description": "A Rust command line application",
+        "version": "1.2.0",
+        "authors": ["sanj"],
+        "min_zat_version": "0.15.0",
+        "tags": ["rust", "cli"],
+        "parent": { "repository": "gh:ssanj/base-template", "ref": "v1.0.0" },
+        "hooks": {
+          "pre_generation": [{ "command": "hooks/check-cargo.sh" }],
+          "post_generation": [{ "command": "git", "args": ["init"], "scopes": [{ "choice": "vcs", "value": "git" }] }]
+        },
+        "actions": [{ "action": "chmod", "paths": ["bin/run.sh"], "mode": "755" }]
+      }
+    "#;
+
+    fs::write(manifest_file.get_path(), content).unwrap();
+
+    let expected_manifest =
+      RepositoryManifest {
+        name: "rust-cli".to_owned(),
+        description: Some("A Rust command line application".to_owned()),
+        version: Some("1.2.0".to_owned()),
+        authors: vec!["sanj".to_owned()],
+        min_zat_version: Some("0.15.0".to_owned()),
+        tags: vec!["rust".to_owned(), "cli".to_owned()],
+        parent: Some(
+          ParentRepository {
+            repository: "gh:ssanj/base-template".to_owned(),
+            git_ref: Some("v1.0.0".to_owned()),
+            subdir: None
+          }
+        ),
+        hooks: RepositoryHooks {
+          pre_generation: vec![LifecycleHook { command: "hooks/check-cargo.sh".to_owned(), args: vec![], scopes: None }],
+          post_generation: vec![
+            LifecycleHook {
+              command: "git".to_owned(),
+              args: vec!["init".to_owned()],
+              scopes: Some(vec![Scope::new_include_choice_value("vcs", "git")])
+            }
+          ],
+        },
+        actions: vec![PostProcessingAction::new(ActionKind::Chmod { paths: vec!["bin/run.sh".to_owned()], mode: "755".to_owned() })],
+      };
+
+    assert_eq!(manifest_file.load(), Ok(Some(expected_manifest)))
+  }
+
+  #[test]
+  fn fails_to_load_an_invalid_manifest() {
+    let repository_dir = TempDir::new().unwrap();
+    let manifest_file = ManifestFile::from(RepositoryDir::from(repository_dir.path()));
+
+    fs::write(manifest_file.get_path(), r#"{ "name": "rust-cli", "min_version": "0.15.0" }"#).unwrap();
+
+    assert_error_with!(
+      manifest_file.load(),
+      Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::ManifestFileError(ManifestFileErrorReason::ManifestDecodeError(..)))) => (),
+      |_| ()
+    )
+  }
+
+  #[test]
+  fn accepts_zat_versions_at_or_above_the_minimum() {
+    let manifest = manifest_with_min_zat_version("0.15.0");
+
+    assert_eq!(manifest.verify_zat_version("manifest", "0.15.0"), Ok(()));
+    assert_eq!(manifest.verify_zat_version("manifest", "0.16.1"), Ok(()));
+    assert_eq!(RepositoryManifest::default().verify_zat_version("manifest", "0.1.0"), Ok(()))
+  }
+
+  #[test]
+  fn fails_on_zat_versions_below_the_minimum() {
+    let manifest = manifest_with_min_zat_version("0.15.0");
+
+    assert_error_with!(
+      manifest.verify_zat_version("manifest", "0.14.9"),
+      Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::ManifestFileError(ManifestFileErrorReason::ZatVersionTooOld(error, _)))) => error,
+      |error: String| assert_eq!(error, "The template 'rust-cli' requires Zat version '0.15.0' or later, but this is Zat version '0.14.9'.")
+    )
+  }
+
+  #[test]
+  fn fails_on_invalid_min_zat_versions() {
+    let manifest = manifest_with_min_zat_version("latest");
+
+    assert_error_with!(
+      manifest.verify_zat_version("manifest", "0.15.0"),
+      Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::ManifestFileError(ManifestFileErrorReason::InvalidMinZatVersion(..)))) => (),
+      |_| ()
+    )
+  }
+}
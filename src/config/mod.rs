@@ -1,11 +1,19 @@
 
 pub mod ignored_files;
 pub mod variable_file;
+pub mod manifest_file;
 pub mod user_config;
 pub mod filters;
 pub mod repository_directory;
 pub mod template_files_directory;
 pub mod target_directory;
+pub mod zat_config_dir;
+pub mod remote_repository;
+pub mod user_defaults;
+pub mod collision_policy;
+pub mod repository_origin;
+pub mod repository_hooks;
+pub mod post_processing_action;
 
 // Private Module
 mod shell_hook_file;
@@ -15,13 +23,25 @@ mod config_shell_hook_status;
 pub use ignored_files::IgnoredFiles;
 pub use variable_file::VariableFile;
 pub use variable_file::DOT_VARIABLES_PROMPT;
+pub use variable_file::DOT_VARIABLES_PROMPT_YAML;
+pub use variable_file::VariableFileFormat;
+pub use manifest_file::{ManifestFile, RepositoryManifest, ParentRepository, ZAT_VERSION};
 pub use user_config::UserConfig;
 pub use filters::Filters;
-pub use repository_directory::RepositoryDir;
+pub use repository_directory::{RepositoryDir, PLUGINS_DIR};
 pub use template_files_directory::TemplateFilesDir;
 pub use target_directory::TargetDir;
+pub use zat_config_dir::ZatConfigDir;
+pub use remote_repository::RemoteRepository;
+pub use user_defaults::{UserDefaults, UserDefaultsFile};
+pub use collision_policy::CollisionPolicy;
+pub use repository_origin::RepositoryOrigin;
+pub use repository_hooks::{HookPhase, RepositoryHooks};
+pub use post_processing_action::{ActionKind, PostProcessingAction};
 pub use shell_hook_file::SHELL_HOOK_FILE;
 pub use config_shell_hook_status::ConfigShellHookStatus;
 
-#[cfg(test)]
 pub use template_files_directory::TEMPLATE_FILES_DIR;
+
+#[cfg(test)]
+pub use repository_hooks::LifecycleHook;
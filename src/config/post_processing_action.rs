@@ -0,0 +1,158 @@
+use serde::Deserialize;
+use std::format as s;
+use std::fmt::Display;
+
+use crate::templates::Scope;
+
+/// A built-in action that is run on the target directory once the templates of a repository are written. Listed under
+/// `actions` in the manifest:
+///
+/// "actions": [
+///   { "action": "git_init" },
+///   { "action": "chmod", "paths": ["bin/run.sh"], "mode": "755" },
+///   { "action": "move", "from": "README-long.md", "to": "README.md", "scopes": [{ "choice": "readme_type", "value": "long" }] },
+///   { "action": "delete", "path": "src/.gitkeep" },
+///   { "action": "run", "command": "cargo", "args": ["fmt"], "working_dir": "app" }
+/// ]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PostProcessingAction {
+  #[serde(flatten)]
+  pub kind: ActionKind,
+
+  /// Only runs the action when these scopes match the selected choices, like the scopes of a variable.
+  #[serde(default)]
+  pub scopes: Option<Vec<Scope>>,
+}
+
+/// Paths are relative to the target directory and can't be outside of it.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ActionKind {
+  /// Initialises a Git repository in the target directory.
+  GitInit,
+
+  /// Sets the permissions of files to an octal mode, such as "755".
+  Chmod {
+    paths: Vec<String>,
+    mode: String,
+  },
+
+  /// Moves or renames a file or directory.
+  Move {
+    from: String,
+    to: String,
+  },
+
+  /// Deletes a file or directory.
+  Delete {
+    path: String,
+  },
+
+  /// Runs a program on the PATH, in the target directory or a directory within it.
+  Run {
+    command: String,
+
+    #[serde(default)]
+    args: Vec<String>,
+
+    #[serde(default)]
+    working_dir: Option<String>,
+  },
+}
+
+impl PostProcessingAction {
+
+  #[cfg(test)]
+  pub fn new(kind: ActionKind) -> Self {
+    Self {
+      kind,
+      scopes: None
+    }
+  }
+
+  /// The command line of an action that runs a command. These are reviewed before a remote repository is trusted.
+  pub fn command_string(&self) -> Option<String> {
+    match &self.kind {
+      ActionKind::Run { command, args, .. } if args.is_empty() => Some(command.clone()),
+      ActionKind::Run { command, args, .. } => Some(s!("{} {}", command, args.join(" "))),
+      _ => None,
+    }
+  }
+}
+
+impl Display for PostProcessingAction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let action =
+      match &self.kind {
+        ActionKind::GitInit => "git init".to_owned(),
+        ActionKind::Chmod { paths, mode } => s!("chmod {} {}", mode, paths.join(" ")),
+        ActionKind::Move { from, to } => s!("move {} to {}", from, to),
+        ActionKind::Delete { path } => s!("delete {}", path),
+        ActionKind::Run { working_dir: Some(working_dir), .. } => s!("run {} in {}", self.command_string().unwrap_or_default(), working_dir),
+        ActionKind::Run { .. } => s!("run {}", self.command_string().unwrap_or_default()),
+      };
+
+    match &self.scopes {
+      Some(scopes) if !scopes.is_empty() => {
+        let scopes: Vec<String> = scopes.iter().map(|scope| scope.to_string()).collect();
+        write!(f, "{} ({})", action, scopes.join(", "))
+      },
+      _ => f.write_str(&action)
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn deserializes_actions() {
+    let content = r#"
+      [
+        { "action": "git_init" },
+        { "action": "chmod", "paths": ["bin/run.sh"], "mode": "755" },
+        { "action": "move", "from": "README-long.md", "to": "README.md", "scopes": [{ "choice": "readme_type", "value": "long" }] },
+        { "action": "delete", "path": "src/.gitkeep" },
+        { "action": "run", "command": "cargo", "args": ["fmt"], "working_dir": "app" }
+      ]
+    "#;
+
+    let actions: Vec<PostProcessingAction> = serde_json::from_str(content).unwrap();
+
+    assert_eq!(
+      actions,
+      vec![
+        PostProcessingAction::new(ActionKind::GitInit),
+        PostProcessingAction::new(ActionKind::Chmod { paths: vec!["bin/run.sh".to_owned()], mode: "755".to_owned() }),
+        PostProcessingAction {
+          kind: ActionKind::Move { from: "README-long.md".to_owned(), to: "README.md".to_owned() },
+          scopes: Some(vec![Scope::new_include_choice_value("readme_type", "long")])
+        },
+        PostProcessingAction::new(ActionKind::Delete { path: "src/.gitkeep".to_owned() }),
+        PostProcessingAction::new(ActionKind::Run { command: "cargo".to_owned(), args: vec!["fmt".to_owned()], working_dir: Some("app".to_owned()) }),
+      ]
+    )
+  }
+
+  #[test]
+  fn fails_to_deserialize_unknown_actions_and_fields() {
+    assert!(serde_json::from_str::<PostProcessingAction>(r#"{ "action": "copy", "path": "README.md" }"#).is_err());
+    assert!(serde_json::from_str::<PostProcessingAction>(r#"{ "action": "delete", "paths": ["README.md"] }"#).is_err())
+  }
+
+  #[test]
+  fn describes_actions() {
+    let action =
+      PostProcessingAction {
+        kind: ActionKind::Run { command: "cargo".to_owned(), args: vec!["fmt".to_owned()], working_dir: Some("app".to_owned()) },
+        scopes: Some(vec![Scope::new_include_choice("formatter")])
+      };
+
+    assert_eq!(action.to_string(), "run cargo fmt in app (include if choice: formatter is chosen with any value)");
+    assert_eq!(action.command_string(), Some("cargo fmt".to_owned()));
+    assert_eq!(PostProcessingAction::new(ActionKind::GitInit).command_string(), None)
+  }
+}
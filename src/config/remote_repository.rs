@@ -0,0 +1,21 @@
+/// A remote repository to process, after any template names or shorthands have been resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteRepository {
+  pub url: String,
+
+  /// Branch, tag or commit to checkout after cloning.
+  pub git_ref: Option<String>,
+
+  /// Directory within the repository that holds the Zat repository.
+  pub subdir: Option<String>,
+}
+
+impl RemoteRepository {
+  pub fn new(url: &str) -> Self {
+    Self {
+      url: url.to_owned(),
+      git_ref: None,
+      subdir: None
+    }
+  }
+}
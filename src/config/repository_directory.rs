@@ -3,6 +3,13 @@ use std::path::Path;
 
 use super::SHELL_HOOK_FILE;
 
+/// Holds the generators of a Zat repository. Each generator is a directory laid out like a Zat repository, with its own
+/// variable file and template files directory.
+pub static GENERATORS_DIR: &str = "generators";
+
+/// Holds the plugins bundled with a Zat repository. Plugin ids are looked up in this directory before the PATH.
+pub static PLUGINS_DIR: &str = "plugins";
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct RepositoryDir {
   path: String
@@ -32,6 +39,43 @@ impl RepositoryDir {
   pub fn shell_hook_file(&self) -> PathBuf {
     self.join(SHELL_HOOK_FILE)
   }
+
+  pub fn generator_dir(&self, generator: &str) -> RepositoryDir {
+    RepositoryDir::from(self.join(GENERATORS_DIR).join(generator).as_path())
+  }
+
+  pub fn plugins_dir(&self) -> PathBuf {
+    self.join(PLUGINS_DIR)
+  }
+
+  /// The path of the plugin if it is bundled in the plugins directory of this repository. Otherwise the plugin id is
+  /// returned as is, so it's found on the PATH (or relative to the current directory).
+  pub fn resolve_plugin(&self, plugin_id: &str) -> String {
+    let bundled_plugin = self.plugins_dir().join(plugin_id);
+
+    if bundled_plugin.is_file() {
+      bundled_plugin.to_string_lossy().to_string()
+    } else {
+      plugin_id.to_owned()
+    }
+  }
+
+  /// The names of the generators defined in this repository, sorted by name.
+  pub fn generator_names(&self) -> Vec<String> {
+    let mut generator_names: Vec<String> =
+      std::fs::read_dir(self.join(GENERATORS_DIR))
+        .map(|entries| {
+          entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect()
+        })
+        .unwrap_or_default();
+
+    generator_names.sort();
+    generator_names
+  }
 }
 
 
@@ -55,3 +99,29 @@ impl Default for RepositoryDir {
   }
 }
 
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+  use tempfile::TempDir;
+
+  #[test]
+  fn resolves_plugins_bundled_with_the_repository() {
+    let repository_dir = TempDir::new().unwrap();
+    std::fs::create_dir(repository_dir.path().join(PLUGINS_DIR)).unwrap();
+    std::fs::write(repository_dir.path().join(PLUGINS_DIR).join("author.sh"), "#!/bin/bash").unwrap();
+
+    let repository_dir = RepositoryDir::from(repository_dir.path());
+
+    assert_eq!(repository_dir.resolve_plugin("author.sh"), repository_dir.plugins_dir().join("author.sh").to_string_lossy().to_string())
+  }
+
+  #[test]
+  fn resolves_other_plugins_to_their_id() {
+    let repository_dir = TempDir::new().unwrap();
+
+    assert_eq!(RepositoryDir::from(repository_dir.path()).resolve_plugin("whoami"), "whoami")
+  }
+}
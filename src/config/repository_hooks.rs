@@ -0,0 +1,89 @@
+use serde::Deserialize;
+use std::format as s;
+use std::fmt::Display;
+
+use super::RepositoryDir;
+use crate::templates::Scope;
+
+/// When a lifecycle hook is run while processing a repository.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HookPhase {
+  /// Once the variables are accepted, before any templates are written. Use it to check for toolchains.
+  PreGeneration,
+
+  /// Once all repositories have been written to the target directory, after the shell hook.
+  PostGeneration,
+}
+
+impl HookPhase {
+  pub const ALL: [HookPhase; 2] = [HookPhase::PreGeneration, HookPhase::PostGeneration];
+}
+
+impl Display for HookPhase {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      HookPhase::PreGeneration => write!(f, "pre-generation"),
+      HookPhase::PostGeneration => write!(f, "post-generation"),
+    }
+  }
+}
+
+
+/// The lifecycle hooks of a repository, by phase. Hooks in each phase are run in the order they are listed.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RepositoryHooks {
+  #[serde(default)]
+  pub pre_generation: Vec<LifecycleHook>,
+
+  #[serde(default)]
+  pub post_generation: Vec<LifecycleHook>,
+}
+
+impl RepositoryHooks {
+
+  pub fn phase(&self, phase: HookPhase) -> &[LifecycleHook] {
+    match phase {
+      HookPhase::PreGeneration => &self.pre_generation,
+      HookPhase::PostGeneration => &self.post_generation,
+    }
+  }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LifecycleHook {
+  /// An executable relative to the repository, or a program on the PATH.
+  pub command: String,
+
+  #[serde(default)]
+  pub args: Vec<String>,
+
+  /// Only runs the hook when these scopes match the selected choices, like the scopes of a variable.
+  #[serde(default)]
+  pub scopes: Option<Vec<Scope>>,
+}
+
+impl LifecycleHook {
+
+  /// The path of the command if it is a file within the repository, otherwise the command as is.
+  pub fn resolve_command(&self, repository_dir: &RepositoryDir) -> String {
+    let command_path = repository_dir.join(&self.command);
+
+    if command_path.is_file() {
+      command_path.to_string_lossy().to_string()
+    } else {
+      self.command.clone()
+    }
+  }
+
+  /// The command line the hook is run with.
+  pub fn command_string(&self) -> String {
+    if self.args.is_empty() {
+      self.command.clone()
+    } else {
+      s!("{} {}", self.command, self.args.join(" "))
+    }
+  }
+}
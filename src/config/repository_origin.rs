@@ -0,0 +1,36 @@
+use std::fmt::{self, Display};
+use std::format as s;
+
+/// Where the repository being processed came from. Plugins and shell hooks of remote repositories and archives are
+/// only run once the user trusts the commit that was checked out, or the archive that was extracted.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum RepositoryOrigin {
+  #[default]
+  Local,
+  Remote { url: String, commit: String },
+
+  /// An extracted archive, identified by the SHA-256 digest of its content.
+  Archive { path: String, sha256: String },
+}
+
+impl RepositoryOrigin {
+
+  /// The source and version the user trusts, or None for local repositories, which are not reviewed.
+  pub fn trust_key(&self) -> Option<(String, String)> {
+    match self {
+      RepositoryOrigin::Local => None,
+      RepositoryOrigin::Remote { url, commit } => Some((url.clone(), commit.clone())),
+      RepositoryOrigin::Archive { path, sha256 } => Some((path.clone(), s!("sha256:{}", sha256))),
+    }
+  }
+}
+
+impl Display for RepositoryOrigin {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      RepositoryOrigin::Local => write!(f, "local"),
+      RepositoryOrigin::Remote { url, commit } => write!(f, "{} at {}", url, commit),
+      RepositoryOrigin::Archive { path, sha256 } => write!(f, "{} with SHA-256 {}", path, sha256),
+    }
+  }
+}
@@ -4,11 +4,19 @@ use super::RepositoryDir;
 use super::TemplateFilesDir;
 use super::Filters;
 use super::IgnoredFiles;
+use super::CollisionPolicy;
+use super::RepositoryOrigin;
+use super::RepositoryHooks;
+use super::PostProcessingAction;
 use crate::logging::Lines;
+use std::collections::BTreeMap;
+use std::time::Duration;
 use std::format as s;
 use std::fmt;
+use serde::Deserialize;
 
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum MenuStyle {
   #[default] // Allows testing through stdin.
   Numbered,
@@ -37,7 +45,31 @@ pub struct UserConfig {
   pub ignores: IgnoredFiles,
   pub verbose: bool,
   pub shell_hook_status: ConfigShellHookStatus,
-  pub menu_style: MenuStyle
+  pub menu_style: MenuStyle,
+  pub variable_defaults: BTreeMap<String, String>,
+
+  /// Further repositories to process into the same target directory, after this repository.
+  pub additional_repository_dirs: Vec<RepositoryDir>,
+  pub collision_policy: CollisionPolicy,
+
+  /// Skips running plugins. Variables are prompted for with their default values instead.
+  pub no_plugins: bool,
+
+  /// Runs plugins again instead of using their cached results.
+  pub no_plugin_cache: bool,
+
+  /// Skips running shell hooks.
+  pub no_hooks: bool,
+
+  /// Stops hooks that run for longer than this. Hooks have no timeout when not set.
+  pub hook_timeout: Option<Duration>,
+  pub origin: RepositoryOrigin,
+
+  /// The lifecycle hooks in the manifest of the repository.
+  pub hooks: RepositoryHooks,
+
+  /// The post-processing actions in the manifest of the repository.
+  pub actions: Vec<PostProcessingAction>,
 }
 
 impl Lines for UserConfig {
@@ -54,7 +86,17 @@ impl Lines for UserConfig {
             ConfigShellHookStatus::NoShellHook => "No shell hook found",
             ConfigShellHookStatus::RunShellHook(_) => "Shell hook found",
         }),
-        s!("Menu Style: {}", self.menu_style)
+        s!("Menu Style: {}", self.menu_style),
+        s!("Variable defaults: {}", self.variable_defaults.iter().map(|(k, v)| s!("{}={}", k, v)).collect::<Vec<_>>().join(", ")),
+        s!("Additional template directories: {}", self.additional_repository_dirs.iter().map(|r| r.path()).collect::<Vec<_>>().join(", ")),
+        s!("On collision: {}", self.collision_policy),
+        s!("Run plugins: {}", !self.no_plugins),
+        s!("Use plugin cache: {}", !self.no_plugin_cache),
+        s!("Run shell hooks: {}", !self.no_hooks),
+        s!("Hook timeout: {}", self.hook_timeout.map(|timeout| s!("{}s", timeout.as_secs())).unwrap_or_else(|| "none".to_owned())),
+        s!("Origin: {}", self.origin),
+        s!("Lifecycle hooks: {}", self.hooks.pre_generation.len() + self.hooks.post_generation.len()),
+        s!("Post-processing actions: {}", self.actions.iter().map(|action| action.to_string()).collect::<Vec<_>>().join(", ")),
       ]
   }
 }
@@ -62,7 +104,43 @@ impl Lines for UserConfig {
 
 impl UserConfig {
 
-  #[cfg(test)]
+  /// A UserConfig for each repository to process, starting with this repository.
+  pub fn repository_configs(&self) -> Vec<UserConfig> {
+    let additional_configs =
+      self
+        .additional_repository_dirs
+        .iter()
+        .map(|repository_dir| self.with_repository(repository_dir));
+
+    std::iter::once(self.clone())
+      .chain(additional_configs)
+      .collect()
+  }
+
+  fn with_repository(&self, repository_dir: &RepositoryDir) -> UserConfig {
+    let shell_hook_file = repository_dir.shell_hook_file();
+
+    let shell_hook_status =
+      if shell_hook_file.exists() {
+        ConfigShellHookStatus::RunShellHook(shell_hook_file.to_string_lossy().to_string())
+      } else {
+        ConfigShellHookStatus::NoShellHook
+      };
+
+    UserConfig {
+      repository_dir: repository_dir.clone(),
+      template_files_dir: TemplateFilesDir::from(repository_dir),
+      shell_hook_status,
+      additional_repository_dirs: vec![],
+      ..self.clone()
+    }
+  }
+
+  /// A UserConfig for reading a repository without processing it into a target directory.
+  pub fn for_repository(repository_dir: &RepositoryDir) -> Self {
+    Self::new(repository_dir.path(), "")
+  }
+
   pub fn new(source_dir: &str, destination_dir: &str) -> Self {
     let repository_dir = RepositoryDir::new(source_dir);
     let template_files_dir = TemplateFilesDir::from(&repository_dir);
@@ -75,7 +153,17 @@ impl UserConfig {
       ignores: Default::default(),
       verbose: Default::default(),
       shell_hook_status: Default::default(),
-      menu_style: Default::default()
+      menu_style: Default::default(),
+      variable_defaults: Default::default(),
+      additional_repository_dirs: Default::default(),
+      collision_policy: Default::default(),
+      no_plugins: Default::default(),
+      no_plugin_cache: Default::default(),
+      no_hooks: Default::default(),
+      hook_timeout: Default::default(),
+      origin: Default::default(),
+      hooks: Default::default(),
+      actions: vec![]
     }
   }
 }
@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+
+use super::user_config::MenuStyle;
+use super::ZatConfigDir;
+use crate::error::{ZatError, ZatResult};
+
+pub const USER_DEFAULTS_FILE: &str = "config.toml";
+
+/// User-level defaults for every invocation of Zat. Stored as TOML:
+///
+/// choice_menu_style = "numbered"
+/// ignores = ["\\.idea"]
+/// verbose = false
+///
+/// [variables]
+/// author_email = "me@example.com"
+///
+/// Flags supplied on the command line take precedence over these values.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserDefaults {
+  pub choice_menu_style: Option<MenuStyle>,
+
+  #[serde(default)]
+  pub ignores: Vec<String>,
+
+  pub verbose: Option<bool>,
+
+  /// Default values for template variables, by variable name.
+  #[serde(default)]
+  pub variables: BTreeMap<String, String>,
+}
+
+impl UserDefaults {
+
+  /// The menu style supplied on the command line, then the one in the config file, then the selection menu.
+  pub fn menu_style(&self, cli_menu_style: Option<MenuStyle>) -> MenuStyle {
+    cli_menu_style
+      .or_else(|| self.choice_menu_style.clone())
+      .unwrap_or(MenuStyle::Selection)
+  }
+
+  /// Ignores from the config file are added to those supplied on the command line.
+  pub fn ignores(&self, cli_ignores: Vec<String>) -> Vec<String> {
+    self
+      .ignores
+      .iter()
+      .cloned()
+      .chain(cli_ignores)
+      .collect()
+  }
+
+  /// Verbose logging supplied on the command line with --verbose or --no-verbose, then the config file, then off.
+  pub fn verbose(&self, cli_verbose: Option<bool>) -> bool {
+    cli_verbose
+      .or(self.verbose)
+      .unwrap_or(false)
+  }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserDefaultsFile {
+  path: PathBuf
+}
+
+impl UserDefaultsFile {
+
+  pub fn path(&self) -> &Path {
+    self.path.as_path()
+  }
+
+  /// Returns empty defaults if the config file does not exist.
+  pub fn load(&self) -> ZatResult<UserDefaults> {
+    let path = self.path.to_string_lossy().to_string();
+
+    if self.path.exists() {
+      let content = fs::read_to_string(&self.path).map_err(|e| ZatError::could_not_read_zat_config_file(&path, e.to_string()))?;
+      toml::from_str(&content).map_err(|e| ZatError::could_not_decode_zat_config_file(&path, e.to_string()))
+    } else {
+      Ok(UserDefaults::default())
+    }
+  }
+}
+
+impl From<&ZatConfigDir> for UserDefaultsFile {
+  fn from(config_dir: &ZatConfigDir) -> Self {
+    Self {
+      path: config_dir.join(USER_DEFAULTS_FILE)
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+  use tempfile::TempDir;
+
+  #[test]
+  fn loads_empty_defaults_if_the_file_does_not_exist() {
+    let config_dir = TempDir::new().unwrap();
+    let defaults_file = UserDefaultsFile::from(&ZatConfigDir::from(config_dir.path()));
+
+    assert_eq!(defaults_file.load(), Ok(UserDefaults::default()))
+  }
+
+  #[test]
+  fn loads_defaults() {
+    let config_dir = TempDir::new().unwrap();
+    let defaults_file = UserDefaultsFile::from(&ZatConfigDir::from(config_dir.path()));
+
+    let content = r#"
+      choice_menu_style = "numbered"
+      ignores = ["\\.idea", "target"]
+      verbose = true
+
+      [variables]
+      author_email = "me@example.com"
+    "#;
+
+    fs::write(defaults_file.path(), content).unwrap();
+
+    let expected_defaults =
+      UserDefaults {
+        choice_menu_style: Some(MenuStyle::Numbered),
+        ignores: vec!["\\.idea".to_owned(), "target".to_owned()],
+        verbose: Some(true),
+        variables: BTreeMap::from([("author_email".to_owned(), "me@example.com".to_owned())])
+      };
+
+    assert_eq!(defaults_file.load(), Ok(expected_defaults))
+  }
+
+  #[test]
+  fn fails_to_load_unknown_fields() {
+    let config_dir = TempDir::new().unwrap();
+    let defaults_file = UserDefaultsFile::from(&ZatConfigDir::from(config_dir.path()));
+    fs::write(defaults_file.path(), "menu_style = \"numbered\"").unwrap();
+
+    match defaults_file.load() {
+      Err(ZatError::ZatConfigError(crate::error::zat_config_error_reason::ZatConfigErrorReason::CouldNotDecodeFile(..))) => (),
+      other => panic!("Expected CouldNotDecodeFile but got: {:?}", other)
+    }
+  }
+
+  #[test]
+  fn cli_values_take_precedence() {
+    let defaults =
+      UserDefaults {
+        choice_menu_style: Some(MenuStyle::Numbered),
+        ignores: vec!["target".to_owned()],
+        verbose: Some(false),
+        variables: BTreeMap::new()
+      };
+
+    assert_eq!(defaults.menu_style(Some(MenuStyle::Selection)), MenuStyle::Selection);
+    assert_eq!(defaults.menu_style(None), MenuStyle::Numbered);
+    assert_eq!(defaults.ignores(vec!["node_modules".to_owned()]), vec!["target".to_owned(), "node_modules".to_owned()]);
+    assert!(defaults.verbose(Some(true)));
+
+    let verbose_defaults = UserDefaults { verbose: Some(true), ..defaults };
+    assert!(!verbose_defaults.verbose(Some(false)));
+    assert!(verbose_defaults.verbose(None))
+  }
+
+  #[test]
+  fn built_in_values_are_used_without_defaults() {
+    let defaults = UserDefaults::default();
+
+    assert_eq!(defaults.menu_style(None), MenuStyle::Selection);
+    assert_eq!(defaults.ignores(vec![]), Vec::<String>::new());
+    assert!(!defaults.verbose(None))
+  }
+}
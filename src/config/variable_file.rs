@@ -1,15 +1,74 @@
 use std::path::Path;
+use std::fmt;
 use super::RepositoryDir;
+use crate::error::{ZatError, ZatResult};
 
 #[derive(Debug, Clone)]
 pub struct VariableFile {
-  path: String
+  path: String,
+  format: VariableFileFormat
 }
 
 pub const DOT_VARIABLES_PROMPT: &str  = ".variables.zat-prompt";
+pub const DOT_VARIABLES_PROMPT_YAML: &str  = ".variables.zat-prompt.yaml";
+pub const DOT_VARIABLES_PROMPT_TOML: &str  = ".variables.zat-prompt.toml";
+
+/// The formats a variable file can be written in. The format is detected from the file extension.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VariableFileFormat {
+  Json,
+  Yaml,
+  Toml
+}
+
+impl fmt::Display for VariableFileFormat {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let label = match self {
+      VariableFileFormat::Json => "JSON",
+      VariableFileFormat::Yaml => "YAML",
+      VariableFileFormat::Toml => "TOML",
+    };
+
+    write!(f, "{}", label)
+  }
+}
 
 impl VariableFile {
 
+  /// Finds the variable file in the repository directory. It is an error to have more than one variable file.
+  /// If there are no variable files, the JSON variable file is returned so that its absence can be reported.
+  pub fn find(repository_dir: &RepositoryDir) -> ZatResult<VariableFile> {
+    let candidates =
+      [
+        (DOT_VARIABLES_PROMPT, VariableFileFormat::Json),
+        (DOT_VARIABLES_PROMPT_YAML, VariableFileFormat::Yaml),
+        (DOT_VARIABLES_PROMPT_TOML, VariableFileFormat::Toml),
+      ];
+
+    let existing_files: Vec<VariableFile> =
+      candidates
+        .into_iter()
+        .map(|(file_name, format)| VariableFile::new(repository_dir, file_name, format))
+        .filter(|variable_file| variable_file.does_exist())
+        .collect();
+
+    match existing_files.as_slice() {
+      [] => Ok(VariableFile::from(repository_dir.clone())),
+      [variable_file] => Ok(variable_file.clone()),
+      multiple => {
+        let paths: Vec<&str> = multiple.iter().map(|variable_file| variable_file.get_path()).collect();
+        Err(ZatError::multiple_variable_files(&paths))
+      }
+    }
+  }
+
+  fn new(repository_dir: &RepositoryDir, file_name: &str, format: VariableFileFormat) -> Self {
+    VariableFile {
+      path: repository_dir.join(file_name).display().to_string(),
+      format
+    }
+  }
+
   pub fn does_exist(&self) -> bool {
     Path::new(&self.path).exists()
   }
@@ -17,14 +76,15 @@ impl VariableFile {
   pub fn get_path(&self) -> &str {
     self.path.as_str()
   }
+
+  pub fn format(&self) -> VariableFileFormat {
+    self.format
+  }
 }
 
 impl From<RepositoryDir> for VariableFile {
   fn from(template_dir: RepositoryDir) -> Self {
-      let variables_file = template_dir.join(DOT_VARIABLES_PROMPT);
-      VariableFile {
-        path: variables_file.display().to_string()
-      }
+      VariableFile::new(&template_dir, DOT_VARIABLES_PROMPT, VariableFileFormat::Json)
   }
 }
 
@@ -33,4 +93,3 @@ impl AsRef<Path> for VariableFile {
     Path::new(&self.path)
   }
 }
-
@@ -0,0 +1,40 @@
+use std::path::{Path, PathBuf};
+use crate::error::{ZatError, ZatResult};
+
+/// Environment variable used to override the Zat configuration directory.
+pub const ZAT_CONFIG_DIR_ENV: &str = "ZAT_CONFIG_DIR";
+
+/// The directory Zat stores user-level files in, such as the template registry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZatConfigDir {
+  path: PathBuf
+}
+
+impl ZatConfigDir {
+
+  /// Uses the directory in `ZAT_CONFIG_DIR` if set, otherwise `~/.config/zat`.
+  pub fn new() -> ZatResult<Self> {
+    match std::env::var_os(ZAT_CONFIG_DIR_ENV) {
+      Some(config_dir) if !config_dir.is_empty() => Ok(Self::from(Path::new(&config_dir))),
+      _ => {
+        dirs::home_dir()
+          .map(|home| Self::from(home.join(".config").join("zat").as_path()))
+          .ok_or_else(ZatError::zat_config_dir_not_found)
+      }
+    }
+  }
+
+  pub fn join<P>(&self, other: P) -> PathBuf where
+    P: AsRef<Path>
+  {
+    self.path.join(other)
+  }
+}
+
+impl From<&Path> for ZatConfigDir {
+  fn from(path: &Path) -> Self {
+    Self {
+      path: path.to_owned()
+    }
+  }
+}
@@ -0,0 +1,26 @@
+use super::error_format::ErrorFormat;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum DescribeCommandErrorReason {
+  InvalidIgnore(String, String, String),
+  CouldNotReadShellHook(String, String, String),
+  CouldNotWriteDescription(String, String, String),
+}
+
+
+impl From<&DescribeCommandErrorReason> for ErrorFormat {
+    fn from(error: &DescribeCommandErrorReason) -> Self {
+      let (error_reason, exception, remediation) =
+        match error {
+          DescribeCommandErrorReason::InvalidIgnore(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          DescribeCommandErrorReason::CouldNotReadShellHook(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          DescribeCommandErrorReason::CouldNotWriteDescription(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+      };
+
+      ErrorFormat {
+        error_reason,
+        exception,
+        remediation,
+      }
+    }
+}
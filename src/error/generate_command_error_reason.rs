@@ -0,0 +1,26 @@
+use super::error_format::ErrorFormat;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GenerateCommandErrorReason {
+  GeneratorDoesNotExist(String, String),
+  TargetDirectoryDoesNotExist(String, String),
+  CouldNotCreatePathPrefix(String, String, String),
+}
+
+
+impl From<&GenerateCommandErrorReason> for ErrorFormat {
+    fn from(error: &GenerateCommandErrorReason) -> Self {
+      let (error_reason, exception, remediation) =
+        match error {
+          GenerateCommandErrorReason::GeneratorDoesNotExist(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          GenerateCommandErrorReason::TargetDirectoryDoesNotExist(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          GenerateCommandErrorReason::CouldNotCreatePathPrefix(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+      };
+
+      ErrorFormat {
+        error_reason,
+        exception,
+        remediation,
+      }
+    }
+}
@@ -0,0 +1,25 @@
+use super::ErrorFormat;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum InheritanceErrorReason {
+  ParentRepositoryDoesNotExist(String, String),
+  ParentRepositoryCycle(String, String),
+  TemplatesCantBeLayered(String, String, String),
+}
+
+impl From<&InheritanceErrorReason> for ErrorFormat {
+  fn from(error: &InheritanceErrorReason) -> Self {
+
+    let (error, exception, fix) = match error {
+        InheritanceErrorReason::ParentRepositoryDoesNotExist(error, fix) => (error, None, fix),
+        InheritanceErrorReason::ParentRepositoryCycle(error, fix) => (error, None, fix),
+        InheritanceErrorReason::TemplatesCantBeLayered(error, exception, fix) => (error, Some(exception), fix),
+    };
+
+    ErrorFormat {
+      error_reason: error.to_owned(),
+      exception: exception.cloned(),
+      remediation: Some(fix.to_owned())
+    }
+  }
+}
@@ -0,0 +1,28 @@
+use super::ErrorFormat;
+
+#[derive(Debug, PartialEq, Clone)]
+#[allow(clippy::enum_variant_names)]
+pub enum ManifestFileErrorReason {
+  ManifestReadError(String, String, String),
+  ManifestDecodeError(String, String, String),
+  InvalidMinZatVersion(String, String, String),
+  ZatVersionTooOld(String, String),
+}
+
+impl From<&ManifestFileErrorReason> for ErrorFormat {
+  fn from(error: &ManifestFileErrorReason) -> Self {
+
+    let (error, exception, fix) = match error {
+        ManifestFileErrorReason::ManifestReadError(error, exception, fix) => (error, Some(exception), fix),
+        ManifestFileErrorReason::ManifestDecodeError(error, exception, fix) => (error, Some(exception), fix),
+        ManifestFileErrorReason::InvalidMinZatVersion(error, exception, fix) => (error, Some(exception), fix),
+        ManifestFileErrorReason::ZatVersionTooOld(error, fix) => (error, None, fix),
+    };
+
+    ErrorFormat {
+      error_reason: error.to_owned(),
+      exception: exception.cloned(),
+      remediation: Some(fix.to_owned())
+    }
+  }
+}
@@ -2,12 +2,21 @@ pub mod zat_error;
 pub mod error_format;
 pub mod user_config_error_reason;
 pub mod variable_file_error_reason;
+pub mod manifest_file_error_reason;
+pub mod inheritance_error_reason;
 pub mod template_processing_error_reason;
 pub mod post_processing_error_reason;
 pub mod bootstrap_command_error_reason;
 pub mod process_remote_command_error_reason;
+pub mod process_archive_command_error_reason;
+pub mod generate_command_error_reason;
+pub mod templatize_command_error_reason;
+pub mod test_command_error_reason;
+pub mod describe_command_error_reason;
+pub mod validate_command_error_reason;
 pub mod plugin_error_reason;
 pub mod generic_error_reason;
+pub mod zat_config_error_reason;
 
 pub use zat_error::ZatAction;
 pub use zat_error::ZatError;
@@ -16,13 +25,22 @@ pub use zat_error::ZatResult;
 use error_format::ErrorFormat;
 use user_config_error_reason::UserConfigErrorReason;
 use variable_file_error_reason::VariableFileErrorReason;
+use manifest_file_error_reason::ManifestFileErrorReason;
+use inheritance_error_reason::InheritanceErrorReason;
 use template_processing_error_reason::TemplateProcessingErrorReason;
 use template_processing_error_reason::ReasonFileErrorReason;
 use post_processing_error_reason::PostProcessingErrorReason;
 use bootstrap_command_error_reason::BootstrapCommandErrorReason;
 use process_remote_command_error_reason::ProcessRemoteCommandErrorReason;
+use process_archive_command_error_reason::ProcessArchiveCommandErrorReason;
+use generate_command_error_reason::GenerateCommandErrorReason;
+use templatize_command_error_reason::TemplatizeCommandErrorReason;
+use test_command_error_reason::TestCommandErrorReason;
+use describe_command_error_reason::DescribeCommandErrorReason;
+use validate_command_error_reason::ValidateCommandErrorReason;
 use plugin_error_reason::PluginErrorReason;
 use generic_error_reason::GenericErrorReason;
+use zat_config_error_reason::ZatConfigErrorReason;
 
 #[cfg(test)]
 pub use zat_error::ProcessCommandErrorReason;
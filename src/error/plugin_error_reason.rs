@@ -9,6 +9,12 @@ pub enum PluginErrorReason {
   CouldNotDecodePluginOutputToUtf8(String, String, String, String),
   CouldNotDecodePluginStdErrToUtf8(String, String, String, String),
   CouldNotDecodePluginResultToJson(String, String, String, String),
+  PluginDependencyNotAnswered(String, String, String),
+  PluginTimedOut(String, String, String),
+  PluginReturnedUnexpectedResult(String, String, String),
+  InvalidPluginChoices(String, String, String),
+  PluginProtocolNotSupported(String, String, String),
+  PluginIdIsOutsidePluginsDir(String, String, String),
 }
 
 impl From<&PluginErrorReason> for ErrorFormat {
@@ -22,6 +28,12 @@ impl From<&PluginErrorReason> for ErrorFormat {
       PluginErrorReason::CouldNotDecodePluginOutputToUtf8(plugin_name, error, exception, fix) => (plugin_name, error.to_owned(), Some(exception.to_owned()), fix.to_owned()),
       PluginErrorReason::CouldNotDecodePluginStdErrToUtf8(plugin_name, error, exception, fix) => (plugin_name, error.to_owned(), Some(exception.to_owned()), fix.to_owned()),
       PluginErrorReason::CouldNotDecodePluginResultToJson(plugin_name, error, exception, fix) => (plugin_name, error.to_owned(), Some(exception.to_owned()), fix.to_owned()),
+      PluginErrorReason::PluginDependencyNotAnswered(plugin_name, error, fix) => (plugin_name, error.to_owned(), None, fix.to_owned()),
+      PluginErrorReason::PluginTimedOut(plugin_name, error, fix) => (plugin_name, error.to_owned(), None, fix.to_owned()),
+      PluginErrorReason::PluginReturnedUnexpectedResult(plugin_name, error, fix) => (plugin_name, error.to_owned(), None, fix.to_owned()),
+      PluginErrorReason::InvalidPluginChoices(plugin_name, error, fix) => (plugin_name, error.to_owned(), None, fix.to_owned()),
+      PluginErrorReason::PluginProtocolNotSupported(plugin_name, error, fix) => (plugin_name, error.to_owned(), None, fix.to_owned()),
+      PluginErrorReason::PluginIdIsOutsidePluginsDir(plugin_name, error, fix) => (plugin_name, error.to_owned(), None, fix.to_owned()),
     };
 
     let error_reason = s!("Plugin '{}' returned the following error: {}", plugin_name, error);
@@ -3,8 +3,10 @@ use super::ErrorFormat;
 #[derive(Debug, PartialEq, Clone)]
 pub enum PostProcessingErrorReason {
   ExecutionError(String, String, String),
-  NonZeroStatusCode(String, String),
-  ProcessInterrupted(String, String),
+  NonZeroStatusCode(String, Option<String>, String),
+  ProcessInterrupted(String, Option<String>, String),
+  TimedOut(String, Option<String>, String),
+  InvalidAction(String, String),
 }
 
 impl From<&PostProcessingErrorReason> for ErrorFormat {
@@ -12,8 +14,10 @@ impl From<&PostProcessingErrorReason> for ErrorFormat {
 
     let (error, exception, fix) = match error {
         PostProcessingErrorReason::ExecutionError(error, exception, fix) => (error, Some(exception), fix),
-        PostProcessingErrorReason::NonZeroStatusCode(error, fix) => (error, None, fix),
-        PostProcessingErrorReason::ProcessInterrupted(error, fix) => (error, None, fix),
+        PostProcessingErrorReason::NonZeroStatusCode(error, exception, fix) => (error, exception.as_ref(), fix),
+        PostProcessingErrorReason::ProcessInterrupted(error, exception, fix) => (error, exception.as_ref(), fix),
+        PostProcessingErrorReason::TimedOut(error, exception, fix) => (error, exception.as_ref(), fix),
+        PostProcessingErrorReason::InvalidAction(error, fix) => (error, None, fix),
     };
 
     ErrorFormat {
@@ -0,0 +1,34 @@
+use super::error_format::ErrorFormat;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ProcessArchiveCommandErrorReason {
+  ArchiveUrlIsInvalid(String, String, String),
+  ArchiveDoesNotExist(String, String),
+  UnsupportedArchiveFormat(String, String),
+  CouldNotCreateExtractionDirectory(String, String, String),
+  CouldNotReadArchive(String, String, String),
+  ArchiveEntryEscapesExtractionDirectory(String, String),
+  CouldNotExtractArchive(String, String, String),
+}
+
+
+impl From<&ProcessArchiveCommandErrorReason> for ErrorFormat {
+    fn from(error: &ProcessArchiveCommandErrorReason) -> Self {
+      let (error_reason, exception, remediation) =
+        match error {
+          ProcessArchiveCommandErrorReason::ArchiveUrlIsInvalid(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          ProcessArchiveCommandErrorReason::ArchiveDoesNotExist(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          ProcessArchiveCommandErrorReason::UnsupportedArchiveFormat(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          ProcessArchiveCommandErrorReason::CouldNotCreateExtractionDirectory(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          ProcessArchiveCommandErrorReason::CouldNotReadArchive(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          ProcessArchiveCommandErrorReason::ArchiveEntryEscapesExtractionDirectory(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          ProcessArchiveCommandErrorReason::CouldNotExtractArchive(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+      };
+
+      ErrorFormat {
+        error_reason,
+        exception,
+        remediation,
+      }
+    }
+}
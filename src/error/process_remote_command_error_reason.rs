@@ -8,6 +8,13 @@ pub enum ProcessRemoteCommandErrorReason {
   CouldNotCreateCheckoutDirectoryStructure(String, String, String),
   GitCloneFailed(String, String, String),
   GitCloneStatusError(String, String),
+  GitCheckoutFailed(String, String, String),
+  GitCheckoutStatusError(String, String),
+  GitCommitFailed(String, String, String),
+  CouldNotReadShellHook(String, String, String),
+  RepositoryNotTrusted(String, String),
+  SubdirIsOutsideRepository(String, String),
+  GitRefIsInvalid(String, String),
 }
 
 
@@ -21,6 +28,13 @@ impl From<&ProcessRemoteCommandErrorReason> for ErrorFormat {
           ProcessRemoteCommandErrorReason::CouldNotCreateCheckoutDirectoryStructure(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
           ProcessRemoteCommandErrorReason::GitCloneFailed(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
           ProcessRemoteCommandErrorReason::GitCloneStatusError(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          ProcessRemoteCommandErrorReason::GitCheckoutFailed(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          ProcessRemoteCommandErrorReason::GitCheckoutStatusError(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          ProcessRemoteCommandErrorReason::GitCommitFailed(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          ProcessRemoteCommandErrorReason::CouldNotReadShellHook(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          ProcessRemoteCommandErrorReason::RepositoryNotTrusted(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          ProcessRemoteCommandErrorReason::SubdirIsOutsideRepository(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          ProcessRemoteCommandErrorReason::GitRefIsInvalid(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
       };
 
       ErrorFormat {
@@ -6,6 +6,8 @@ pub enum TemplateProcessingErrorReason {
   NoFilesToProcessError(String, String),
   ReadingFileError(ReasonFileErrorReason),
   WritingFileError(String, String, String),
+  FileAlreadyExistsError(String, String),
+  InsertionError(String, String),
   DirectoryCreationError(String, String, String),
   TeraTemplateRenderingError(String, String,String),
 }
@@ -26,6 +28,8 @@ impl From<&TemplateProcessingErrorReason> for ErrorFormat {
         TemplateProcessingErrorReason::ReadingFileError(ReasonFileErrorReason::UnsupportedContentError(error, exception, fix)) => (error, Some(exception), fix),
         TemplateProcessingErrorReason::ReadingFileError(ReasonFileErrorReason::PrefixError(error, exception, fix)) => (error, Some(exception), fix),
         TemplateProcessingErrorReason::WritingFileError(error, exception, fix) => (error, Some(exception), fix),
+        TemplateProcessingErrorReason::FileAlreadyExistsError(error, fix) => (error, None, fix),
+        TemplateProcessingErrorReason::InsertionError(error, fix) => (error, None, fix),
         TemplateProcessingErrorReason::DirectoryCreationError(error, exception, fix) => (error, Some(exception), fix),
         TemplateProcessingErrorReason::TeraTemplateRenderingError(error, exception, fix) => (error, Some(exception), fix),
     };
@@ -0,0 +1,32 @@
+use super::error_format::ErrorFormat;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TemplatizeCommandErrorReason {
+  SourceDirectoryDoesNotExist(String, String),
+  RepositoryDirectoryShouldNotExist(String, String),
+  InvalidReplacement(String, String),
+  InvalidIgnore(String, String, String),
+  CouldNotReadSourceFile(String, String, String),
+  CouldNotCreateFile(String, String, String),
+}
+
+
+impl From<&TemplatizeCommandErrorReason> for ErrorFormat {
+    fn from(error: &TemplatizeCommandErrorReason) -> Self {
+      let (error_reason, exception, remediation) =
+        match error {
+          TemplatizeCommandErrorReason::SourceDirectoryDoesNotExist(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          TemplatizeCommandErrorReason::RepositoryDirectoryShouldNotExist(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          TemplatizeCommandErrorReason::InvalidReplacement(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          TemplatizeCommandErrorReason::InvalidIgnore(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          TemplatizeCommandErrorReason::CouldNotReadSourceFile(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          TemplatizeCommandErrorReason::CouldNotCreateFile(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+      };
+
+      ErrorFormat {
+        error_reason,
+        exception,
+        remediation,
+      }
+    }
+}
@@ -0,0 +1,28 @@
+use super::error_format::ErrorFormat;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TestCommandErrorReason {
+  NoTestCases(String, String),
+  CouldNotRunTestCase(String, String, String),
+  CouldNotBlessTestCase(String, String, String),
+  TestCasesFailed(String, String),
+}
+
+
+impl From<&TestCommandErrorReason> for ErrorFormat {
+    fn from(error: &TestCommandErrorReason) -> Self {
+      let (error_reason, exception, remediation) =
+        match error {
+          TestCommandErrorReason::NoTestCases(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+          TestCommandErrorReason::CouldNotRunTestCase(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          TestCommandErrorReason::CouldNotBlessTestCase(error, exception, remediation) => (error.to_owned(), Some(exception.to_owned()), Some(remediation.to_owned())),
+          TestCommandErrorReason::TestCasesFailed(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+      };
+
+      ErrorFormat {
+        error_reason,
+        exception,
+        remediation,
+      }
+    }
+}
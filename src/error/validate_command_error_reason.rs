@@ -0,0 +1,22 @@
+use super::error_format::ErrorFormat;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ValidateCommandErrorReason {
+  RepositoryNotValid(String, String),
+}
+
+
+impl From<&ValidateCommandErrorReason> for ErrorFormat {
+    fn from(error: &ValidateCommandErrorReason) -> Self {
+      let (error_reason, exception, remediation) =
+        match error {
+          ValidateCommandErrorReason::RepositoryNotValid(error, remediation) => (error.to_owned(), None, Some(remediation.to_owned())),
+      };
+
+      ErrorFormat {
+        error_reason,
+        exception,
+        remediation,
+      }
+    }
+}
@@ -8,6 +8,9 @@ pub enum VariableFileErrorReason {
   VariableReadError(String, String),
   VariableDecodeError(String, String),
   VariableFileHasNoVariableDefinitions(String, String),
+  MultipleVariableFiles(String, String),
+  VariableSchemaError(String, String),
+  ConflictingVariableFilters(String, String),
 }
 
 impl From<&VariableFileErrorReason> for ErrorFormat {
@@ -19,6 +22,9 @@ impl From<&VariableFileErrorReason> for ErrorFormat {
         VariableFileErrorReason::VariableReadError(error, fix) => (error, fix),
         VariableFileErrorReason::VariableDecodeError(error, fix) => (error, fix),
         VariableFileErrorReason::VariableFileHasNoVariableDefinitions(error, fix) => (error, fix),
+        VariableFileErrorReason::MultipleVariableFiles(error, fix) => (error, fix),
+        VariableFileErrorReason::VariableSchemaError(error, fix) => (error, fix),
+        VariableFileErrorReason::ConflictingVariableFilters(error, fix) => (error, fix),
     };
 
     ErrorFormat {
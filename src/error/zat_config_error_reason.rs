@@ -0,0 +1,32 @@
+use super::ErrorFormat;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ZatConfigErrorReason {
+  ConfigDirectoryNotFound(String, String),
+  CouldNotReadFile(String, String, String),
+  CouldNotDecodeFile(String, String, String),
+  CouldNotWriteFile(String, String, String),
+  TemplateNotFound(String, String),
+  TemplateAlreadyExists(String, String),
+  InvalidTemplateShorthand(String, String),
+}
+
+impl From<&ZatConfigErrorReason> for ErrorFormat {
+  fn from(error: &ZatConfigErrorReason) -> Self {
+    let (error, exception, fix) = match error {
+        ZatConfigErrorReason::ConfigDirectoryNotFound(error, fix) => (error, None, fix),
+        ZatConfigErrorReason::CouldNotReadFile(error, exception, fix) => (error, Some(exception), fix),
+        ZatConfigErrorReason::CouldNotDecodeFile(error, exception, fix) => (error, Some(exception), fix),
+        ZatConfigErrorReason::CouldNotWriteFile(error, exception, fix) => (error, Some(exception), fix),
+        ZatConfigErrorReason::TemplateNotFound(error, fix) => (error, None, fix),
+        ZatConfigErrorReason::TemplateAlreadyExists(error, fix) => (error, None, fix),
+        ZatConfigErrorReason::InvalidTemplateShorthand(error, fix) => (error, None, fix),
+    };
+
+    ErrorFormat {
+      error_reason: error.to_owned(),
+      exception: exception.cloned(),
+      remediation: Some(fix.to_owned())
+    }
+  }
+}
@@ -1,17 +1,26 @@
 
 use format as s;
-use url::Url;
 use super::ErrorFormat;
 use super::GenericErrorReason;
 use super::UserConfigErrorReason;
 use super::VariableFileErrorReason;
+use super::ManifestFileErrorReason;
+use super::InheritanceErrorReason;
 use super::TemplateProcessingErrorReason;
 use super::ReasonFileErrorReason;
 use super::PostProcessingErrorReason;
 use super::BootstrapCommandErrorReason;
 use super::ProcessRemoteCommandErrorReason;
+use super::ProcessArchiveCommandErrorReason;
+use super::GenerateCommandErrorReason;
+use super::TemplatizeCommandErrorReason;
+use super::TestCommandErrorReason;
+use super::DescribeCommandErrorReason;
+use super::ValidateCommandErrorReason;
 use super::PluginErrorReason;
+use super::ZatConfigErrorReason;
 use ansi_term::Color::Yellow;
+use std::time::Duration;
 
 pub type ZatResult<A> = Result<A, ZatError>;
 pub type ZatAction = Result<(), ZatError>;
@@ -23,7 +32,14 @@ pub enum ZatError {
   ProcessCommandError(ProcessCommandErrorReason),
   BootstrapCommandError(BootstrapCommandErrorReason),
   ProcessRemoteCommandError(ProcessRemoteCommandErrorReason),
-  PluginError(PluginErrorReason)
+  ProcessArchiveCommandError(ProcessArchiveCommandErrorReason),
+  GenerateCommandError(GenerateCommandErrorReason),
+  TemplatizeCommandError(TemplatizeCommandErrorReason),
+  TestCommandError(TestCommandErrorReason),
+  DescribeCommandError(DescribeCommandErrorReason),
+  ValidateCommandError(ValidateCommandErrorReason),
+  PluginError(PluginErrorReason),
+  ZatConfigError(ZatConfigErrorReason),
 
 }
 
@@ -32,6 +48,8 @@ pub enum ZatError {
 pub enum ProcessCommandErrorReason {
   UserConfigError(UserConfigErrorReason),
   VariableFileError(VariableFileErrorReason),
+  ManifestFileError(ManifestFileErrorReason),
+  InheritanceError(InheritanceErrorReason),
   TemplateProcessingError(TemplateProcessingErrorReason),
   PostProcessingError(PostProcessingErrorReason),
 }
@@ -54,6 +72,8 @@ impl ZatError {
     let exception_section = match error.exception {
         Some(exception) => {
           let exception_heading = ZatError::heading("Exception");
+          // Exceptions such as the output of a shell hook can span several lines
+          let exception = exception.replace('\n', &s!("\n{}", content_indent));
           s!("\n\n{}{}\n{}{}", heading_indent, exception_heading, content_indent, exception)
         },
         None => "".to_owned(),
@@ -162,12 +182,140 @@ impl ZatError {
     )
   }
 
-  pub fn variable_file_cant_be_decoded(path: &str, reason: &str) -> ZatError {
+  pub fn variable_file_cant_be_decoded(path: &str, format: &str, reason: &str) -> ZatError {
     ZatError::ProcessCommandError(
       ProcessCommandErrorReason::VariableFileError(
         VariableFileErrorReason::VariableDecodeError(
-          s!("Variable file '{}' could not be decoded as JSON into the expected format. It failed decoding with this error: {}. Zat uses this file to retrieve tokens that will be replaced when rendering the templates.", path, reason),
-          s!("Make the variable file '{}' is a valid JSON file in the format required by Zat. See `zat --help` for more details on the format", path)
+          s!("Variable file '{}' could not be decoded as {} into the expected format. It failed decoding with this error: {}. Zat uses this file to retrieve tokens that will be replaced when rendering the templates.", path, format, reason),
+          s!("Make the variable file '{}' is a valid {} file in the format required by Zat. See `zat --help` for more details on the format", path, format)
+        )
+      )
+    )
+  }
+
+  /// Each violation is a (location, message, fix) triple.
+  pub fn variable_file_does_not_match_schema(path: &str, violations: &[(String, String, String)]) -> ZatError {
+    let problems = violations.iter().map(|(location, message, _)| s!("\n    - {}: {}", location, message)).collect::<String>();
+    let fixes = violations.iter().map(|(location, _, fix)| s!("\n    - {}: {}", location, fix)).collect::<String>();
+
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::VariableFileError(
+        VariableFileErrorReason::VariableSchemaError(
+          s!("Variable file '{}' does not match the format required by Zat. It has the following problems:{}", path, problems),
+          s!("Please fix each problem in the variable file. See 'dot-variables.schema.json' in the Zat repository for the full format:{}", fixes)
+        )
+      )
+    )
+  }
+
+  pub fn variable_has_conflicting_filters(variable_name: &str, filter_name: &str, filter: &str, other_filter: &str) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::VariableFileError(
+        VariableFileErrorReason::ConflictingVariableFilters(
+          s!("Variable '{}' is defined by more than one repository with a filter named '{}', but the filters differ: {} and {}. Zat can't tell which one to use for '{}'.", variable_name, filter_name, filter, other_filter, filter_name),
+          s!("Please use the same filter for '{}' in each repository, or give one of the filters a different name.", filter_name)
+        )
+      )
+    )
+  }
+
+  pub fn multiple_variable_files(paths: &[&str]) -> ZatError {
+    let files = paths.iter().map(|path| s!("'{}'", path)).collect::<Vec<_>>().join(", ");
+
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::VariableFileError(
+        VariableFileErrorReason::MultipleVariableFiles(
+          s!("Found more than one variable file: {}. Zat can only read variables from a single file.", files),
+          "Please keep only one of the JSON, YAML or TOML variable files.".to_owned()
+        )
+      )
+    )
+  }
+
+  // -------------------------------------------------------------------------------------------------------------------
+  // ManifestFileError
+  // -------------------------------------------------------------------------------------------------------------------
+
+  pub fn manifest_file_cant_be_read(path: &str, exception: String) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::ManifestFileError(
+        ManifestFileErrorReason::ManifestReadError(
+          s!("Manifest file '{}' could not be read. Zat uses this file to describe the template and the Zat versions it supports.", path),
+          exception,
+          s!("Make sure Zat can open and read the manifest file '{}' and has the required file permissions.", path)
+        )
+      )
+    )
+  }
+
+  pub fn manifest_file_cant_be_decoded(path: &str, exception: String) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::ManifestFileError(
+        ManifestFileErrorReason::ManifestDecodeError(
+          s!("Manifest file '{}' could not be decoded as JSON into the expected format.", path),
+          exception,
+          s!("Make sure the manifest file '{}' is a valid JSON file in the format required by Zat. See the user manual for more details on the format.", path)
+        )
+      )
+    )
+  }
+
+  pub fn invalid_min_zat_version(path: &str, min_zat_version: &str, exception: String) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::ManifestFileError(
+        ManifestFileErrorReason::InvalidMinZatVersion(
+          s!("The 'min_zat_version' of '{}' in manifest file '{}' is not a valid version.", min_zat_version, path),
+          exception,
+          "Please supply a 'min_zat_version' in the format MAJOR.MINOR.PATCH. Eg. 0.15.0".to_owned()
+        )
+      )
+    )
+  }
+
+  pub fn zat_version_too_old(template: &str, min_zat_version: &str, zat_version: &str) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::ManifestFileError(
+        ManifestFileErrorReason::ZatVersionTooOld(
+          s!("The template '{}' requires Zat version '{}' or later, but this is Zat version '{}'.", template, min_zat_version, zat_version),
+          s!("Please upgrade Zat to version '{}' or later.", min_zat_version)
+        )
+      )
+    )
+  }
+
+  // -------------------------------------------------------------------------------------------------------------------
+  // InheritanceError
+  // -------------------------------------------------------------------------------------------------------------------
+
+  pub fn parent_repository_does_not_exist(parent_path: &str, manifest_path: &str) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::InheritanceError(
+        InheritanceErrorReason::ParentRepositoryDoesNotExist(
+          s!("The parent repository '{}' declared in manifest file '{}' does not exist.", parent_path, manifest_path),
+          "Please make sure the parent repository exists. Local parent repositories are relative to the repository that declares them.".to_owned()
+        )
+      )
+    )
+  }
+
+  pub fn parent_repository_cycle(parent: &str, manifest_path: &str) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::InheritanceError(
+        InheritanceErrorReason::ParentRepositoryCycle(
+          s!("The parent repository '{}' declared in manifest file '{}' has already been inherited. A repository can't inherit from itself, directly or through its parents.", parent, manifest_path),
+          "Please remove the parent that refers back to an inheriting repository.".to_owned()
+        )
+      )
+    )
+  }
+
+  pub fn templates_cant_be_layered(path: &str, exception: String) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::InheritanceError(
+        InheritanceErrorReason::TemplatesCantBeLayered(
+          s!("Could not copy '{}' while combining the templates of the repository and its parents.", path),
+          exception,
+          s!("Make sure Zat can read '{}' and can write to the temporary directory.", path)
         )
       )
     )
@@ -242,6 +390,39 @@ impl ZatError {
     )
   }
 
+  pub fn output_file_already_exists(path: &str) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::TemplateProcessingError(
+        TemplateProcessingErrorReason::FileAlreadyExistsError(
+          s!("Output file '{}' already exists. It was already written by an earlier template file.", path),
+          "Please remove the file from one of the templates, or use '--on-collision overwrite' or '--on-collision skip' to choose which template's file to keep.".to_owned()
+        )
+      )
+    )
+  }
+
+  pub fn insertion_file_does_not_exist(path: &str, insert_file: &str) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::TemplateProcessingError(
+        TemplateProcessingErrorReason::InsertionError(
+          s!("Could not insert '{}' into '{}' as the file does not exist. Insert files add content to existing files.", insert_file, path),
+          s!("Please make sure '{}' exists in the target directory, or rename the insert file to create a new file.", path)
+        )
+      )
+    )
+  }
+
+  pub fn insertion_marker_not_found(path: &str, marker: &str) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::TemplateProcessingError(
+        TemplateProcessingErrorReason::InsertionError(
+          s!("Could not find the marker '{}' in '{}'. Content is inserted before the line containing the marker.", marker, path),
+          s!("Please add a line containing '{}', usually in a comment, where the content should be inserted in '{}'.", marker, path)
+        )
+      )
+    )
+  }
+
   pub fn could_not_create_output_file_directory(path: &str, error: String) -> ZatError {
     ZatError::ProcessCommandError(
       ProcessCommandErrorReason::TemplateProcessingError(
@@ -282,22 +463,74 @@ impl ZatError {
     )
   }
 
-  pub fn post_processing_hook_completed_with_non_zero_status(path: &str, arg: &str, status: i32) -> ZatError {
+  /// The stderr tail is the last lines the shell hook wrote to stderr, and the log file has all of its output.
+  pub fn post_processing_hook_completed_with_non_zero_status(path: &str, arg: &str, status: i32, stderr_tail: Option<String>, log_file: Option<&str>) -> ZatError {
     ZatError::ProcessCommandError(
       ProcessCommandErrorReason::PostProcessingError(
         PostProcessingErrorReason::NonZeroStatusCode(
           s!("Shell hook '{} {}' failed with status code {}. The shell hook failed with a non-zero error code signifying an error.", path, arg, status),
-          s!("Please check the logs above for why the shell hook failed. Try running the shell hook file '{}' with argument '{}' manually to iterate on the error.", path, arg))
+          stderr_tail,
+          s!("{} Try running the shell hook file '{}' with argument '{}' manually to iterate on the error.", Self::check_hook_output(log_file), path, arg))
       )
     )
   }
 
-  pub fn post_processing_hook_was_shutdown(path: &str) -> ZatError {
+  pub fn post_processing_hook_was_shutdown(path: &str, stderr_tail: Option<String>, log_file: Option<&str>) -> ZatError {
     ZatError::ProcessCommandError(
       ProcessCommandErrorReason::PostProcessingError(
         PostProcessingErrorReason::ProcessInterrupted(
           s!("Shell hook '{}' was shutdown. Some other process killed the shell hook process.", path),
-          s!("Try running the shell hook file '{}' manually on the output.", path))
+          stderr_tail,
+          s!("{} Try running the shell hook file '{}' manually on the output.", Self::check_hook_output(log_file), path))
+      )
+    )
+  }
+
+  pub fn post_processing_hook_timed_out(path: &str, timeout: Duration, stderr_tail: Option<String>, log_file: Option<&str>) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::PostProcessingError(
+        PostProcessingErrorReason::TimedOut(
+          s!("Shell hook '{}' did not finish within its timeout of {}s and was stopped.", path, timeout.as_secs()),
+          stderr_tail,
+          s!("{} Increase the timeout with --hook-timeout if the shell hook needs longer.", Self::check_hook_output(log_file)))
+      )
+    )
+  }
+
+  fn check_hook_output(log_file: Option<&str>) -> String {
+    match log_file {
+      Some(log_file) => s!("Please check the output of the shell hook in '{}' for why it failed.", log_file),
+      None => "Please check the output of the shell hook above for why it failed.".to_owned(),
+    }
+  }
+
+  pub fn post_processing_action_failed(action: &str, error: String) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::PostProcessingError(
+        PostProcessingErrorReason::ExecutionError(
+          s!("Post-processing action '{}' failed with an error.", action),
+          error,
+          s!("Please ensure the paths of the action '{}' exist in the target directory once the templates are written.", action))
+      )
+    )
+  }
+
+  pub fn post_processing_action_path_is_outside_target_dir(action: &str, path: &str) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::PostProcessingError(
+        PostProcessingErrorReason::InvalidAction(
+          s!("Post-processing action '{}' uses the path '{}', which is outside the target directory.", action, path),
+          "Please use paths relative to the target directory, without any '..' components.".to_owned())
+      )
+    )
+  }
+
+  pub fn post_processing_action_has_invalid_mode(action: &str, mode: &str) -> ZatError {
+    ZatError::ProcessCommandError(
+      ProcessCommandErrorReason::PostProcessingError(
+        PostProcessingErrorReason::InvalidAction(
+          s!("Post-processing action '{}' has an invalid mode '{}'.", action, mode),
+          "Please supply the mode in octal, such as \"755\".".to_owned())
       )
     )
   }
@@ -371,7 +604,7 @@ impl ZatError {
   }
 
 
-  pub fn could_not_create_checkout_directory_structure(error: String, path: &str, url: &Url) -> ZatError {
+  pub fn could_not_create_checkout_directory_structure(error: String, path: &str, url: &str) -> ZatError {
     ZatError::ProcessRemoteCommandError(
       ProcessRemoteCommandErrorReason::CouldNotCreateCheckoutDirectoryStructure(
         s!("Zat could not create the local checkout directory '{}'. Zat needs to create a local checkout directory for the remote repository '{}' before it clones it locally.", path, url),
@@ -402,15 +635,350 @@ impl ZatError {
   }
 
 
+  // -------------------------------------------------------------------------------------------------------------------
+  // Process Archive Errors
+  // -------------------------------------------------------------------------------------------------------------------
+
+  pub fn invalid_archive_url(error: String, url: &str) -> ZatError {
+    ZatError::ProcessArchiveCommandError(
+      ProcessArchiveCommandErrorReason::ArchiveUrlIsInvalid(
+        s!("The archive URL supplied '{}' is invalid. Zat needs a local path or a valid 'file://' URL to read the archive.", url),
+        error,
+        "Please ensure the archive is supplied as a local path or as a 'file://' URL with an absolute path.".to_owned()
+      )
+    )
+  }
+
+
+  pub fn archive_does_not_exist(path: &str) -> ZatError {
+    ZatError::ProcessArchiveCommandError(
+      ProcessArchiveCommandErrorReason::ArchiveDoesNotExist(
+        s!("The archive '{}' does not exist. Zat needs an archive containing a Zat repository to process.", path),
+        s!("Please ensure the archive '{}' exists and is readable by the Zat user.", path)
+      )
+    )
+  }
+
+
+  pub fn unsupported_archive_format(path: &str) -> ZatError {
+    ZatError::ProcessArchiveCommandError(
+      ProcessArchiveCommandErrorReason::UnsupportedArchiveFormat(
+        s!("The archive '{}' is not in a supported format. Zat supports '.tar.gz', '.tgz' and '.zip' archives.", path),
+        "Please supply an archive with one of the following extensions: '.tar.gz', '.tgz' or '.zip'.".to_owned()
+      )
+    )
+  }
+
+
+  pub fn could_not_create_extraction_directory(error: String) -> ZatError {
+    ZatError::ProcessArchiveCommandError(
+      ProcessArchiveCommandErrorReason::CouldNotCreateExtractionDirectory(
+        "Zat could not create a folder under your system's temporary directory. Zat needs to create a temporary local folder to extract the archive.".to_owned(),
+        error,
+        "Please ensure the Zat user has enough privileges to create a temporary directory and that you are not out of disk space".to_owned()
+      )
+    )
+  }
+
+
+  pub fn could_not_read_archive(error: String, path: &str) -> ZatError {
+    ZatError::ProcessArchiveCommandError(
+      ProcessArchiveCommandErrorReason::CouldNotReadArchive(
+        s!("Zat could not read the archive '{}'.", path),
+        error,
+        s!("Please ensure the archive '{}' is readable by the Zat user and is not corrupted.", path)
+      )
+    )
+  }
+
+
+  pub fn archive_entry_escapes_extraction_directory(entry: &str, path: &str) -> ZatError {
+    ZatError::ProcessArchiveCommandError(
+      ProcessArchiveCommandErrorReason::ArchiveEntryEscapesExtractionDirectory(
+        s!("The archive '{}' has an entry '{}' that would be extracted outside the extraction directory. Zat does not extract archives with absolute paths or paths containing '..'.", path, entry),
+        s!("Please recreate the archive '{}' with paths relative to the Zat repository.", path)
+      )
+    )
+  }
+
+
+  pub fn could_not_extract_archive(error: String, path: &str, extraction_path: &str) -> ZatError {
+    ZatError::ProcessArchiveCommandError(
+      ProcessArchiveCommandErrorReason::CouldNotExtractArchive(
+        s!("Zat could not extract the archive '{}' to the local directory '{}'.", path, extraction_path),
+        error,
+        s!("Please ensure the archive '{}' is not corrupted and that Zat has enough privileges to create files under '{}'.", path, extraction_path)
+      )
+    )
+  }
+
+
+  pub fn git_checkout_error(error: String, program: &str, git_ref: &str) -> ZatError {
+    ZatError::ProcessRemoteCommandError(
+      ProcessRemoteCommandErrorReason::GitCheckoutFailed(
+        s!("Zat could not checkout ref '{}' of the remote repository. \n\n    Zat ran the following command to checkout the ref: \n    '{}'", git_ref, program),
+        error,
+        "Please ensure you have Git installed and it's accessible on the PATH used by Zat.".to_owned()
+      )
+    )
+  }
+
+  pub fn git_checkout_status_error(error_code: Option<i32>, git_ref: &str, url: &str) -> ZatError {
+    let code = error_code.map_or_else(|| "Unknown".to_owned(), |ec| ec.to_string());
+    ZatError::ProcessRemoteCommandError(
+      ProcessRemoteCommandErrorReason::GitCheckoutStatusError(
+        s!("Zat could not checkout ref '{}' of remote repository '{}' because it returned an exit code of '{}'.", git_ref, url, code),
+        s!("Please ensure the ref '{}' is a branch, tag or commit that exists in the remote repository '{}'.", git_ref, url)
+      )
+    )
+  }
+
+  pub fn git_commit_error(error: String, program: &str, url: &str) -> ZatError {
+    ZatError::ProcessRemoteCommandError(
+      ProcessRemoteCommandErrorReason::GitCommitFailed(
+        s!("Zat could not find the commit checked out from the remote repository '{}'. \n\n    Zat ran the following command to find the commit: \n    '{}'", url, program),
+        error,
+        "Please ensure you have Git installed and it's accessible on the PATH used by Zat.".to_owned()
+      )
+    )
+  }
+
+  pub fn could_not_read_remote_shell_hook(shell_hook: &str, error: String) -> ZatError {
+    ZatError::ProcessRemoteCommandError(
+      ProcessRemoteCommandErrorReason::CouldNotReadShellHook(
+        s!("Zat could not read the shell hook '{}' of the remote repository to show it before it is run.", shell_hook),
+        error,
+        "Please ensure the shell hook of the remote repository is a readable file, or supply --no-hooks to skip it.".to_owned()
+      )
+    )
+  }
+
+  pub fn remote_repository_not_trusted(repositories: &str) -> ZatError {
+    ZatError::ProcessRemoteCommandError(
+      ProcessRemoteCommandErrorReason::RepositoryNotTrusted(
+        s!("The plugins and shell hooks of the repositories {} were not trusted, so they were not run.", repositories),
+        "Please review the repositories and run Zat again to trust them. Supply --no-plugins and --no-hooks to process the repository without running its plugins and shell hooks.".to_owned()
+      )
+    )
+  }
+
+  pub fn remote_repository_subdir_is_outside_repository(subdir: &str, url: &str) -> ZatError {
+    ZatError::ProcessRemoteCommandError(
+      ProcessRemoteCommandErrorReason::SubdirIsOutsideRepository(
+        s!("The subdirectory '{}' of the remote repository '{}' is outside the repository.", subdir, url),
+        "Please supply a subdirectory relative to the root of the repository, without any '..' components.".to_owned()
+      )
+    )
+  }
+
+  pub fn remote_repository_git_ref_is_invalid(git_ref: &str, url: &str) -> ZatError {
+    ZatError::ProcessRemoteCommandError(
+      ProcessRemoteCommandErrorReason::GitRefIsInvalid(
+        s!("The ref '{}' of the remote repository '{}' starts with a '-', so Git would read it as an option.", git_ref, url),
+        "Please supply the name of a branch, tag or commit to check out.".to_owned()
+      )
+    )
+  }
+
+
+  // -------------------------------------------------------------------------------------------------------------------
+  // Generate Errors
+  // -------------------------------------------------------------------------------------------------------------------
+
+  pub fn generator_does_not_exist(generator: &str, repository_dir: &str, generators: &[String]) -> ZatError {
+    let available_generators =
+      if generators.is_empty() {
+        "This repository does not define any generators.".to_owned()
+      } else {
+        s!("The available generators are: {}.", generators.join(", "))
+      };
+
+    ZatError::GenerateCommandError(
+      GenerateCommandErrorReason::GeneratorDoesNotExist(
+        s!("The generator '{}' does not exist in the Zat repository '{}'. {}", generator, repository_dir, available_generators),
+        "Please supply the name of a directory within the 'generators' directory of the Zat repository.".to_owned()
+      )
+    )
+  }
+
+  pub fn generate_target_dir_does_not_exist(target_dir: &str) -> ZatError {
+    ZatError::GenerateCommandError(
+      GenerateCommandErrorReason::TargetDirectoryDoesNotExist(
+        s!("The target directory '{}' does not exist. Generators add files to an existing project.", target_dir),
+        "Please supply the directory of an existing project as the target directory. Use 'zat process' to create a new project.".to_owned()
+      )
+    )
+  }
+
+  pub fn could_not_create_path_prefix(path: &str, exception: String) -> ZatError {
+    ZatError::GenerateCommandError(
+      GenerateCommandErrorReason::CouldNotCreatePathPrefix(
+        s!("Could not create the directory '{}' for the path prefix.", path),
+        exception,
+        s!("Please ensure the directory '{}' can be created and the target directory has the necessary permissions.", path)
+      )
+    )
+  }
+
+  // -------------------------------------------------------------------------------------------------------------------
+  // Templatize Errors
+  // -------------------------------------------------------------------------------------------------------------------
+
+  pub fn templatize_source_dir_does_not_exist(source_dir: &str) -> ZatError {
+    ZatError::TemplatizeCommandError(
+      TemplatizeCommandErrorReason::SourceDirectoryDoesNotExist(
+        s!("The source directory '{}' does not exist. A Zat repository is created from an existing project.", source_dir),
+        "Please supply the directory of an existing project as the source directory.".to_owned()
+      )
+    )
+  }
+
+  pub fn templatize_repository_dir_should_not_exist(repository_dir: &str) -> ZatError {
+    ZatError::TemplatizeCommandError(
+      TemplatizeCommandErrorReason::RepositoryDirectoryShouldNotExist(
+        s!("The repository directory '{}' should not exist. It will be created from the source directory.", repository_dir),
+        "Please supply a directory that does not exist for the repository.".to_owned()
+      )
+    )
+  }
+
+  pub fn invalid_templatize_replacement(replacement: &str) -> ZatError {
+    ZatError::TemplatizeCommandError(
+      TemplatizeCommandErrorReason::InvalidReplacement(
+        s!("The replacement '{}' is not valid.", replacement),
+        "Please supply replacements as <TEXT>=<VARIABLE_NAME>. Eg. --replace \"my-service=project\"".to_owned()
+      )
+    )
+  }
+
+  pub fn invalid_templatize_ignore(ignore: &str, exception: String) -> ZatError {
+    ZatError::TemplatizeCommandError(
+      TemplatizeCommandErrorReason::InvalidIgnore(
+        s!("The ignore '{}' is not a valid regular expression.", ignore),
+        exception,
+        "Please supply ignores as regular expressions. Eg. --ignores \"target/.*\"".to_owned()
+      )
+    )
+  }
+
+  pub fn could_not_read_templatize_source_file(path: &str, exception: String) -> ZatError {
+    ZatError::TemplatizeCommandError(
+      TemplatizeCommandErrorReason::CouldNotReadSourceFile(
+        s!("Could not read the source file '{}'.", path),
+        exception,
+        s!("Please ensure the file '{}' is readable by the current user, or ignore it with --ignores.", path)
+      )
+    )
+  }
+
+  pub fn could_not_create_templatize_file(path: &str, exception: String) -> ZatError {
+    ZatError::TemplatizeCommandError(
+      TemplatizeCommandErrorReason::CouldNotCreateFile(
+        s!("The repository file '{}' could not be created.", path),
+        exception,
+        s!("Please ensure the file '{}' is writable by the current user", path)
+      )
+    )
+  }
+
+  // -------------------------------------------------------------------------------------------------------------------
+  // Test Errors
+  // -------------------------------------------------------------------------------------------------------------------
+
+  pub fn no_template_tests(repository_dir: &str) -> ZatError {
+    ZatError::TestCommandError(
+      TestCommandErrorReason::NoTestCases(
+        s!("The Zat repository '{}' does not have any test cases.", repository_dir),
+        "Please add a test case as a directory within the 'tests' directory of the repository, with an 'answers.txt' file. Run with --bless to create its expected output.".to_owned()
+      )
+    )
+  }
+
+  pub fn could_not_run_test_case(test_case: &str, exception: String) -> ZatError {
+    ZatError::TestCommandError(
+      TestCommandErrorReason::CouldNotRunTestCase(
+        s!("The test case '{}' could not be rendered.", test_case),
+        exception,
+        s!("Please ensure the answers in 'tests/{}/answers.txt' match the prompts of the repository, one per line.", test_case)
+      )
+    )
+  }
+
+  pub fn could_not_bless_test_case(test_case: &str, expected_dir: &str, exception: String) -> ZatError {
+    ZatError::TestCommandError(
+      TestCommandErrorReason::CouldNotBlessTestCase(
+        s!("The expected output of the test case '{}' could not be updated.", test_case),
+        exception,
+        s!("Please ensure the directory '{}' is writable by the current user.", expected_dir)
+      )
+    )
+  }
+
+  pub fn template_tests_failed(failed_test_cases: &[String], test_case_count: usize) -> ZatError {
+    ZatError::TestCommandError(
+      TestCommandErrorReason::TestCasesFailed(
+        s!("{} of {} test case(s) failed: {}", failed_test_cases.len(), test_case_count, failed_test_cases.join(", ")),
+        "Please fix the templates, or run with --bless to accept the rendered output as the expected output.".to_owned()
+      )
+    )
+  }
+
+  // -------------------------------------------------------------------------------------------------------------------
+  // Describe Errors
+  // -------------------------------------------------------------------------------------------------------------------
+
+  pub fn invalid_describe_ignore(ignore: &str, exception: String) -> ZatError {
+    ZatError::DescribeCommandError(
+      DescribeCommandErrorReason::InvalidIgnore(
+        s!("The ignore '{}' is not a valid regular expression.", ignore),
+        exception,
+        "Please supply ignores as regular expressions. Eg. --ignores \"target/.*\"".to_owned()
+      )
+    )
+  }
+
+  pub fn could_not_read_describe_shell_hook(path: &str, exception: String) -> ZatError {
+    ZatError::DescribeCommandError(
+      DescribeCommandErrorReason::CouldNotReadShellHook(
+        s!("Could not read the shell hook file '{}'.", path),
+        exception,
+        s!("Please ensure the file '{}' is readable by the current user.", path)
+      )
+    )
+  }
+
+  pub fn could_not_write_description(exception: String) -> ZatError {
+    ZatError::DescribeCommandError(
+      DescribeCommandErrorReason::CouldNotWriteDescription(
+        "Could not write the description of the repository as JSON.".to_owned(),
+        exception,
+        "Please try again with --format text.".to_owned()
+      )
+    )
+  }
+
+  // -------------------------------------------------------------------------------------------------------------------
+  // Validate Errors
+  // -------------------------------------------------------------------------------------------------------------------
+
+  pub fn repository_not_valid(repository: &str, problems: usize) -> ZatError {
+    ZatError::ValidateCommandError(
+      ValidateCommandErrorReason::RepositoryNotValid(
+        s!("The repository '{}' has {} problem(s), which are listed above.", repository, problems),
+        "Please fix the problems and run 'zat validate' again.".to_owned()
+      )
+    )
+  }
+
   // -------------------------------------------------------------------------------------------------------------------
   // Plugin Errors
   // -------------------------------------------------------------------------------------------------------------------
 
-  pub fn could_not_run_plugin(plugin: &str, exception: String) -> ZatError {
+  pub fn could_not_run_plugin(plugin: &str, plugin_path: &str, exception: String) -> ZatError {
     ZatError::PluginError(
       PluginErrorReason::CouldNotRunPlugin(
         plugin.to_owned(),
-        "Plugin could not be run. Does it exist and is it executable?".to_owned(),
+        s!("Plugin could not be run from '{}'. Does it exist and is it executable?", plugin_path),
         exception,
         "Try running the plugin manually to fix the above error.".to_owned()
       )
@@ -484,6 +1052,147 @@ impl ZatError {
     )
   }
 
+  pub fn plugin_timed_out(plugin: &str, timeout: Duration, elapsed: Duration) -> ZatError {
+    ZatError::PluginError(
+      PluginErrorReason::PluginTimedOut(
+        plugin.to_owned(),
+        s!("The plugin did not finish within its timeout of {}ms and was stopped after {}ms.", timeout.as_millis(), elapsed.as_millis()),
+        "Try running the plugin manually to see why it does not finish. Increase its 'timeout_ms' if it needs longer, or mark it as 'optional' to use the variable's default value instead.".to_owned()
+      )
+    )
+  }
+
+  pub fn plugin_protocol_not_supported(plugin: &str, protocol_version: u32, supported_version: u32) -> ZatError {
+    ZatError::PluginError(
+      PluginErrorReason::PluginProtocolNotSupported(
+        plugin.to_owned(),
+        s!("The plugin responded with version {} of the plugin protocol, but this version of Zat supports versions 1 to {}.", protocol_version, supported_version),
+        "Please upgrade Zat, or use a version of the plugin that supports an older version of the plugin protocol.".to_owned()
+      )
+    )
+  }
+
+  pub fn plugin_id_is_outside_plugins_dir(plugin: &str, repository_dir: &str) -> ZatError {
+    ZatError::PluginError(
+      PluginErrorReason::PluginIdIsOutsidePluginsDir(
+        plugin.to_owned(),
+        s!("The plugin id of repository '{}' is absolute or contains '..'. Plugins are looked up in the 'plugins' directory of the repository that defines them and can't be outside of it.", repository_dir),
+        "Please use the name of a plugin in the 'plugins' directory of the repository, or of a program on the PATH.".to_owned()
+      )
+    )
+  }
+
+  pub fn plugin_returned_choices_instead_of_result(plugin: &str) -> ZatError {
+    ZatError::PluginError(
+      PluginErrorReason::PluginReturnedUnexpectedResult(
+        plugin.to_owned(),
+        "The plugin returned choices, but it provides a value.".to_owned(),
+        r#"Add "provides": "choices" to the plugin to select from the choices it returns, or return a single result as {"success":{"result":"<YOUR_RESULT>"}}."#.to_owned()
+      )
+    )
+  }
+
+  pub fn plugin_returned_result_instead_of_choices(plugin: &str) -> ZatError {
+    ZatError::PluginError(
+      PluginErrorReason::PluginReturnedUnexpectedResult(
+        plugin.to_owned(),
+        "The plugin returned a single result, but it provides choices.".to_owned(),
+        r#"Return the choices as {"success":{"choices":[{"display":"<DISPLAY>","description":"<DESCRIPTION>","value":"<VALUE>"}]}}, or remove "provides" from the plugin to use its result as a default value."#.to_owned()
+      )
+    )
+  }
+
+  pub fn invalid_plugin_choices(plugin: &str, variable_name: &str, error: &str) -> ZatError {
+    ZatError::PluginError(
+      PluginErrorReason::InvalidPluginChoices(
+        plugin.to_owned(),
+        s!("The choices returned for variable '{}' are invalid. {}", variable_name, error),
+        "Ensure the plugin returns at least one choice, that each choice has a 'display', 'description' and 'value', and that each 'value' is unique.".to_owned()
+      )
+    )
+  }
+
+  pub fn plugin_dependency_not_answered(plugin: &str, variable_name: &str, dependency: &str) -> ZatError {
+    ZatError::PluginError(
+      PluginErrorReason::PluginDependencyNotAnswered(
+        plugin.to_owned(),
+        s!("The plugin of variable '{}' depends on '{}', which has not been answered.", variable_name, dependency),
+        s!("Please ensure '{}' is a variable defined before '{}' in '.variables.zat-prompt', or a choice that is selected.", dependency, variable_name)
+      )
+    )
+  }
+
+
+  // -------------------------------------------------------------------------------------------------------------------
+  // Zat Config Errors
+  // -------------------------------------------------------------------------------------------------------------------
+
+  pub fn zat_config_dir_not_found() -> ZatError {
+    ZatError::ZatConfigError(
+      ZatConfigErrorReason::ConfigDirectoryNotFound(
+        "Zat could not find your home directory. Zat stores its configuration under '~/.config/zat'.".to_owned(),
+        "Please set the ZAT_CONFIG_DIR environment variable to the directory Zat should use for its configuration.".to_owned()
+      )
+    )
+  }
+
+  pub fn could_not_read_zat_config_file(path: &str, exception: String) -> ZatError {
+    ZatError::ZatConfigError(
+      ZatConfigErrorReason::CouldNotReadFile(
+        s!("Zat could not read the configuration file '{}'.", path),
+        exception,
+        s!("Please ensure the configuration file '{}' is readable by the Zat user.", path)
+      )
+    )
+  }
+
+  pub fn could_not_decode_zat_config_file(path: &str, exception: String) -> ZatError {
+    ZatError::ZatConfigError(
+      ZatConfigErrorReason::CouldNotDecodeFile(
+        s!("Zat could not decode the configuration file '{}' as TOML into the expected format.", path),
+        exception,
+        s!("Please fix the configuration file '{}' or remove it. See `zat --help` for more details on the format.", path)
+      )
+    )
+  }
+
+  pub fn could_not_write_zat_config_file(path: &str, exception: String) -> ZatError {
+    ZatError::ZatConfigError(
+      ZatConfigErrorReason::CouldNotWriteFile(
+        s!("Zat could not write the configuration file '{}'.", path),
+        exception,
+        s!("Please ensure the configuration file '{}' and its parent directories are writable by the Zat user.", path)
+      )
+    )
+  }
+
+  pub fn template_not_found_in_registry(name: &str, path: &str) -> ZatError {
+    ZatError::ZatConfigError(
+      ZatConfigErrorReason::TemplateNotFound(
+        s!("The template '{}' could not be found in the template registry '{}'.", name, path),
+        s!("Please add the template with `zat templates add --name {} --url <REPOSITORY_URL>` or use `zat templates list` to see the registered templates.", name)
+      )
+    )
+  }
+
+  pub fn template_already_exists_in_registry(name: &str, path: &str) -> ZatError {
+    ZatError::ZatConfigError(
+      ZatConfigErrorReason::TemplateAlreadyExists(
+        s!("The template '{}' already exists in the template registry '{}'.", name, path),
+        s!("Please remove the existing template with `zat templates remove --name {}` before adding it again.", name)
+      )
+    )
+  }
+
+  pub fn invalid_template_shorthand(template: &str) -> ZatError {
+    ZatError::ZatConfigError(
+      ZatConfigErrorReason::InvalidTemplateShorthand(
+        s!("The template shorthand '{}' is invalid. GitHub shorthands should be of the form 'gh:owner/repository'.", template),
+        "Please supply the template as 'gh:owner/repository', a registered template name or a full repository URL.".to_owned()
+      )
+    )
+  }
+
 
   // -------------------------------------------------------------------------------------------------------------------
   // Generic Unforeseen Errors
@@ -508,14 +1217,30 @@ impl std::fmt::Display for ZatError {
       let string_rep = match self {
         ZatError::ProcessCommandError(ProcessCommandErrorReason::UserConfigError(error))                => ZatError::print_formatted_error("Got a configuration error", error),
         ZatError::ProcessCommandError(ProcessCommandErrorReason::VariableFileError(error))              => ZatError::print_formatted_error("Got an error processing variables", error),
+        ZatError::ProcessCommandError(ProcessCommandErrorReason::ManifestFileError(error))              => ZatError::print_formatted_error("Got an error reading the repository manifest", error),
+        ZatError::ProcessCommandError(ProcessCommandErrorReason::InheritanceError(error))               => ZatError::print_formatted_error("Got an error inheriting from a parent repository", error),
         ZatError::ProcessCommandError(ProcessCommandErrorReason::TemplateProcessingError(error))        => ZatError::print_formatted_error("There was an error running the template", error),
         ZatError::ProcessCommandError(ProcessCommandErrorReason::PostProcessingError(error))            => ZatError::print_formatted_error("There was an error running the post processor", error),
         ZatError::BootstrapCommandError(error)                                                          =>
           ZatError::print_formatted_error("There was an error running the bootstrap process", error),
        ZatError::ProcessRemoteCommandError(error)                                                       =>
           ZatError::print_formatted_error("There was an error running a remote processing command", error),
+       ZatError::ProcessArchiveCommandError(error)                                                      =>
+          ZatError::print_formatted_error("There was an error running an archive processing command", error),
+       ZatError::GenerateCommandError(error)                                                            =>
+          ZatError::print_formatted_error("There was an error running a generator", error),
+       ZatError::TemplatizeCommandError(error)                                                          =>
+          ZatError::print_formatted_error("There was an error creating a repository from a project", error),
+       ZatError::TestCommandError(error)                                                                =>
+          ZatError::print_formatted_error("There was an error testing the templates", error),
+       ZatError::DescribeCommandError(error)                                                            =>
+          ZatError::print_formatted_error("There was an error describing the repository", error),
+       ZatError::ValidateCommandError(error)                                                            =>
+          ZatError::print_formatted_error("There was an error validating the repository", error),
        ZatError::PluginError(error)                                                       =>
           ZatError::print_formatted_error("There was an error running a plugin", error),
+       ZatError::ZatConfigError(error)                                                      =>
+          ZatError::print_formatted_error("There was an error with the Zat configuration", error),
        ZatError::GenericError(error)                                                       =>
           ZatError::print_formatted_error("There was an unexpected error", error),
       };
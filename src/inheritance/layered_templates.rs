@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::config::TemplateFilesDir;
+use crate::error::{ZatAction, ZatError};
+use crate::processor::StringTokenReplacer;
+
+/// The placeholder used to commit empty template directories to Git. It's not part of the template.
+const KEEP_FILE: &str = ".keep";
+
+/// The extension of template files, which is removed from their output path.
+const TEMPLATE_EXTENSION: &str = ".tmpl";
+
+/// Combines the template files of several repositories into a single template files directory.
+pub struct LayeredTemplates<'a> {
+  destination: &'a TemplateFilesDir,
+  token_replacer: &'a dyn StringTokenReplacer,
+
+  /// The template file that writes each output path, relative to the destination.
+  output_paths: HashMap<PathBuf, PathBuf>,
+}
+
+impl <'a> LayeredTemplates<'a> {
+
+  pub fn new(destination: &'a TemplateFilesDir, token_replacer: &'a dyn StringTokenReplacer) -> Self {
+    Self {
+      destination,
+      token_replacer,
+      output_paths: HashMap::new()
+    }
+  }
+
+  /// Copies the template files in `source` into the destination, replacing any files already copied that write the same
+  /// output path. Files are compared by the path they are written to, so a `README.md` replaces a `README.md.tmpl`.
+  /// Repositories without a template files directory have nothing to copy.
+  pub fn copy(&mut self, source: &TemplateFilesDir) -> ZatAction {
+    if !source.does_exist() {
+      return Ok(())
+    }
+
+    for entry in WalkDir::new(source).min_depth(1) {
+      let entry = entry.map_err(|e| ZatError::templates_cant_be_layered(source.path(), e.to_string()))?;
+      let source_path = entry.path();
+      let relative_path = source_path.strip_prefix(source).expect("Could not strip the template files directory prefix");
+      let destination_path = Path::new(self.destination.path()).join(relative_path);
+
+      if entry.file_type().is_dir() {
+        fs::create_dir_all(&destination_path).map_err(|e| ZatError::templates_cant_be_layered(&source_path.to_string_lossy(), e.to_string()))?
+      } else if relative_path != Path::new(KEEP_FILE) {
+        let replaced_file = self.output_paths.insert(self.output_path(relative_path), relative_path.to_owned());
+
+        if let Some(replaced_file) = replaced_file.filter(|replaced_file| replaced_file != relative_path) {
+          let replaced_path = Path::new(self.destination.path()).join(replaced_file);
+          fs::remove_file(&replaced_path).map_err(|e| ZatError::templates_cant_be_layered(&replaced_path.to_string_lossy(), e.to_string()))?
+        }
+
+        fs::copy(source_path, &destination_path).map_err(|e| ZatError::templates_cant_be_layered(&source_path.to_string_lossy(), e.to_string()))?;
+      }
+    }
+
+    Ok(())
+  }
+
+  /// The path a template file is written to, once the tokens in its name are replaced and any template extension is
+  /// removed.
+  fn output_path(&self, relative_path: &Path) -> PathBuf {
+    let output_path = self.token_replacer.replace(&relative_path.to_string_lossy());
+
+    PathBuf::from(output_path.strip_suffix(TEMPLATE_EXTENSION).unwrap_or(&output_path))
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::config::RepositoryDir;
+  use crate::processor::string_token_replacer::ReplacingStringTokenReplacer;
+  use pretty_assertions::assert_eq;
+  use tempfile::TempDir;
+
+  fn template_files_dir(root: &Path, name: &str, files: &[(&str, &str)]) -> TemplateFilesDir {
+    let template_files_dir = TemplateFilesDir::from(&RepositoryDir::from(root.join(name).as_path()));
+    fs::create_dir_all(&template_files_dir).unwrap();
+
+    for (file, content) in files {
+      fs::write(Path::new(template_files_dir.path()).join(file), content).unwrap()
+    }
+
+    template_files_dir
+  }
+
+  fn files_in(template_files_dir: &TemplateFilesDir) -> Vec<(String, String)> {
+    let mut files: Vec<(String, String)> =
+      fs::read_dir(template_files_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .map(|path| (path.file_name().unwrap().to_string_lossy().to_string(), fs::read_to_string(&path).unwrap()))
+        .collect();
+
+    files.sort();
+    files
+  }
+
+  #[test]
+  fn replaces_parent_files_with_the_same_output_path() {
+    let root = TempDir::new().unwrap();
+    let parent = template_files_dir(root.path(), "parent", &[("README.md.tmpl", "parent readme"), ("$project$.md", "parent project"), ("LICENSE", "parent license")]);
+    let child = template_files_dir(root.path(), "child", &[("README.md", "child readme"), ("my-app.md.tmpl", "child project")]);
+    let destination = template_files_dir(root.path(), "layered", &[]);
+
+    let token_replacer = ReplacingStringTokenReplacer::new(&[("$project$", "my-app")]);
+    let mut layered_templates = LayeredTemplates::new(&destination, &token_replacer);
+    layered_templates.copy(&parent).unwrap();
+    layered_templates.copy(&child).unwrap();
+
+    assert_eq!(
+      files_in(&destination),
+      vec![
+        ("LICENSE".to_owned(), "parent license".to_owned()),
+        ("README.md".to_owned(), "child readme".to_owned()),
+        ("my-app.md.tmpl".to_owned(), "child project".to_owned()),
+      ]
+    )
+  }
+}
@@ -0,0 +1,4 @@
+pub mod parent_repositories;
+pub mod layered_templates;
+
+pub use parent_repositories::ParentRepositories;
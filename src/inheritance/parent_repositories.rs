@@ -0,0 +1,318 @@
+use std::fs;
+use std::path::Path;
+use std::format as s;
+use tempfile::TempDir;
+
+use crate::command::ProcessRemoteTemplates;
+use crate::command::process_remote_templates::is_remote_url;
+use crate::config::{ManifestFile, ParentRepository, RemoteRepository, RepositoryDir, RepositoryManifest, RepositoryOrigin, TemplateFilesDir, UserConfig, VariableFile, ZAT_VERSION};
+use crate::error::{ZatError, ZatResult};
+use crate::logging::Logger;
+use crate::processor::StringTokenReplacer;
+use crate::registry::TemplateResolver;
+use crate::templates::{TemplateVariableProvider, TemplateVariables};
+use super::layered_templates::LayeredTemplates;
+
+/// The ancestors of a Zat repository, declared through the `parent` of each manifest.
+#[derive(Debug)]
+pub struct ParentRepositories {
+  /// From the immediate parent to the root of the chain.
+  parents: Vec<RepositoryDir>,
+
+  /// Checkouts of remote parents. These are removed once processing is complete.
+  checkouts: Vec<TempDir>,
+
+  /// The URL and commit of each remote parent, in the order of their checkouts. The user trusts these before any
+  /// plugins are run.
+  origins: Vec<RepositoryOrigin>,
+
+  /// The combined templates of the repository and its parents.
+  layered_templates: Option<TempDir>,
+}
+
+impl ParentRepositories {
+
+  /// Follows the parent of each manifest, starting from the manifest of the repository being processed.
+  pub fn load(repository_dir: &RepositoryDir, manifest: Option<&RepositoryManifest>) -> ZatResult<Self> {
+    let mut parents = vec![];
+    let mut checkouts = vec![];
+    let mut origins = vec![];
+    let mut inherited = vec![Self::local_identity(repository_dir)];
+
+    let mut current_dir = repository_dir.clone();
+    let mut current_manifest = manifest.cloned();
+
+    while let Some(parent) = current_manifest.and_then(|manifest| manifest.parent) {
+      let manifest_path = ManifestFile::from(current_dir.clone()).get_path().to_owned();
+
+      let (parent_dir, identity) =
+        if is_remote_url(&TemplateResolver::expand_url(&parent.repository)?) {
+          let remote_repository = Self::remote_repository(&parent)?;
+          let (checkout, parent_dir) = ProcessRemoteTemplates::checkout(&remote_repository)?;
+          let commit = ProcessRemoteTemplates::head_commit(&remote_repository.url, &RepositoryDir::from(checkout.path()))?;
+          checkouts.push(checkout);
+          origins.push(RepositoryOrigin::Remote { url: remote_repository.url, commit });
+          (parent_dir, Self::remote_identity(&parent))
+        } else {
+          let parent_dir = RepositoryDir::from(Path::new(current_dir.path()).join(&parent.repository).as_path());
+          if !parent_dir.does_exist() {
+            return Err(ZatError::parent_repository_does_not_exist(parent_dir.path(), &manifest_path))
+          }
+          let identity = Self::local_identity(&parent_dir);
+          (parent_dir, identity)
+        };
+
+      if inherited.contains(&identity) {
+        return Err(ZatError::parent_repository_cycle(&parent.repository, &manifest_path))
+      }
+
+      let parent_manifest_file = ManifestFile::from(parent_dir.clone());
+      let parent_manifest = parent_manifest_file.load()?;
+
+      if let Some(parent_manifest) = &parent_manifest {
+        parent_manifest.verify_zat_version(parent_manifest_file.get_path(), ZAT_VERSION)?
+      }
+
+      Logger::info(&s!("Inheriting from parent repository: {}", parent.repository));
+
+      inherited.push(identity);
+      parents.push(parent_dir.clone());
+      current_dir = parent_dir;
+      current_manifest = parent_manifest;
+    }
+
+    Ok(
+      Self {
+        parents,
+        checkouts,
+        origins,
+        layered_templates: None
+      }
+    )
+  }
+
+  /// The origins of the remote parents, which are checked out at whichever commit their ref points to when processed.
+  pub fn origins(&self) -> &[RepositoryOrigin] {
+    &self.origins
+  }
+
+  /// The origin of the remote parent whose checkout contains the repository directory, if any. Local parents declared
+  /// by a remote parent are within its checkout, so they have its origin too.
+  pub fn remote_origin_of(&self, repository_dir: &RepositoryDir) -> Option<&RepositoryOrigin> {
+    self
+      .checkouts
+      .iter()
+      .zip(&self.origins)
+      .find(|(checkout, _)| Path::new(repository_dir.path()).starts_with(checkout.path()))
+      .map(|(_, origin)| origin)
+  }
+
+  /// Reads the variables of the repository and its parents. Variables of a repository override the variables of its
+  /// parents with the same name. Only the repository being processed needs a variable file when it has parents.
+  pub fn template_variables(&self, template_variable_provider: &impl TemplateVariableProvider, user_config: &UserConfig) -> ZatResult<TemplateVariables> {
+    if self.parents.is_empty() {
+      return template_variable_provider.get_tokens(user_config.clone())
+    }
+
+    let mut template_variables: Option<TemplateVariables> = None;
+
+    for repository_dir in self.parents.iter().rev().chain(std::iter::once(&user_config.repository_dir)) {
+      if VariableFile::find(repository_dir)?.does_exist() {
+        let repository_config = UserConfig { repository_dir: repository_dir.clone(), ..user_config.clone() };
+        let variables = template_variable_provider.get_tokens(repository_config)?;
+
+        template_variables =
+          match template_variables {
+            Some(parent_variables) => Some(variables.inherit_from(parent_variables)),
+            None => Some(variables)
+          }
+      }
+    }
+
+    match template_variables {
+      Some(variables) => Ok(variables),
+      None => template_variable_provider.get_tokens(user_config.clone()) // Reports the missing variable file
+    }
+  }
+
+  /// Combines the templates of the parents and the repository, with files from the repository replacing files of its
+  /// parents that are written to the same path. The token replacer resolves the path each file is written to. Returns a
+  /// UserConfig that processes the combined templates.
+  pub fn layer_templates(&mut self, user_config: UserConfig, token_replacer: &dyn StringTokenReplacer) -> ZatResult<UserConfig> {
+    if self.parents.is_empty() {
+      return Ok(user_config)
+    }
+
+    let layered_templates_dir =
+      tempfile::Builder::new()
+        .prefix("zat-templates-")
+        .tempdir()
+        .map_err(|e| ZatError::templates_cant_be_layered(&std::env::temp_dir().to_string_lossy(), e.to_string()))?;
+
+    let template_files_dir = TemplateFilesDir::from(&RepositoryDir::from(layered_templates_dir.path()));
+    fs::create_dir_all(&template_files_dir).map_err(|e| ZatError::templates_cant_be_layered(template_files_dir.path(), e.to_string()))?;
+
+    let mut layered_templates = LayeredTemplates::new(&template_files_dir, token_replacer);
+
+    for repository_dir in self.parents.iter().rev() {
+      layered_templates.copy(&TemplateFilesDir::from(repository_dir))?
+    }
+
+    layered_templates.copy(&user_config.template_files_dir)?;
+
+    self.layered_templates = Some(layered_templates_dir);
+
+    Ok(
+      UserConfig {
+        template_files_dir,
+        ..user_config
+      }
+    )
+  }
+
+  /// Removes any remote checkouts and combined templates.
+  pub fn remove(self) {
+    for checkout in self.checkouts {
+      ProcessRemoteTemplates::remove_temporary_directory(checkout)
+    }
+
+    if let Some(layered_templates) = self.layered_templates {
+      ProcessRemoteTemplates::remove_temporary_directory(layered_templates)
+    }
+  }
+
+  fn remote_repository(parent: &ParentRepository) -> ZatResult<RemoteRepository> {
+    Ok(
+      RemoteRepository {
+        url: TemplateResolver::expand_url(&parent.repository)?,
+        git_ref: parent.git_ref.clone(),
+        subdir: parent.subdir.clone()
+      }
+    )
+  }
+
+  fn remote_identity(parent: &ParentRepository) -> String {
+    s!("{}#{}/{}", parent.repository, parent.git_ref.as_deref().unwrap_or(""), parent.subdir.as_deref().unwrap_or(""))
+  }
+
+  fn local_identity(repository_dir: &RepositoryDir) -> String {
+    fs::canonicalize(repository_dir)
+      .map(|path| path.to_string_lossy().to_string())
+      .unwrap_or_else(|_| repository_dir.path().to_owned())
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::assert_error_with;
+  use crate::config::DOT_VARIABLES_PROMPT;
+  use crate::config::manifest_file::DOT_MANIFEST;
+  use crate::error::ProcessCommandErrorReason;
+  use crate::error::inheritance_error_reason::InheritanceErrorReason;
+  use crate::processor::string_token_replacer::EchoingStringTokenReplacer;
+  use crate::templates::DefaultTemplateVariableProvider;
+  use pretty_assertions::assert_eq;
+
+  fn create_repository(root: &Path, name: &str, parent: Option<&str>, variables: Option<&str>, files: &[(&str, &str)]) -> RepositoryDir {
+    let repository_dir = root.join(name);
+    fs::create_dir_all(repository_dir.join("template")).unwrap();
+
+    let parent_field = parent.map(|p| s!(r#", "parent": {{ "repository": "{}" }}"#, p)).unwrap_or_default();
+    fs::write(repository_dir.join(DOT_MANIFEST), s!(r#"{{ "name": "{}"{} }}"#, name, parent_field)).unwrap();
+
+    if let Some(variables) = variables {
+      fs::write(repository_dir.join(DOT_VARIABLES_PROMPT), variables).unwrap();
+    }
+
+    for (file, content) in files {
+      let file_path = repository_dir.join("template").join(file);
+      fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+      fs::write(file_path, content).unwrap();
+    }
+
+    RepositoryDir::from(repository_dir.as_path())
+  }
+
+  fn load_parents(repository_dir: &RepositoryDir) -> ZatResult<ParentRepositories> {
+    let manifest = ManifestFile::from(repository_dir.clone()).load()?;
+    ParentRepositories::load(repository_dir, manifest.as_ref())
+  }
+
+  #[test]
+  fn layers_templates_and_variables_from_parents() {
+    let root = TempDir::new().unwrap();
+    let target_dir = TempDir::new().unwrap();
+
+    create_repository(
+      root.path(),
+      "base",
+      None,
+      Some(r#"[{ "variable_name": "project", "description": "Project", "prompt": "Project?" }, { "variable_name": "license", "description": "License", "prompt": "License?" }]"#),
+      &[("LICENSE", "base license"), ("ci/build.yml", "base build")]
+    );
+
+    create_repository(
+      root.path(),
+      "rust",
+      Some("../base"),
+      None,
+      &[("ci/build.yml", "rust build"), ("Cargo.toml", "rust cargo")]
+    );
+
+    let repository_dir =
+      create_repository(
+        root.path(),
+        "rust-cli",
+        Some("../rust"),
+        Some(r#"[{ "variable_name": "license", "description": "License", "prompt": "Which license?" }, { "variable_name": "binary", "description": "Binary", "prompt": "Binary?" }]"#),
+        &[("Cargo.toml", "cli cargo"), ("src/main.rs", "cli main")]
+      );
+
+    let mut parent_repositories = load_parents(&repository_dir).unwrap();
+    let user_config = UserConfig::new(repository_dir.path(), &target_dir.path().to_string_lossy());
+
+    let variables = parent_repositories.template_variables(&DefaultTemplateVariableProvider::new(), &user_config).unwrap();
+    let variable_prompts: Vec<(&str, &str)> = variables.tokens.iter().map(|t| (t.variable_name.as_str(), t.prompt.as_str())).collect();
+
+    assert_eq!(variable_prompts, vec![("project", "Project?"), ("license", "Which license?"), ("binary", "Binary?")]);
+
+    let layered_config = parent_repositories.layer_templates(user_config, &EchoingStringTokenReplacer).unwrap();
+    let layered_dir = Path::new(layered_config.template_files_dir.path());
+    let read = |file: &str| fs::read_to_string(layered_dir.join(file)).unwrap();
+
+    assert_eq!(read("LICENSE"), "base license");
+    assert_eq!(read("ci/build.yml"), "rust build");
+    assert_eq!(read("Cargo.toml"), "cli cargo");
+    assert_eq!(read("src/main.rs"), "cli main");
+
+    parent_repositories.remove();
+    assert!(!layered_dir.exists())
+  }
+
+  #[test]
+  fn fails_on_missing_parents() {
+    let root = TempDir::new().unwrap();
+    let repository_dir = create_repository(root.path(), "rust-cli", Some("../base"), None, &[]);
+
+    assert_error_with!(
+      load_parents(&repository_dir),
+      Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::InheritanceError(InheritanceErrorReason::ParentRepositoryDoesNotExist(..)))) => (),
+      |_| ()
+    )
+  }
+
+  #[test]
+  fn fails_on_parent_cycles() {
+    let root = TempDir::new().unwrap();
+    create_repository(root.path(), "base", Some("../rust-cli"), None, &[]);
+    let repository_dir = create_repository(root.path(), "rust-cli", Some("../base"), None, &[]);
+
+    assert_error_with!(
+      load_parents(&repository_dir),
+      Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::InheritanceError(InheritanceErrorReason::ParentRepositoryCycle(error, _)))) => error,
+      |error: String| assert!(error.starts_with("The parent repository '../rust-cli'"))
+    )
+  }
+}
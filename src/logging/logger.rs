@@ -1,12 +1,61 @@
 use std::{println as p, eprintln as e};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
 use ansi_term::Colour::{Yellow, Red, Green};
 
+/// When set, only errors are logged. Used when stdout is for output that tools read, such as JSON.
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+  /// When set, messages logged on this thread are kept instead of printed. See Logger::capture.
+  static CAPTURED: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
 pub struct Logger;
 
 impl Logger {
 
+  pub (crate) fn quiet() {
+    QUIET.store(true, Ordering::Relaxed)
+  }
+
+  fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+  }
+
+  /// Runs the action, returning the messages it logged instead of printing them. Used to log the output of work done
+  /// in parallel in a deterministic order with Logger::replay.
+  pub (crate) fn capture<T>(action: impl FnOnce() -> T) -> (T, Vec<String>) {
+    CAPTURED.with(|captured| captured.replace(Some(vec![])));
+    let result = action();
+    let messages = CAPTURED.with(|captured| captured.replace(None)).unwrap_or_default();
+
+    (result, messages)
+  }
+
+  pub (crate) fn replay(messages: Vec<String>) {
+    for message in messages {
+      p!("{}", message)
+    }
+  }
+
+  fn print(message: String) {
+    let unprinted =
+      CAPTURED.with(|captured| {
+        match captured.borrow_mut().as_mut() {
+          Some(messages) => { messages.push(message); None },
+          None => Some(message),
+        }
+      });
+
+    if let Some(message) = unprinted {
+      p!("{}", message)
+    }
+  }
+
   pub (crate) fn info(message: &str) {
-    p!("\n{}", Yellow.paint(message))
+    if Self::is_quiet() { return }
+    Self::print(format!("\n{}", Yellow.paint(message)))
   }
 
   pub (crate) fn info_str(message: &str) -> String {
@@ -14,15 +63,18 @@ impl Logger {
   }
 
   pub (crate) fn success(message: &str) {
-    p!("\n{}", Green.paint(message))
+    if Self::is_quiet() { return }
+    Self::print(format!("\n{}", Green.paint(message)))
   }
 
   pub (crate) fn coloured(message: &str) {
-    p!("\n{}", message)
+    if Self::is_quiet() { return }
+    Self::print(format!("\n{}", message))
   }
 
   pub (crate) fn warn(message: &str) {
-    p!("\n{}", Red.paint(message))
+    if Self::is_quiet() { return }
+    Self::print(format!("\n{}", Red.paint(message)))
   }
 
   pub (crate) fn error(message: &str, error: String) {
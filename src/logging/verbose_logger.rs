@@ -31,6 +31,23 @@ impl VerboseLogger {
     }
   }
 
+  /// Logs where each plugin is run from, as plugins bundled with the repository are used before those on the PATH.
+  pub(crate) fn log_plugin_paths(user_config: &UserConfig, template_variables: &TemplateVariables) {
+    if user_config.verbose && !user_config.no_plugins {
+      let plugin_paths: Vec<String> =
+        template_variables
+          .tokens
+          .iter()
+          .filter_map(|variable| variable.plugin.as_ref().map(|plugin| (&variable.variable_name, plugin)))
+          .map(|(variable_name, plugin)| format!("{}: {} -> {}", variable_name, plugin.id, plugin.path()))
+          .collect();
+
+      if !plugin_paths.is_empty() {
+        Printer::print_verbose_strings("Plugin paths", plugin_paths)
+      }
+    }
+  }
+
   pub(crate) fn log_user_supplied_variables(user_config: &UserConfig, user_supplied_values: &ValidConfig) {
     if user_config.verbose {
       Printer::print_verbose("User Supplied Values", user_supplied_values);
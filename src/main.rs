@@ -11,6 +11,11 @@ mod workflow;
 mod command;
 mod plugin;
 mod choice;
+mod registry;
+mod inheritance;
+mod templatize;
+mod template_tests;
+mod trust;
 
 use error::ZatAction;
 use logging::Logger;
@@ -0,0 +1,55 @@
+use std::fs;
+use std::format as s;
+
+use crate::config::RepositoryDir;
+use crate::logging::Logger;
+
+/// Plugins bundled in the plugins directory of a Zat repository.
+pub struct BundledPlugins;
+
+impl BundledPlugins {
+
+  /// Checkouts and archives don't always keep the executable bit of files, so bundled plugins are made executable
+  /// once a remote repository is available locally. Failures are only warned about, as running the plugin reports them.
+  #[cfg(unix)]
+  pub fn make_executable(repository_dir: &RepositoryDir) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let entries =
+      match fs::read_dir(repository_dir.plugins_dir()) {
+        Ok(entries) => entries,
+        Err(_) => return,
+      };
+
+    for path in entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()).filter(|path| path.is_file()) {
+      fs::set_permissions(&path, fs::Permissions::from_mode(0o755))
+        .unwrap_or_else(|e| Logger::warn(&s!("Could not make the plugin '{}' executable, reason: {}", path.to_string_lossy(), e)))
+    }
+  }
+
+  #[cfg(not(unix))]
+  pub fn make_executable(_repository_dir: &RepositoryDir) {}
+}
+
+
+#[cfg(all(test, unix))]
+mod tests {
+  use super::*;
+  use crate::config::PLUGINS_DIR;
+  use pretty_assertions::assert_eq;
+  use std::os::unix::fs::PermissionsExt;
+  use tempfile::TempDir;
+
+  #[test]
+  fn makes_bundled_plugins_executable() {
+    let repository_dir = TempDir::new().unwrap();
+    let plugin = repository_dir.path().join(PLUGINS_DIR).join("author.sh");
+    fs::create_dir(repository_dir.path().join(PLUGINS_DIR)).unwrap();
+    fs::write(&plugin, "#!/bin/bash").unwrap();
+    fs::set_permissions(&plugin, fs::Permissions::from_mode(0o644)).unwrap();
+
+    BundledPlugins::make_executable(&RepositoryDir::from(repository_dir.path()));
+
+    assert_eq!(fs::metadata(&plugin).unwrap().permissions().mode() & 0o777, 0o755)
+  }
+}
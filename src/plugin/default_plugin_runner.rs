@@ -1,18 +1,80 @@
-use std::process::Command;
+use std::io::{Read, Write};
+use std::process::{Child, Command, Output, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
-use super::{PluginResult, PluginRunner};
+use super::{PluginContext, PluginDescription, PluginResponse, PluginResult, PluginRunner, DESCRIBE_PLUGIN_ARG, PLUGIN_PROTOCOL_VERSION};
 use crate::logging::Logger;
-use crate::templates::{Plugin, ArgType};
+use crate::processor::StringTokenReplacer;
+use crate::templates::{Plugin, PluginArg, ArgType};
 use crate::error::{ZatResult, ZatError};
 use std::format as s;
 
 pub struct DefaultPluginRunner;
 
+/// What a plugin wrote to a stream, shared with the thread that reads it.
+type SharedOutput = Arc<Mutex<Vec<u8>>>;
+
 impl PluginRunner for DefaultPluginRunner {
-  fn run_plugin(&self, plugin: Plugin) -> ZatResult<PluginResult> {
-    Logger::info(&s!("Running plugin: {}", Self::generate_command_string(&plugin)));
+  fn run_plugin(&self, plugin: Plugin, context: &PluginContext) -> ZatResult<PluginResult> {
+    let plugin = Self::replace_tokens(plugin, context);
+    let program = Self::generate_command_string(&plugin);
+    Logger::info(&s!("Running plugin: {}", program));
+
+    let plugin_path = plugin.path();
+    let context = context.for_repository(&plugin.repository_dir);
+    let context_json = serde_json::to_string(&context).map_err(|e| ZatError::could_not_run_plugin(&program, &plugin_path, e.to_string()))?;
+
+    let mut command = Self::command(&plugin, &plugin_path);
+    command.envs(context.env_vars());
+
+    let (result, std_err) = Self::run_command(command, &program, &plugin_path, Some(&context_json), plugin.timeout())?;
 
-    let mut command = Command::new(&plugin.id);
+    let response: PluginResponse =
+      serde_json::from_str(&result)
+        .map_err(|e| ZatError::could_not_decode_plugin_result_to_json(&program, e.to_string(), &result, &std_err))?;
+
+    if !response.is_supported() {
+      return Err(ZatError::plugin_protocol_not_supported(&program, response.protocol_version, PLUGIN_PROTOCOL_VERSION))
+    }
+
+    for warning in &response.warnings {
+      Logger::warn(&s!("Plugin '{}' warned: {}", program, warning))
+    }
+
+    Ok(response.result)
+  }
+}
+
+impl DefaultPluginRunner {
+
+  const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+  /// How long to wait for the rest of the output of a plugin once it has finished. Processes a plugin starts in the
+  /// background can keep its output open, so the output is not waited on forever.
+  const OUTPUT_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+  pub fn new() -> Self {
+    DefaultPluginRunner
+  }
+
+  /// Runs the plugin with DESCRIBE_PLUGIN_ARG, so it describes the arguments it accepts instead of returning a result.
+  pub fn describe_plugin(&self, plugin: &Plugin) -> ZatResult<PluginDescription> {
+    let program = s!("{} {}", plugin.id, DESCRIBE_PLUGIN_ARG);
+    let plugin_path = plugin.path();
+
+    let mut command = Command::new(&plugin_path);
+    command.arg(DESCRIBE_PLUGIN_ARG);
+
+    let (result, std_err) = Self::run_command(command, &program, &plugin_path, None, plugin.timeout())?;
+
+    serde_json::from_str(&result)
+      .map_err(|e| ZatError::could_not_decode_plugin_result_to_json(&program, e.to_string(), &result, &std_err))
+  }
+
+  fn command(plugin: &Plugin, plugin_path: &str) -> Command {
+    let mut command = Command::new(plugin_path);
 
     match &plugin.args {
       None => (),
@@ -28,39 +90,143 @@ impl PluginRunner for DefaultPluginRunner {
       },
     };
 
-    let program = Self::generate_command_string(&plugin);
+    command
+  }
+
+  /// Runs the command, writing any input to its stdin, and returns what it wrote to stdout and stderr.
+  fn run_command(mut command: Command, program: &str, plugin_path: &str, input: Option<&str>, timeout: Duration) -> ZatResult<(String, String)> {
+    let mut child =
+      command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| ZatError::could_not_run_plugin(program, plugin_path, e.to_string()))?;
+
+    // Plugins don't have to read their input, so it is written on its own thread to not block the timeout if the
+    // plugin never reads it. Plugins may also exit before it is written.
+    if let Some(mut stdin) = child.stdin.take() {
+      let input = input.unwrap_or_default().to_owned();
+      thread::spawn(move || {
+        let _ = stdin.write_all(input.as_bytes());
+      });
+    }
 
-    let output =
-        command
-          .output()
-          .map_err(|e| ZatError::could_not_run_plugin(&program, e.to_string()))?;
+    let output = Self::wait_with_timeout(child, program, plugin_path, timeout)?;
 
     if !&output.status.success() {
-      return Err(ZatError::plugin_return_invalid_status_code(&program, output.status.code().as_ref()));
+      return Err(ZatError::plugin_return_invalid_status_code(program, output.status.code().as_ref()));
     }
 
-    let mut result =
-      std::str::from_utf8(&output.stdout).map_err(|e| ZatError::could_not_decode_plugin_result_to_utf8(&program, e.to_string()))?;
-
-    result = result.trim();
+    let result =
+      std::str::from_utf8(&output.stdout).map_err(|e| ZatError::could_not_decode_plugin_result_to_utf8(program, e.to_string()))?;
 
     let std_err =
-      std::str::from_utf8(&output.stderr).map_err(|e| ZatError::could_not_decode_plugin_stderr_to_utf8(&program, e.to_string()))?;
+      std::str::from_utf8(&output.stderr).map_err(|e| ZatError::could_not_decode_plugin_stderr_to_utf8(program, e.to_string()))?;
+
+    Ok((result.trim().to_owned(), std_err.to_owned()))
+  }
 
-    let plugin_result: PluginResult =
-      serde_json::from_str(result)
-        .map_err(|e| ZatError::could_not_decode_plugin_result_to_json(&program, e.to_string(), result, std_err))?;
+  /// Waits for the plugin to finish, stopping it if it runs for longer than the timeout. Output is read while waiting
+  /// so that a plugin that writes a lot of output does not block on a full pipe.
+  fn wait_with_timeout(mut child: Child, program: &str, plugin_path: &str, timeout: Duration) -> ZatResult<Output> {
+    let stdout = child.stdout.take().map(Self::read_in_background);
+    let stderr = child.stderr.take().map(Self::read_in_background);
+
+    let started = Instant::now();
+
+    let status =
+      loop {
+        match child.try_wait().map_err(|e| ZatError::could_not_run_plugin(program, plugin_path, e.to_string()))? {
+          Some(status) => break status,
+          None if started.elapsed() >= timeout => {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(ZatError::plugin_timed_out(program, timeout, started.elapsed()))
+          },
+          None => thread::sleep(Self::POLL_INTERVAL),
+        }
+      };
+
+    let readers: Vec<&JoinHandle<()>> = stdout.iter().chain(stderr.iter()).map(|(reader, _)| reader).collect();
+    Self::wait_for_output(&readers);
+
+    let collect = |reader: Option<(JoinHandle<()>, SharedOutput)>| {
+      reader
+        .map(|(_, buffer)| buffer.lock().unwrap_or_else(|e| e.into_inner()).clone())
+        .unwrap_or_default()
+    };
 
-    Ok(plugin_result)
+    Ok(
+      Output {
+        status,
+        stdout: collect(stdout),
+        stderr: collect(stderr),
+      }
+    )
   }
-}
 
-impl DefaultPluginRunner {
-  pub fn new() -> Self {
-    DefaultPluginRunner
+  /// Reads the stream into a buffer until it is closed. The buffer is shared, so that what was read can be used even if
+  /// the stream is never closed.
+  fn read_in_background(mut reader: impl Read + Send + 'static) -> (JoinHandle<()>, SharedOutput) {
+    let buffer = Arc::new(Mutex::new(vec![]));
+    let shared_buffer = Arc::clone(&buffer);
+
+    let handle =
+      thread::spawn(move || {
+        let mut chunk = [0; 8192];
+        while let Ok(read) = reader.read(&mut chunk) {
+          if read == 0 {
+            break
+          }
+
+          shared_buffer.lock().unwrap_or_else(|e| e.into_inner()).extend_from_slice(&chunk[..read])
+        }
+      });
+
+    (handle, buffer)
+  }
+
+  fn wait_for_output(readers: &[&JoinHandle<()>]) {
+    let started = Instant::now();
+
+    while readers.iter().any(|reader| !reader.is_finished()) && started.elapsed() < Self::OUTPUT_GRACE_PERIOD {
+      thread::sleep(Self::POLL_INTERVAL)
+    }
   }
 
-  fn generate_command_string(plugin: &Plugin) -> String {
+  /// Replaces the tokens of answered variables in the arguments of the plugin.
+  fn replace_tokens(plugin: Plugin, context: &PluginContext) -> Plugin {
+    let args =
+      plugin
+        .args
+        .map(|args| {
+          match args {
+            ArgType::MutlipleArgs(items) => {
+              ArgType::MutlipleArgs(
+                items
+                  .into_iter()
+                  .map(|item| PluginArg { value: context.replace(&item.value), ..item })
+                  .collect()
+              )
+            },
+            ArgType::ArgLine(items) => ArgType::ArgLine(items.iter().map(|item| context.replace(item)).collect()),
+          }
+        });
+
+    Plugin {
+      args,
+      ..plugin
+    }
+  }
+
+  /// The command line the plugin is run with, once the tokens in its arguments have been replaced.
+  pub fn resolved_command_string(plugin: &Plugin, context: &PluginContext) -> String {
+    Self::generate_command_string(&Self::replace_tokens(plugin.clone(), context))
+  }
+
+  /// The command line the plugin is run with.
+  pub fn generate_command_string(plugin: &Plugin) -> String {
     let program = plugin.id.as_str();
 
     let plugin_args = plugin.args.clone();
@@ -94,7 +260,10 @@ impl DefaultPluginRunner {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::templates::PluginArg;
+    use crate::templates::{PluginArg, PluginProvides};
+    use crate::args::test_util::create_file_in;
+    use crate::error::plugin_error_reason::PluginErrorReason;
+    use tempfile::TempDir;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -110,7 +279,13 @@ mod tests {
         Plugin {
           id: "my-program".to_owned(),
           args: Some(ArgType::MutlipleArgs(args)),
+          depends_on: vec![],
+          timeout_ms: None,
+          cache_ttl_secs: None,
+          optional: false,
+          provides: PluginProvides::Value,
           result: Default::default(),
+          repository_dir: Default::default(),
       };
 
       let command_string = DefaultPluginRunner:: generate_command_string(&plugin);
@@ -135,7 +310,13 @@ mod tests {
         Plugin {
           id: "my-program".to_owned(),
           args: Some(ArgType::ArgLine(args)),
+          depends_on: vec![],
+          timeout_ms: None,
+          cache_ttl_secs: None,
+          optional: false,
+          provides: PluginProvides::Value,
           result: Default::default(),
+          repository_dir: Default::default(),
       };
 
       let command_string = DefaultPluginRunner:: generate_command_string(&plugin);
@@ -150,11 +331,48 @@ mod tests {
         Plugin {
           id: "my-program".to_owned(),
           args: None,
+          depends_on: vec![],
+          timeout_ms: None,
+          cache_ttl_secs: None,
+          optional: false,
+          provides: PluginProvides::Value,
           result: Default::default(),
+          repository_dir: Default::default(),
       };
 
       let command_string = DefaultPluginRunner:: generate_command_string(&plugin);
 
       assert_eq!(command_string, "my-program")
     }
+
+    fn script(content: &str) -> (TempDir, String) {
+      let script_dir = TempDir::new().unwrap();
+      create_file_in(script_dir.path(), "plugin.sh", content.as_bytes(), Some(0o755));
+      let script_path = script_dir.path().join("plugin.sh").to_string_lossy().to_string();
+
+      (script_dir, script_path)
+    }
+
+    #[test]
+    fn times_out_if_the_plugin_does_not_read_its_input() {
+      let (_script_dir, script_path) = script("#!/bin/sh\nsleep 5");
+      let input = "x".repeat(1024 * 1024);
+
+      let started = Instant::now();
+      let result = DefaultPluginRunner::run_command(Command::new(&script_path), "plugin.sh", &script_path, Some(&input), Duration::from_millis(200));
+
+      assert!(matches!(result, Err(ZatError::PluginError(PluginErrorReason::PluginTimedOut(..)))), "{:?}", result);
+      assert!(started.elapsed() < Duration::from_secs(3))
+    }
+
+    #[test]
+    fn returns_the_output_if_a_background_process_keeps_it_open() {
+      let (_script_dir, script_path) = script("#!/bin/sh\necho 'result'\nsleep 5 &");
+
+      let started = Instant::now();
+      let result = DefaultPluginRunner::run_command(Command::new(&script_path), "plugin.sh", &script_path, None, Duration::from_secs(3));
+
+      assert_eq!(result, Ok(("result".to_owned(), "".to_owned())));
+      assert!(started.elapsed() < Duration::from_secs(3))
+    }
 }
@@ -2,8 +2,19 @@ mod plugin_runner;
 mod default_plugin_runner;
 mod plugin_result;
 mod plugin_runner_workflow;
+mod plugin_context;
+mod plugin_protocol;
+mod bundled_plugins;
+mod plugin_cache;
 
 pub use default_plugin_runner::DefaultPluginRunner;
 pub use plugin_runner::PluginRunner;
-pub use plugin_result::PluginResult;
-pub use plugin_runner_workflow::PluginRunnerWorkflow;
+pub use plugin_result::{PluginResult, PluginSuccess};
+pub use plugin_runner_workflow::{PluginOutputs, PluginRunnerWorkflow};
+pub use plugin_context::PluginContext;
+pub use bundled_plugins::BundledPlugins;
+pub use plugin_cache::PluginCache;
+pub use plugin_protocol::{PluginResponse, PluginDescription, PLUGIN_PROTOCOL_VERSION, DESCRIBE_PLUGIN_ARG, is_supported_protocol_version};
+
+#[cfg(test)]
+pub use plugin_protocol::PluginArgumentDescription;
@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use std::format as s;
+
+use super::PluginSuccess;
+use crate::config::{UserConfig, ZatConfigDir};
+use crate::logging::Logger;
+
+pub const PLUGIN_CACHE_FILE: &str = "plugin-cache.toml";
+
+/// Plugins are run in parallel, so the cache file is read and written by one plugin at a time.
+static PLUGIN_CACHE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Results of plugins that set a `cache_ttl_secs`, by the id of the plugin, the command line it was run with and a hash
+/// of the answers it was sent. Stored as TOML:
+///
+/// [results."scala-deps | scala-deps -o org.scala-lang -g scala3-library -s 3 | 5d41402a..."]
+/// cached_at = 1760000000
+/// ttl_secs = 3600
+///
+/// [results."scala-deps | scala-deps -o org.scala-lang -g scala3-library -s 3 | 5d41402a...".result]
+/// result = "3.3.1"
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PluginCacheStore {
+  #[serde(default)]
+  pub results: BTreeMap<String, CachedPluginResult>
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedPluginResult {
+  /// When the result was cached, in seconds since the Unix epoch.
+  pub cached_at: u64,
+
+  /// How long the result can be reused for. Results cached before this was recorded are expired.
+  #[serde(default)]
+  pub ttl_secs: u64,
+  pub result: PluginSuccess,
+}
+
+impl PluginCacheStore {
+
+  /// The cached result of the command line, if it was cached less than `ttl` before `now`.
+  pub fn get(&self, command: &str, ttl: Duration, now: u64) -> Option<PluginSuccess> {
+    self
+      .results
+      .get(command)
+      .filter(|cached| now.saturating_sub(cached.cached_at) < ttl.as_secs())
+      .map(|cached| cached.result.clone())
+  }
+
+  /// Caches the result of the command line, and drops any results that have expired so the cache doesn't keep growing.
+  pub fn put(&mut self, command: &str, result: &PluginSuccess, ttl: Duration, now: u64) {
+    self.results.retain(|_, cached| now.saturating_sub(cached.cached_at) < cached.ttl_secs);
+
+    self.results.insert(
+      command.to_owned(),
+      CachedPluginResult {
+        cached_at: now,
+        ttl_secs: ttl.as_secs(),
+        result: result.clone()
+      }
+    );
+  }
+}
+
+
+/// Reads and writes the cached results of plugins in the Zat configuration directory. Caching is best effort, so a
+/// cache that can't be read or written is warned about and plugins are run as usual.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginCache {
+  path: Option<PathBuf>
+}
+
+impl PluginCache {
+
+  /// A cache in the Zat configuration directory, or a disabled cache when `--no-plugin-cache` is supplied.
+  pub fn new(user_config: &UserConfig) -> Self {
+    if user_config.no_plugin_cache {
+      Self::disabled()
+    } else {
+      ZatConfigDir::new()
+        .map(|config_dir| Self::from(&config_dir))
+        .unwrap_or_else(|_| Self::disabled())
+    }
+  }
+
+  /// A cache that never has results and does not store any.
+  pub fn disabled() -> Self {
+    Self {
+      path: None
+    }
+  }
+
+  pub fn get(&self, command: &str, ttl: Duration) -> Option<PluginSuccess> {
+    let path = self.path.as_ref()?;
+    let _lock = PLUGIN_CACHE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    Self::load(path).get(command, ttl, Self::now())
+  }
+
+  pub fn put(&self, command: &str, result: &PluginSuccess, ttl: Duration) {
+    if let Some(path) = &self.path {
+      let _lock = PLUGIN_CACHE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+      let mut store = Self::load(path);
+      store.put(command, result, ttl, Self::now());
+
+      Self::save(path, &store)
+        .unwrap_or_else(|e| Logger::warn(&s!("Could not cache the result of plugin '{}' in '{}', reason: {}", command, path.to_string_lossy(), e)))
+    }
+  }
+
+  /// Returns an empty store if the cache file does not exist or can't be read.
+  fn load(path: &Path) -> PluginCacheStore {
+    if !path.exists() {
+      return PluginCacheStore::default()
+    }
+
+    fs::read_to_string(path)
+      .map_err(|e| e.to_string())
+      .and_then(|content| toml::from_str(&content).map_err(|e| e.to_string()))
+      .unwrap_or_else(|e| {
+        Logger::warn(&s!("Could not read the plugin cache '{}', so plugins will be run again. Reason: {}", path.to_string_lossy(), e));
+        PluginCacheStore::default()
+      })
+  }
+
+  fn save(path: &Path, store: &PluginCacheStore) -> Result<(), String> {
+    let content = toml::to_string_pretty(store).map_err(|e| e.to_string())?;
+
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    fs::write(path, content).map_err(|e| e.to_string())
+  }
+
+  fn now() -> u64 {
+    SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map(|duration| duration.as_secs())
+      .unwrap_or_default()
+  }
+}
+
+impl From<&ZatConfigDir> for PluginCache {
+  fn from(config_dir: &ZatConfigDir) -> Self {
+    Self {
+      path: Some(config_dir.join(PLUGIN_CACHE_FILE))
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::templates::Choice;
+  use pretty_assertions::assert_eq;
+  use tempfile::TempDir;
+
+  fn value(result: &str) -> PluginSuccess {
+    PluginSuccess::Value { result: result.to_owned() }
+  }
+
+  #[test]
+  fn only_returns_results_within_their_ttl() {
+    let mut store = PluginCacheStore::default();
+    store.put("scala-deps -s 3", &value("3.3.1"), Duration::from_secs(60), 1000);
+
+    assert_eq!(store.get("scala-deps -s 3", Duration::from_secs(60), 1059), Some(value("3.3.1")));
+    assert_eq!(store.get("scala-deps -s 3", Duration::from_secs(60), 1060), None);
+    assert_eq!(store.get("scala-deps -s 2", Duration::from_secs(60), 1000), None)
+  }
+
+  #[test]
+  fn drops_expired_results_when_caching_another() {
+    let mut store = PluginCacheStore::default();
+    store.put("scala-deps -s 2", &value("2.13.12"), Duration::from_secs(60), 1000);
+    store.put("scala-deps -s 3", &value("3.3.1"), Duration::from_secs(3600), 1000);
+    store.put("scala-versions", &value("3"), Duration::from_secs(60), 1060);
+
+    assert_eq!(store.results.keys().collect::<Vec<_>>(), vec!["scala-deps -s 3", "scala-versions"])
+  }
+
+  #[test]
+  fn saves_and_loads_cached_results() {
+    let config_dir = TempDir::new().unwrap();
+    let plugin_cache = PluginCache::from(&ZatConfigDir::from(config_dir.path().join("nested").as_path()));
+
+    let choices = PluginSuccess::Choices { choices: vec![Choice::new("Scala 3", "The latest Scala 3", "3.3.1")] };
+    let outputs = PluginSuccess::Outputs { outputs: BTreeMap::from([("scala_version".to_owned(), "3.3.1".to_owned())]) };
+
+    plugin_cache.put("scala-deps -s 3", &value("3.3.1"), Duration::from_secs(60));
+    plugin_cache.put("scala-versions", &choices, Duration::from_secs(60));
+    plugin_cache.put("scala-outputs", &outputs, Duration::from_secs(60));
+
+    assert_eq!(plugin_cache.get("scala-deps -s 3", Duration::from_secs(60)), Some(value("3.3.1")));
+    assert_eq!(plugin_cache.get("scala-versions", Duration::from_secs(60)), Some(choices));
+    assert_eq!(plugin_cache.get("scala-outputs", Duration::from_secs(60)), Some(outputs))
+  }
+
+  #[test]
+  fn a_disabled_cache_has_no_results() {
+    let plugin_cache = PluginCache::disabled();
+    plugin_cache.put("scala-deps -s 3", &value("3.3.1"), Duration::from_secs(60));
+
+    assert_eq!(plugin_cache.get("scala-deps -s 3", Duration::from_secs(60)), None)
+  }
+}
@@ -0,0 +1,199 @@
+use std::collections::{BTreeMap, HashMap};
+use serde::Serialize;
+
+use super::PLUGIN_PROTOCOL_VERSION;
+use crate::config::{RepositoryDir, UserConfig};
+use crate::processor::StringTokenReplacer;
+use crate::templates::{TemplateVariables, UserChoiceKey, UserChoiceValue, UserVariableKey, UserVariableValue};
+use crate::token_expander::{DefaultExpandFilters, ExpandFilters};
+
+/// What a plugin knows about the template being processed when it is run. Sent to the plugin as JSON on stdin and as
+/// `ZAT_*` environment variables.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct PluginContext {
+  /// The version of the plugin protocol the response should follow.
+  pub protocol_version: u32,
+
+  /// The answered variables, by variable name.
+  pub variables: BTreeMap<String, String>,
+
+  /// The value of each selected choice, by variable name.
+  pub choices: BTreeMap<String, String>,
+  pub target_dir: String,
+  pub repository_dir: String,
+
+  /// The answered variables and the tokens of their filters, such as $project__snake$.
+  #[serde(skip)]
+  tokens: BTreeMap<String, String>,
+}
+
+impl PluginContext {
+
+  pub const PROTOCOL_VERSION_ENV: &'static str = "ZAT_PLUGIN_PROTOCOL_VERSION";
+  pub const TARGET_DIR_ENV: &'static str = "ZAT_TARGET_DIR";
+  pub const REPOSITORY_DIR_ENV: &'static str = "ZAT_REPOSITORY_DIR";
+  pub const VARIABLE_ENV_PREFIX: &'static str = "ZAT_VAR_";
+  pub const CHOICE_ENV_PREFIX: &'static str = "ZAT_CHOICE_";
+
+  pub fn new(user_config: &UserConfig, template_variables: &TemplateVariables, user_variables: &HashMap<UserVariableKey, UserVariableValue>, user_choices: &HashMap<UserChoiceKey, UserChoiceValue>) -> Self {
+    let variables =
+      user_variables
+        .iter()
+        .map(|(key, value)| (key.value.clone(), value.value.clone()))
+        .collect();
+
+    let choices =
+      user_choices
+        .iter()
+        .map(|(key, value)| (key.value.clone(), value.value.value.clone()))
+        .collect();
+
+    let tokens =
+      DefaultExpandFilters::new()
+        .expand_filers(template_variables.clone(), user_variables.clone())
+        .value
+        .into_iter()
+        .map(|(key, value)| (key.value, value.value))
+        .collect();
+
+    Self {
+      protocol_version: PLUGIN_PROTOCOL_VERSION,
+      variables,
+      choices,
+      target_dir: user_config.target_dir.path.clone(),
+      repository_dir: user_config.repository_dir.path().to_owned(),
+      tokens,
+    }
+  }
+
+  /// The context of a plugin defined by a repository, which may be a parent or another repository processed along with
+  /// the repository being processed.
+  pub fn for_repository(&self, repository_dir: &RepositoryDir) -> Self {
+    Self {
+      repository_dir: repository_dir.path().to_owned(),
+      ..self.clone()
+    }
+  }
+
+  /// The environment variables to run a plugin with.
+  pub fn env_vars(&self) -> Vec<(String, String)> {
+    std::iter::once((Self::PROTOCOL_VERSION_ENV.to_owned(), self.protocol_version.to_string()))
+      .chain(self.template_env_vars())
+      .collect()
+  }
+
+  /// The environment variables for the directories, answered variables and selected choices of the template. Shared
+  /// by plugins and hooks. Variable and choice names are upper-cased, and any characters that are not letters or digits
+  /// are replaced with '_'.
+  pub fn template_env_vars(&self) -> Vec<(String, String)> {
+    let variables =
+      self
+        .variables
+        .iter()
+        .map(|(name, value)| (format!("{}{}", Self::VARIABLE_ENV_PREFIX, Self::env_name(name)), value.clone()));
+
+    let choices =
+      self
+        .choices
+        .iter()
+        .map(|(name, value)| (format!("{}{}", Self::CHOICE_ENV_PREFIX, Self::env_name(name)), value.clone()));
+
+    vec![
+      (Self::TARGET_DIR_ENV.to_owned(), self.target_dir.clone()),
+      (Self::REPOSITORY_DIR_ENV.to_owned(), self.repository_dir.clone()),
+    ]
+    .into_iter()
+    .chain(variables)
+    .chain(choices)
+    .collect()
+  }
+
+  fn env_name(name: &str) -> String {
+    name
+      .chars()
+      .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+      .collect()
+  }
+}
+
+impl StringTokenReplacer for PluginContext {
+  /// Replaces the tokens of answered variables. Tokens of variables that have not been answered are left as is.
+  fn replace(&self, input: &str) -> String {
+    // Replace longer tokens first, so that $project__snake$ is not replaced by a shorter token that it contains
+    let mut tokens: Vec<(&String, &String)> = self.tokens.iter().collect();
+    tokens.sort_by_key(|(token, _)| std::cmp::Reverse(token.len()));
+
+    tokens
+      .into_iter()
+      .fold(input.to_owned(), |replaced, (token, value)| replaced.replace(token, value))
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::templates::{Choice, FilterType, TemplateVariable, VariableFilter};
+  use pretty_assertions::assert_eq;
+
+  fn context() -> PluginContext {
+    let template_variables =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable {
+            variable_name: "project-name".to_owned(),
+            description: "Project".to_owned(),
+            prompt: "Please enter your project name".to_owned(),
+            filters: VariableFilter::from_pairs(&[("snake", &FilterType::Snake)]),
+            default_value: None,
+            plugin: None,
+            choices: vec![],
+            scopes: None,
+          }
+        ]
+      );
+
+    let user_variables = HashMap::from([(UserVariableKey::new("project-name".to_owned()), UserVariableValue::new("My Project".to_owned()))]);
+    let user_choices = HashMap::from([(UserChoiceKey::new("readme_type".to_owned()), UserChoiceValue::new(Choice::new("Long", "A long README", "long")))]);
+
+    PluginContext::new(&UserConfig::new("/repository", "/target"), &template_variables, &user_variables, &user_choices)
+  }
+
+  #[test]
+  fn env_vars_include_variables_choices_and_directories() {
+    assert_eq!(
+      context().env_vars(),
+      vec![
+        ("ZAT_PLUGIN_PROTOCOL_VERSION".to_owned(), "2".to_owned()),
+        ("ZAT_TARGET_DIR".to_owned(), "/target".to_owned()),
+        ("ZAT_REPOSITORY_DIR".to_owned(), "/repository".to_owned()),
+        ("ZAT_VAR_PROJECT_NAME".to_owned(), "My Project".to_owned()),
+        ("ZAT_CHOICE_README_TYPE".to_owned(), "long".to_owned()),
+      ]
+    )
+  }
+
+  #[test]
+  fn replaces_tokens_of_answered_variables() {
+    assert_eq!(
+      context().replace("--name $project-name__snake$ --title $project-name$ --unknown $author$"),
+      "--name my_project --title My Project --unknown $author$"
+    )
+  }
+
+  #[test]
+  fn serializes_to_json_without_tokens() {
+    let json: serde_json::Value = serde_json::to_value(context()).unwrap();
+
+    assert_eq!(
+      json,
+      serde_json::json!({
+        "protocol_version": 2,
+        "variables": { "project-name": "My Project" },
+        "choices": { "readme_type": "long" },
+        "target_dir": "/target",
+        "repository_dir": "/repository"
+      })
+    )
+  }
+}
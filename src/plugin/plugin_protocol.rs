@@ -0,0 +1,106 @@
+use serde::Deserialize;
+
+use super::PluginResult;
+
+/// The version of the plugin protocol spoken by this version of Zat. Version 1 plugins only return a single result and
+/// don't include a protocol version in their responses.
+pub const PLUGIN_PROTOCOL_VERSION: u32 = 2;
+
+/// Whether this version of Zat can read responses of a version of the plugin protocol.
+pub fn is_supported_protocol_version(protocol_version: u32) -> bool {
+  (1..=PLUGIN_PROTOCOL_VERSION).contains(&protocol_version)
+}
+
+/// The argument a plugin is run with to describe itself, instead of returning a result.
+pub const DESCRIBE_PLUGIN_ARG: &str = "--zat-describe";
+
+/// What a plugin writes to stdout when it is run.
+///
+/// {"protocol_version":2,"success":{"outputs":{"scala_version":"3.3.1"}},"warnings":["Could not reach Maven Central"]}
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluginResponse {
+  #[serde(default = "PluginResponse::legacy_protocol_version")]
+  pub protocol_version: u32,
+
+  #[serde(flatten)]
+  pub result: PluginResult,
+
+  /// Displayed to the user, whether the plugin succeeded or not.
+  #[serde(default)]
+  pub warnings: Vec<String>,
+}
+
+impl PluginResponse {
+  fn legacy_protocol_version() -> u32 {
+    1
+  }
+
+  pub fn is_supported(&self) -> bool {
+    is_supported_protocol_version(self.protocol_version)
+  }
+}
+
+
+/// What a plugin writes to stdout when it is run with DESCRIBE_PLUGIN_ARG.
+///
+/// {"protocol_version":2,"name":"scala-deps","arguments":[{"name":"o","required":true,"description":"Organisation"}]}
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PluginDescription {
+  pub protocol_version: u32,
+  pub name: String,
+
+  #[serde(default)]
+  pub description: Option<String>,
+
+  #[serde(default)]
+  pub arguments: Vec<PluginArgumentDescription>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PluginArgumentDescription {
+  /// The name of the argument without its prefix, such as 'o' for '-o' or 'group' for '--group'.
+  pub name: String,
+
+  #[serde(default)]
+  pub required: bool,
+
+  #[serde(default)]
+  pub description: Option<String>,
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::plugin::PluginSuccess;
+  use pretty_assertions::assert_eq;
+  use std::collections::BTreeMap;
+
+  #[test]
+  fn responses_without_a_protocol_version_are_version_1() {
+    let response: PluginResponse = serde_json::from_str(r#"{"success":{"result":"2.10.0"}}"#).unwrap();
+
+    assert_eq!(response.protocol_version, 1);
+    assert!(response.warnings.is_empty());
+    assert!(matches!(response.result, PluginResult::Success(PluginSuccess::Value { result }) if result == "2.10.0"))
+  }
+
+  #[test]
+  fn decodes_outputs_and_warnings() {
+    let response: PluginResponse =
+      serde_json::from_str(r#"{"protocol_version":2,"success":{"outputs":{"scala":"3.3.1","sbt":"1.9.7"}},"warnings":["Using a cached version"]}"#).unwrap();
+
+    let expected_outputs = BTreeMap::from([("sbt".to_owned(), "1.9.7".to_owned()), ("scala".to_owned(), "3.3.1".to_owned())]);
+
+    assert!(response.is_supported());
+    assert_eq!(response.warnings, vec!["Using a cached version"]);
+    assert!(matches!(response.result, PluginResult::Success(PluginSuccess::Outputs { outputs }) if outputs == expected_outputs))
+  }
+
+  #[test]
+  fn newer_protocol_versions_are_not_supported() {
+    let response: PluginResponse = serde_json::from_str(r#"{"protocol_version":3,"success":{"result":"2.10.0"}}"#).unwrap();
+
+    assert!(!response.is_supported())
+  }
+}
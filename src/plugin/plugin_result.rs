@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+use crate::templates::Choice;
+
 #[derive(Debug, Clone, serde::Deserialize)]
 pub enum PluginResult {
   #[serde(rename = "success")]
@@ -10,12 +13,28 @@ pub enum PluginResult {
 impl PluginResult {
   pub fn success(result: String) -> Self {
     PluginResult::Success(
-      PluginSuccess {
+      PluginSuccess::Value {
         result
       }
     )
   }
 
+  pub fn choices(choices: Vec<Choice>) -> Self {
+    PluginResult::Success(
+      PluginSuccess::Choices {
+        choices
+      }
+    )
+  }
+
+  pub fn outputs(outputs: &[(&str, &str)]) -> Self {
+    PluginResult::Success(
+      PluginSuccess::Outputs {
+        outputs: outputs.iter().map(|(name, value)| ((*name).to_owned(), (*value).to_owned())).collect()
+      }
+    )
+  }
+
   pub fn error(plugin_name: String, error: String, exception: Option<String>, fix: String) -> Self {
       PluginResult::Error(
         PluginError {
@@ -28,9 +47,20 @@ impl PluginResult {
   }
 }
 
-#[derive(Debug, Clone, serde::Deserialize)]
-pub struct PluginSuccess {
-  pub result: String,
+/// A plugin either returns a single value, named values for several variables, or a list of choices for the user to
+/// select from. Outputs are decoded first, so that a response with both outputs and a result is not decoded as a value.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum PluginSuccess {
+  Outputs {
+    outputs: BTreeMap<String, String>
+  },
+  Value {
+    result: String
+  },
+  Choices {
+    choices: Vec<Choice>
+  },
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -1,8 +1,8 @@
 use crate::error::ZatResult;
 use crate::templates::Plugin;
-use crate::plugin::PluginResult;
+use crate::plugin::{PluginContext, PluginResult};
 
-/// Runs a plugin and returns the result
+/// Runs a plugin with the context of the template being processed and returns the result
 pub trait PluginRunner {
-  fn run_plugin(&self, plugin: Plugin) -> ZatResult<PluginResult>;
+  fn run_plugin(&self, plugin: Plugin, context: &PluginContext) -> ZatResult<PluginResult>;
 }
@@ -1,32 +1,207 @@
-use super::{PluginResult, PluginRunner};
-use crate::templates::{TemplateVariables, PluginRunResult, PluginRunStatus};
-use crate::error::{ZatError, ZatAction};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use sha2::{Digest, Sha256};
+
+use super::{DefaultPluginRunner, PluginCache, PluginContext, PluginResult, PluginRunner, PluginSuccess};
+use crate::templates::{Choice, TemplateVariable, TemplateVariables, Plugin, PluginProvides, PluginRunResult, PluginRunStatus};
+use crate::error::{ZatError, ZatAction, ZatResult};
+use crate::error::plugin_error_reason::PluginErrorReason;
+use crate::logging::Logger;
+use std::format as s;
+
+/// Values returned by a plugin for other variables, by variable name.
+pub type PluginOutputs = BTreeMap<String, String>;
 
 pub struct PluginRunnerWorkflow;
 
+/// The result of running the plugin of a variable, with the messages it logged.
+type PluginRun = (ZatResult<(Plugin, PluginOutputs)>, Vec<String>);
+
 impl PluginRunnerWorkflow {
 
-  pub fn run_plugins(plugin_runner: impl PluginRunner, template_variables: &mut TemplateVariables) -> ZatAction {
+  /// The most plugins that are run at the same time.
+  pub const MAX_PARALLEL_PLUGINS: usize = 4;
+
+  /// Runs the plugins that provide a value and don't depend on any variables or choices. Plugins with dependencies are
+  /// run when their variable is prompted for, once their dependencies have been answered. Outputs a plugin returns for
+  /// other variables become their default values.
+  ///
+  /// The plugins are run in parallel, but their logs and any error are reported in the order their variables are
+  /// defined, as if they were run one after another.
+  pub fn run_plugins(plugin_runner: impl PluginRunner + Sync, template_variables: &mut TemplateVariables, context: &PluginContext, plugin_cache: &PluginCache) -> ZatAction {
+    let independent_plugins: Vec<(usize, String, Plugin)> =
+      template_variables
+        .tokens
+        .iter()
+        .enumerate()
+        .filter_map(|(index, tv)| tv.plugin.as_ref().map(|plugin| (index, tv.variable_name.clone(), plugin.clone())))
+        .filter(|(_, _, plugin)| plugin.depends_on.is_empty() && plugin.provides == PluginProvides::Value)
+        .collect();
+
+    let plugin_runs = Self::run_in_parallel(&plugin_runner, &independent_plugins, context, plugin_cache);
+
+    let mut outputs = PluginOutputs::new();
+
+    for ((index, _, _), (result, messages)) in independent_plugins.iter().zip(plugin_runs) {
+      Logger::replay(messages);
+      let (plugin, plugin_outputs) = result?;
+      template_variables.tokens[*index].plugin = Some(plugin);
+      outputs.extend(plugin_outputs)
+    }
+
+    Self::apply_outputs(template_variables.tokens.iter_mut(), outputs);
+
+    Ok(())
+  }
+
+  /// Runs each plugin on a pool of up to MAX_PARALLEL_PLUGINS threads. The runs are returned in the order of the
+  /// plugins supplied.
+  fn run_in_parallel(plugin_runner: &(impl PluginRunner + Sync), plugins: &[(usize, String, Plugin)], context: &PluginContext, plugin_cache: &PluginCache) -> Vec<PluginRun> {
+    let next_plugin = AtomicUsize::new(0);
+    let plugin_runs: Mutex<BTreeMap<usize, PluginRun>> = Mutex::new(BTreeMap::new());
+
+    thread::scope(|scope| {
+      for _ in 0..Self::MAX_PARALLEL_PLUGINS.min(plugins.len()) {
+        scope.spawn(|| {
+          loop {
+            let position = next_plugin.fetch_add(1, Ordering::Relaxed);
+
+            let (variable_name, mut plugin) =
+              match plugins.get(position) {
+                Some((_, variable_name, plugin)) => (variable_name, plugin.clone()),
+                None => break,
+              };
+
+            let (result, messages) = Logger::capture(|| Self::run_plugin(plugin_runner, variable_name, &mut plugin, context, plugin_cache));
+
+            plugin_runs
+              .lock()
+              .expect("Another plugin thread panicked") // Unexpected, so throw
+              .insert(position, (result.map(|outputs| (plugin, outputs)), messages));
+          }
+        });
+      }
+    });
+
+    plugin_runs
+      .into_inner()
+      .expect("A plugin thread panicked") // Unexpected, so throw
+      .into_values()
+      .collect()
+  }
+
+  /// Sets the default value of each variable with an output. Outputs that don't match a variable are ignored.
+  pub fn apply_outputs<'a>(variables: impl Iterator<Item = &'a mut TemplateVariable>, mut outputs: PluginOutputs) {
+    for variable in variables {
+      if let Some(value) = outputs.remove(&variable.variable_name) {
+        variable.default_value = Some(value)
+      }
+    }
+  }
+
+  /// Runs the plugins that provide choices, and sets the choices of their variables. These are run before any choices
+  /// are prompted for.
+  pub fn run_choice_plugins(plugin_runner: impl PluginRunner, template_variables: &mut TemplateVariables, context: &PluginContext, plugin_cache: &PluginCache) -> ZatAction {
 
     for tv in template_variables.tokens.iter_mut() {
-      if let Some(plugin) = tv.plugin.as_mut() {
-        let run_result = plugin_runner.run_plugin(plugin.clone());
-        match run_result {
-          Ok(PluginResult::Success(plugin_success)) => {
-            plugin.result = PluginRunStatus::Run(PluginRunResult::new(&plugin_success.result));
-          },
-          Ok(PluginResult::Error(error)) => {
-            let exception = &error.exception.unwrap_or("<No Exception>".to_owned());
-            let zerr = ZatError::plugin_returned_error(&error.plugin_name, &error.error, exception, &error.fix);
-            return Err(zerr)
-          },
-          Err(error) => return Err(error),
+      if let Some(plugin) = tv.plugin.as_ref().filter(|plugin| plugin.provides == PluginProvides::Choices) {
+        if let Some(success) = Self::run(&plugin_runner, plugin, context, plugin_cache)? {
+          match success {
+            PluginSuccess::Choices { choices } => {
+              Self::validate_choices(plugin, &tv.variable_name, &choices)?;
+              tv.choices = choices
+            },
+            PluginSuccess::Value { .. } | PluginSuccess::Outputs { .. } => return Err(ZatError::plugin_returned_result_instead_of_choices(&plugin.id)),
+          }
         }
       }
     }
 
     Ok(())
   }
+
+  /// Runs the plugin of a variable and updates it with its result. A plugin that returns outputs has the output named
+  /// after its variable as its result, and the outputs for any other variables are returned.
+  pub fn run_plugin(plugin_runner: &impl PluginRunner, variable_name: &str, plugin: &mut Plugin, context: &PluginContext, plugin_cache: &PluginCache) -> ZatResult<PluginOutputs> {
+    match Self::run(plugin_runner, plugin, context, plugin_cache)? {
+      Some(PluginSuccess::Value { result }) => {
+        plugin.result = PluginRunStatus::Run(PluginRunResult::new(&result));
+        Ok(PluginOutputs::new())
+      },
+      Some(PluginSuccess::Outputs { mut outputs }) => {
+        if let Some(result) = outputs.remove(variable_name) {
+          plugin.result = PluginRunStatus::Run(PluginRunResult::new(&result))
+        }
+        Ok(outputs)
+      },
+      Some(PluginSuccess::Choices { .. }) => Err(ZatError::plugin_returned_choices_instead_of_result(&plugin.id)),
+      None => Ok(PluginOutputs::new()),
+    }
+  }
+
+  /// Runs a plugin, returning None if it is optional and timed out. Plugins with a cache TTL return their cached result
+  /// for the same plugin, command line and context instead of being run, until it expires.
+  fn run(plugin_runner: &impl PluginRunner, plugin: &Plugin, context: &PluginContext, plugin_cache: &PluginCache) -> ZatResult<Option<PluginSuccess>> {
+    let cached_command = plugin.cache_ttl().map(|ttl| (Self::cache_key(plugin, context), ttl));
+
+    if let Some((cache_key, ttl)) = &cached_command {
+      if let Some(plugin_success) = plugin_cache.get(cache_key, *ttl) {
+        Logger::info(&s!("Using the cached result of plugin: {}", DefaultPluginRunner::resolved_command_string(plugin, context)));
+        return Ok(Some(plugin_success))
+      }
+    }
+
+    let run_result = plugin_runner.run_plugin(plugin.clone(), context);
+    match run_result {
+      Ok(PluginResult::Success(plugin_success)) => {
+        if let Some((cache_key, ttl)) = &cached_command {
+          plugin_cache.put(cache_key, &plugin_success, *ttl)
+        }
+        Ok(Some(plugin_success))
+      },
+      Ok(PluginResult::Error(error)) => {
+        let exception = &error.exception.unwrap_or("<No Exception>".to_owned());
+        Err(ZatError::plugin_returned_error(&error.plugin_name, &error.error, exception, &error.fix))
+      },
+      Err(ZatError::PluginError(PluginErrorReason::PluginTimedOut(program, error, _))) if plugin.optional => {
+        Logger::warn(&s!("Plugin '{}' is optional and timed out, so its variable will use its default value. {}", program, error));
+        Ok(None)
+      },
+      Err(error) => Err(error),
+    }
+  }
+
+  /// The key a result of the plugin is cached by. A plugin can return a different result for other answers, so the key
+  /// has its id, its command line and a hash of the answered variables and selected choices it is sent. The target and
+  /// repository directories are left out, as they change with each project generated and each remote checkout.
+  pub fn cache_key(plugin: &Plugin, context: &PluginContext) -> String {
+    let answers_json = serde_json::to_string(&(&context.variables, &context.choices)).unwrap_or_default();
+    let answers_hash: String = Sha256::digest(answers_json.as_bytes()).iter().map(|byte| s!("{:02x}", byte)).collect();
+
+    s!("{} | {} | {}", plugin.id, DefaultPluginRunner::resolved_command_string(plugin, context), answers_hash)
+  }
+
+  fn validate_choices(plugin: &Plugin, variable_name: &str, choices: &[Choice]) -> ZatAction {
+    if choices.is_empty() {
+      return Err(ZatError::invalid_plugin_choices(&plugin.id, variable_name, "The plugin did not return any choices."))
+    }
+
+    let mut values = HashSet::new();
+
+    for choice in choices {
+      if choice.display.trim().is_empty() || choice.description.trim().is_empty() || choice.value.trim().is_empty() {
+        return Err(ZatError::invalid_plugin_choices(&plugin.id, variable_name, "The plugin returned a choice with an empty 'display', 'description' or 'value'."))
+      }
+
+      if !values.insert(choice.value.as_str()) {
+        return Err(ZatError::invalid_plugin_choices(&plugin.id, variable_name, &s!("The plugin returned more than one choice with the value '{}'.", choice.value)))
+      }
+    }
+
+    Ok(())
+  }
 }
 
 
@@ -46,25 +221,116 @@ mod tests {
 
     struct FailingPluginRunner(String, String, String, String);
 
+    struct TimingOutPluginRunner;
+
+    struct ChoicePluginRunner(Vec<Choice>);
+
+    struct OutputsPluginRunner;
+
+    /// Fails with the id of the plugin, taking longer for plugins that are defined first.
+    struct SlowerFirstFailingPluginRunner;
+
+    /// Tracks the most plugins that were running at the same time.
+    struct ConcurrencyTrackingPluginRunner<'a> {
+      running: &'a AtomicUsize,
+      max_running: &'a AtomicUsize,
+    }
+
     impl PluginRunner for PanicingPluginRunner {
-        fn run_plugin(&self, _plugin: crate::templates::Plugin) -> crate::error::ZatResult<PluginResult> {
+        fn run_plugin(&self, _plugin: crate::templates::Plugin, _context: &PluginContext) -> crate::error::ZatResult<PluginResult> {
             panic!("Running the plugin failed")
         }
     }
 
     impl PluginRunner for SuccessfulPluginRunner {
 
-      fn run_plugin(&self, _plugin: crate::templates::Plugin) -> crate::error::ZatResult<PluginResult> {
+      fn run_plugin(&self, _plugin: crate::templates::Plugin, _context: &PluginContext) -> crate::error::ZatResult<PluginResult> {
           Ok(PluginResult::success(self.0.clone()))
       }
     }
 
     impl PluginRunner for FailingPluginRunner {
-      fn run_plugin(&self, _plugin: Plugin) -> crate::error::ZatResult<PluginResult> {
+      fn run_plugin(&self, _plugin: Plugin, _context: &PluginContext) -> crate::error::ZatResult<PluginResult> {
           Ok(PluginResult::error(self.0.clone(), self.1.clone(), Some(self.2.clone()), self.3.clone()))
       }
     }
 
+    impl PluginRunner for TimingOutPluginRunner {
+      fn run_plugin(&self, plugin: Plugin, _context: &PluginContext) -> crate::error::ZatResult<PluginResult> {
+          Err(ZatError::plugin_timed_out(&plugin.id, plugin.timeout(), plugin.timeout()))
+      }
+    }
+
+    impl PluginRunner for ChoicePluginRunner {
+      fn run_plugin(&self, _plugin: Plugin, _context: &PluginContext) -> crate::error::ZatResult<PluginResult> {
+          Ok(PluginResult::choices(self.0.clone()))
+      }
+    }
+
+    impl PluginRunner for OutputsPluginRunner {
+      fn run_plugin(&self, _plugin: Plugin, _context: &PluginContext) -> crate::error::ZatResult<PluginResult> {
+          Ok(PluginResult::outputs(&[("variable_name-0", "Scala 3"), ("variable_name-2", "sbt 1.9"), ("unknown", "ignored")]))
+      }
+    }
+
+    impl PluginRunner for SlowerFirstFailingPluginRunner {
+      fn run_plugin(&self, plugin: Plugin, _context: &PluginContext) -> crate::error::ZatResult<PluginResult> {
+          if plugin.id == "plugin-0" {
+            std::thread::sleep(std::time::Duration::from_millis(100))
+          }
+          Ok(PluginResult::error(plugin.id.clone(), "some error".to_owned(), None, "some fix".to_owned()))
+      }
+    }
+
+    impl PluginRunner for ConcurrencyTrackingPluginRunner<'_> {
+      fn run_plugin(&self, plugin: Plugin, _context: &PluginContext) -> crate::error::ZatResult<PluginResult> {
+          let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+          self.max_running.fetch_max(running, Ordering::SeqCst);
+          std::thread::sleep(std::time::Duration::from_millis(50));
+          self.running.fetch_sub(1, Ordering::SeqCst);
+
+          Ok(PluginResult::success(plugin.id))
+      }
+    }
+
+    fn create_plugin_variables(n: u8) -> TemplateVariables {
+      let mut template_variables = create_template_variables(n);
+
+      for (index, variable) in template_variables.tokens.iter_mut().enumerate() {
+        variable.plugin = Some(Plugin::new(&s!("plugin-{}", index), &[]))
+      }
+
+      template_variables
+    }
+
+    fn create_choice_plugin_variables() -> TemplateVariables {
+      let mut template_variables = create_template_variables(1);
+
+      template_variables.tokens[0].plugin =
+        Some(
+          Plugin {
+            provides: PluginProvides::Choices,
+            ..Plugin::new("scala-versions", &[])
+          }
+        );
+
+      template_variables
+    }
+
+    fn create_timing_out_plugin(optional: bool) -> Plugin {
+      Plugin {
+        id: "My Plugin".to_owned(),
+        args: Default::default(),
+        depends_on: vec![],
+        timeout_ms: Some(100),
+        cache_ttl_secs: None,
+        optional,
+        provides: PluginProvides::Value,
+        result: PluginRunStatus::NotRun,
+        repository_dir: Default::default(),
+      }
+    }
+
     fn create_template_variable(variable_name: &str, description: &str, prompt: &str) -> TemplateVariable {
 
       TemplateVariable {
@@ -104,7 +370,37 @@ mod tests {
 
       // By using a PanicingPluginRunner we prove that it is not used
       let plugin_runner = PanicingPluginRunner;
-      let result = PluginRunnerWorkflow::run_plugins(plugin_runner, &mut template_variables);
+      let result = PluginRunnerWorkflow::run_plugins(plugin_runner, &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
+
+      assert_eq!(result, Ok(()));
+      assert_eq!(template_variables, expected_template_variables)
+    }
+
+    #[test]
+    fn should_not_run_plugins_with_dependencies() {
+      let mut template_variables = create_template_variables(2);
+
+      let variable: &mut TemplateVariable = template_variables.tokens.get_mut(1).unwrap();
+
+      variable.plugin =
+        Some(
+          Plugin {
+            id: "My Plugin".to_owned(),
+            args: Default::default(),
+            depends_on: vec!["variable_name-0".to_owned()],
+            timeout_ms: None,
+            cache_ttl_secs: None,
+            optional: false,
+            provides: PluginProvides::Value,
+            result: PluginRunStatus::NotRun,
+            repository_dir: Default::default(),
+          }
+        );
+
+      let expected_template_variables = template_variables.clone();
+
+      // By using a PanicingPluginRunner we prove that it is not used
+      let result = PluginRunnerWorkflow::run_plugins(PanicingPluginRunner, &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
 
       assert_eq!(result, Ok(()));
       assert_eq!(template_variables, expected_template_variables)
@@ -121,7 +417,13 @@ mod tests {
         Plugin {
           id: "My Plugin".to_owned(),
           args: Default::default(),
+          depends_on: vec![],
+          timeout_ms: None,
+          cache_ttl_secs: None,
+          optional: false,
+          provides: PluginProvides::Value,
           result: PluginRunStatus::NotRun,
+          repository_dir: Default::default(),
         };
 
       variable.plugin = Some(plugin.clone());
@@ -140,7 +442,7 @@ mod tests {
 
 
       let plugin_runner = SuccessfulPluginRunner("some result".to_owned());
-      let result = PluginRunnerWorkflow::run_plugins(plugin_runner, &mut template_variables);
+      let result = PluginRunnerWorkflow::run_plugins(plugin_runner, &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
 
       assert_eq!(result, Ok(()));
       assert_eq!(template_variables, expected_template_variables)
@@ -156,7 +458,13 @@ mod tests {
         Plugin {
           id: "My Plugin".to_owned(),
           args: Default::default(),
+          depends_on: vec![],
+          timeout_ms: None,
+          cache_ttl_secs: None,
+          optional: false,
+          provides: PluginProvides::Value,
           result: PluginRunStatus::NotRun,
+          repository_dir: Default::default(),
         };
 
       variable.plugin = Some(plugin);
@@ -171,7 +479,7 @@ mod tests {
           "some fix".to_owned()
           );
 
-      let result = PluginRunnerWorkflow::run_plugins(plugin_runner, &mut template_variables);
+      let result = PluginRunnerWorkflow::run_plugins(plugin_runner, &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
 
       let assert_error = |error: ErrorFormat| {
 
@@ -196,5 +504,197 @@ mod tests {
       );
       assert_eq!(template_variables, expected_template_variables)
     }
-}
 
+    #[test]
+    fn should_not_run_an_optional_plugin_that_times_out() {
+      let mut plugin = create_timing_out_plugin(true);
+
+      let result = PluginRunnerWorkflow::run_plugin(&TimingOutPluginRunner, "variable_name-0", &mut plugin, &PluginContext::default(), &PluginCache::disabled());
+
+      assert_eq!(result, Ok(PluginOutputs::new()));
+      assert_eq!(plugin.result, PluginRunStatus::NotRun)
+    }
+
+    #[test]
+    fn should_fail_when_a_required_plugin_times_out() {
+      let mut plugin = create_timing_out_plugin(false);
+
+      let result = PluginRunnerWorkflow::run_plugin(&TimingOutPluginRunner, "variable_name-0", &mut plugin, &PluginContext::default(), &PluginCache::disabled());
+
+      assert_eq!(
+        result,
+        Err(
+          ZatError::PluginError(
+            PluginErrorReason::PluginTimedOut(
+              "My Plugin".to_owned(),
+              "The plugin did not finish within its timeout of 100ms and was stopped after 100ms.".to_owned(),
+              "Try running the plugin manually to see why it does not finish. Increase its 'timeout_ms' if it needs longer, or mark it as 'optional' to use the variable's default value instead.".to_owned()
+            )
+          )
+        )
+      )
+    }
+
+    #[test]
+    fn should_set_the_choices_returned_by_a_choice_plugin() {
+      let mut template_variables = create_choice_plugin_variables();
+      let choices = vec![Choice::new("Scala 3", "The latest Scala 3", "3.3.1"), Choice::new("Scala 2", "The latest Scala 2", "2.13.12")];
+
+      let result = PluginRunnerWorkflow::run_choice_plugins(ChoicePluginRunner(choices.clone()), &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
+
+      assert_eq!(result, Ok(()));
+      assert_eq!(template_variables.tokens[0].choices, choices)
+    }
+
+    #[test]
+    fn should_only_run_choice_plugins_before_choices() {
+      let mut template_variables = create_template_variables(1);
+      template_variables.tokens[0].plugin = Some(Plugin::new("My Plugin", &[]));
+
+      let expected_template_variables = template_variables.clone();
+
+      // By using a PanicingPluginRunner we prove that it is not used
+      let result = PluginRunnerWorkflow::run_choice_plugins(PanicingPluginRunner, &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
+
+      assert_eq!(result, Ok(()));
+      assert_eq!(template_variables, expected_template_variables)
+    }
+
+    #[test]
+    fn should_fail_when_a_choice_plugin_returns_duplicate_values() {
+      let mut template_variables = create_choice_plugin_variables();
+      let choices = vec![Choice::new("Scala 3", "The latest Scala 3", "3.3.1"), Choice::new("Scala 3 LTS", "The Scala 3 LTS", "3.3.1")];
+
+      let result = PluginRunnerWorkflow::run_choice_plugins(ChoicePluginRunner(choices), &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
+
+      assert_eq!(
+        result,
+        Err(
+          ZatError::invalid_plugin_choices(
+            "scala-versions",
+            "variable_name-0",
+            "The plugin returned more than one choice with the value '3.3.1'."
+          )
+        )
+      );
+      assert!(template_variables.tokens[0].choices.is_empty())
+    }
+
+    #[test]
+    fn should_fail_when_a_choice_plugin_returns_no_choices() {
+      let mut template_variables = create_choice_plugin_variables();
+
+      let result = PluginRunnerWorkflow::run_choice_plugins(ChoicePluginRunner(vec![]), &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
+
+      assert_eq!(result, Err(ZatError::invalid_plugin_choices("scala-versions", "variable_name-0", "The plugin did not return any choices.")))
+    }
+
+    #[test]
+    fn should_fail_when_a_value_plugin_returns_choices() {
+      let mut plugin = Plugin::new("My Plugin", &[]);
+
+      let result = PluginRunnerWorkflow::run_plugin(&ChoicePluginRunner(vec![Choice::new("Scala 3", "The latest Scala 3", "3.3.1")]), "variable_name-0", &mut plugin, &PluginContext::default(), &PluginCache::disabled());
+
+      assert_eq!(result, Err(ZatError::plugin_returned_choices_instead_of_result("My Plugin")))
+    }
+
+    #[test]
+    fn should_fill_other_variables_with_plugin_outputs() {
+      let mut template_variables = create_template_variables(3);
+      template_variables.tokens[0].plugin = Some(Plugin::new("scala-deps", &[]));
+
+      let mut expected_template_variables = template_variables.clone();
+      expected_template_variables.tokens[0].plugin = Some(Plugin { result: PluginRunStatus::Run(PluginRunResult::new("Scala 3")), ..Plugin::new("scala-deps", &[]) });
+      expected_template_variables.tokens[2].default_value = Some("sbt 1.9".to_owned());
+
+      let result = PluginRunnerWorkflow::run_plugins(OutputsPluginRunner, &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
+
+      assert_eq!(result, Ok(()));
+      assert_eq!(template_variables, expected_template_variables)
+    }
+
+    #[test]
+    fn should_replace_user_defaults_with_plugin_outputs() {
+      let variable_defaults = BTreeMap::from([("variable_name-1".to_owned(), "user default".to_owned()), ("variable_name-2".to_owned(), "sbt 1.8".to_owned())]);
+      let mut template_variables = create_template_variables(3).with_user_defaults(&variable_defaults);
+      template_variables.tokens[0].plugin = Some(Plugin::new("scala-deps", &[]));
+
+      let result = PluginRunnerWorkflow::run_plugins(OutputsPluginRunner, &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
+
+      assert_eq!(result, Ok(()));
+      assert_eq!(template_variables.tokens[1].default_value, Some("user default".to_owned()));
+      assert_eq!(template_variables.tokens[2].default_value, Some("sbt 1.9".to_owned()))
+    }
+
+    #[test]
+    fn should_run_independent_plugins_in_parallel() {
+      let mut template_variables = create_plugin_variables(6);
+      let max_running = AtomicUsize::new(0);
+      let plugin_runner = ConcurrencyTrackingPluginRunner { running: &AtomicUsize::new(0), max_running: &max_running };
+
+      let result = PluginRunnerWorkflow::run_plugins(plugin_runner, &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
+
+      let results: Vec<PluginRunStatus> =
+        template_variables
+          .tokens
+          .into_iter()
+          .filter_map(|variable| variable.plugin.map(|plugin| plugin.result))
+          .collect();
+
+      let expected_results: Vec<PluginRunStatus> =
+        (0..6)
+          .map(|index| PluginRunStatus::Run(PluginRunResult::new(&s!("plugin-{}", index))))
+          .collect();
+
+      let max_running = max_running.load(Ordering::SeqCst);
+
+      assert_eq!(result, Ok(()));
+      assert_eq!(results, expected_results);
+      assert!(max_running > 1 && max_running <= PluginRunnerWorkflow::MAX_PARALLEL_PLUGINS, "max running plugins: {}", max_running)
+    }
+
+    #[test]
+    fn should_report_the_failure_of_the_first_plugin_defined() {
+      let mut template_variables = create_plugin_variables(2);
+
+      let result = PluginRunnerWorkflow::run_plugins(SlowerFirstFailingPluginRunner, &mut template_variables, &PluginContext::default(), &PluginCache::disabled());
+
+      assert_eq!(result, Err(ZatError::plugin_returned_error("plugin-0", "some error", "<No Exception>", "some fix")))
+    }
+
+    #[test]
+    fn should_use_the_cached_result_of_a_plugin() {
+      let config_dir = tempfile::TempDir::new().unwrap();
+      let plugin_cache = PluginCache::from(&crate::config::ZatConfigDir::from(config_dir.path()));
+      let mut plugin = Plugin { cache_ttl_secs: Some(60), ..Plugin::new("scala-deps", &["-s", "3"]) };
+      plugin_cache.put(&PluginRunnerWorkflow::cache_key(&plugin, &PluginContext::default()), &PluginSuccess::Value { result: "3.3.1".to_owned() }, std::time::Duration::from_secs(60));
+
+      // By using a PanicingPluginRunner we prove that it is not used
+      let result = PluginRunnerWorkflow::run_plugin(&PanicingPluginRunner, "variable_name-0", &mut plugin, &PluginContext::default(), &plugin_cache);
+
+      assert_eq!(result, Ok(PluginOutputs::new()));
+      assert_eq!(plugin.result, PluginRunStatus::Run(PluginRunResult::new("3.3.1")))
+    }
+
+    #[test]
+    fn should_cache_results_by_plugin_id_and_answers() {
+      let plugin = Plugin { cache_ttl_secs: Some(60), ..Plugin::new("scala-deps", &["-s", "3"]) };
+      let cache_key = PluginRunnerWorkflow::cache_key(&plugin, &PluginContext::default());
+
+      let checked_out_plugin = Plugin { repository_dir: crate::config::RepositoryDir::new("/tmp/zat-checkout"), ..plugin.clone() };
+      let mut other_directories = PluginContext::default();
+      other_directories.target_dir = "other-target".to_owned();
+      other_directories.repository_dir = "/tmp/zat-checkout".to_owned();
+
+      let mut other_answers = PluginContext::default();
+      other_answers.variables.insert("project".to_owned(), "my-app".to_owned());
+
+      let mut other_choices = PluginContext::default();
+      other_choices.choices.insert("scala".to_owned(), "2".to_owned());
+
+      assert!(cache_key.starts_with("scala-deps | scala-deps -s 3 | "));
+      assert_eq!(PluginRunnerWorkflow::cache_key(&checked_out_plugin, &other_directories), cache_key);
+      assert_ne!(PluginRunnerWorkflow::cache_key(&plugin, &other_answers), cache_key);
+      assert_ne!(PluginRunnerWorkflow::cache_key(&plugin, &other_choices), cache_key)
+    }
+}
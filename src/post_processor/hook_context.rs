@@ -0,0 +1,26 @@
+use std::collections::HashMap;
+
+use crate::config::UserConfig;
+use crate::plugin::PluginContext;
+use crate::templates::{TemplateVariables, UserChoiceKey, UserChoiceValue, UserVariableKey, UserVariableValue};
+
+/// What hooks know about the template being processed.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HookContext {
+  /// The selected choices, which decide the hooks that are in scope.
+  pub choices: HashMap<UserChoiceKey, UserChoiceValue>,
+
+  /// The `ZAT_*` environment variables hooks are run with. These are named like those of plugins.
+  pub env_vars: Vec<(String, String)>,
+}
+
+impl HookContext {
+  pub fn new(user_config: &UserConfig, template_variables: &TemplateVariables, user_variables: &HashMap<UserVariableKey, UserVariableValue>, user_choices: &HashMap<UserChoiceKey, UserChoiceValue>) -> Self {
+    let env_vars = PluginContext::new(user_config, template_variables, user_variables, user_choices).template_env_vars();
+
+    Self {
+      choices: user_choices.clone(),
+      env_vars
+    }
+  }
+}
@@ -0,0 +1,169 @@
+use std::fs;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::format as s;
+
+use crate::logging::{Logger, Printer};
+
+/// The file in the target directory the output of a failed hook is written to.
+pub const HOOK_LOG_FILE: &str = "zat-hook-failure.log";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HookStream {
+  Stdout,
+  Stderr,
+}
+
+/// The lines a hook wrote to stdout and stderr, in the order they were read.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HookOutput {
+  lines: Vec<(HookStream, String)>
+}
+
+impl HookOutput {
+
+  /// The number of lines of stderr to include in the error of a failed hook.
+  pub const STDERR_TAIL_LINES: usize = 10;
+
+  pub fn push(&mut self, stream: HookStream, line: String) {
+    self.lines.push((stream, line))
+  }
+
+  /// The last lines written to stderr, or None if nothing was written to stderr.
+  pub fn stderr_tail(&self) -> Option<String> {
+    let stderr: Vec<&str> =
+      self
+        .lines
+        .iter()
+        .filter(|(stream, _)| *stream == HookStream::Stderr)
+        .map(|(_, line)| line.as_str())
+        .collect();
+
+    let tail = &stderr[stderr.len().saturating_sub(Self::STDERR_TAIL_LINES)..];
+
+    if tail.is_empty() {
+      None
+    } else {
+      Some(tail.join("\n"))
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.lines.is_empty()
+  }
+
+  /// Each line, as it was written.
+  pub fn lines(&self) -> Vec<String> {
+    self.lines.iter().map(|(_, line)| line.clone()).collect()
+  }
+
+  /// Each line, prefixed with the stream it was written to.
+  pub fn log_lines(&self) -> Vec<String> {
+    self
+      .lines
+      .iter()
+      .map(|(stream, line)| {
+        match stream {
+          HookStream::Stdout => s!("[stdout] {}", line),
+          HookStream::Stderr => s!("[stderr] {}", line),
+        }
+      })
+      .collect()
+  }
+
+  /// Writes the output of a failed hook to HOOK_LOG_FILE in the target directory, returning the path of the log file.
+  /// If the target directory has not been created yet, or the log file can't be written, the output is printed instead.
+  pub fn write_log(&self, target_dir: &Path, command_line: &str) -> Option<PathBuf> {
+    let log_file = target_dir.join(HOOK_LOG_FILE);
+    let content = s!("$ {}\n{}\n", command_line, self.log_lines().join("\n"));
+
+    let written =
+      if target_dir.is_dir() {
+        fs::write(&log_file, content)
+          .map_err(|e| Logger::warn(&s!("Could not write the output of the shell hook to '{}', reason: {}", log_file.to_string_lossy(), e)))
+          .is_ok()
+      } else {
+        false
+      };
+
+    if written {
+      Some(log_file)
+    } else {
+      Printer::print_verbose_strings(&s!("Output of {}", command_line), self.log_lines());
+      None
+    }
+  }
+
+  /// Reads the lines of a stream of a hook into the output until the stream is closed, printing each line as it is read
+  /// when `stream_lines` is set.
+  pub fn read_in_background(output: &Arc<Mutex<HookOutput>>, reader: impl Read + Send + 'static, stream: HookStream, stream_lines: bool) -> JoinHandle<()> {
+    let output = Arc::clone(output);
+
+    thread::spawn(move || {
+      for line in BufReader::new(reader).split(b'\n') {
+        let Ok(line) = line else { break };
+        let line = String::from_utf8_lossy(&line).trim_end_matches('\r').to_owned();
+
+        if stream_lines {
+          Printer::content_only(&line)
+        }
+
+        output.lock().unwrap_or_else(|e| e.into_inner()).push(stream, line)
+      }
+    })
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+  use tempfile::TempDir;
+
+  fn output(lines: &[(HookStream, &str)]) -> HookOutput {
+    let mut output = HookOutput::default();
+    for (stream, line) in lines {
+      output.push(*stream, (*line).to_owned())
+    }
+
+    output
+  }
+
+  #[test]
+  fn stderr_tail_has_the_last_lines_of_stderr() {
+    let mut hook_output = output(&[(HookStream::Stdout, "checking cargo")]);
+    assert_eq!(hook_output.stderr_tail(), None);
+
+    for line in 1 ..= 12 {
+      hook_output.push(HookStream::Stderr, s!("error {}", line))
+    }
+
+    let expected: Vec<String> = (3 ..= 12).map(|line| s!("error {}", line)).collect();
+    assert_eq!(hook_output.stderr_tail(), Some(expected.join("\n")))
+  }
+
+  #[test]
+  fn writes_the_log_to_the_target_dir() {
+    let target_dir = TempDir::new().unwrap();
+    let hook_output = output(&[(HookStream::Stdout, "checking cargo"), (HookStream::Stderr, "cargo was not found")]);
+
+    let log_file = hook_output.write_log(target_dir.path(), "check-cargo.sh /target");
+
+    assert_eq!(log_file, Some(target_dir.path().join(HOOK_LOG_FILE)));
+    assert_eq!(
+      fs::read_to_string(target_dir.path().join(HOOK_LOG_FILE)).unwrap(),
+      "$ check-cargo.sh /target\n[stdout] checking cargo\n[stderr] cargo was not found\n"
+    )
+  }
+
+  #[test]
+  fn does_not_write_the_log_if_the_target_dir_does_not_exist() {
+    let target_dir = TempDir::new().unwrap();
+    let hook_output = output(&[(HookStream::Stderr, "cargo was not found")]);
+
+    assert_eq!(hook_output.write_log(&target_dir.path().join("not-created"), "check-cargo.sh"), None)
+  }
+}
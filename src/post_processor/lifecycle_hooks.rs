@@ -0,0 +1,98 @@
+use crate::choice::DefaultChoiceScopeFilter;
+use crate::config::{HookPhase, UserConfig};
+use crate::error::ZatAction;
+use crate::logging::Logger;
+
+use super::{HookContext, PostProcessingHook};
+use super::shell_hook::run_shell_hook;
+use std::format as s;
+
+/// Runs the lifecycle hooks of a phase, listed in the manifest of the repository. Hooks with scopes are only run when
+/// their scopes match the selected choices.
+pub struct LifecycleHooks(pub HookPhase);
+
+impl PostProcessingHook for LifecycleHooks {
+
+  fn run(&self, user_config: &UserConfig, hook_context: &HookContext) -> ZatAction {
+    let LifecycleHooks(phase) = self;
+
+    let hooks =
+      user_config
+        .hooks
+        .phase(*phase)
+        .iter()
+        .filter(|hook| DefaultChoiceScopeFilter::is_in_scope(&hook_context.choices, &hook.scopes));
+
+    for hook in hooks {
+      if user_config.no_hooks {
+        Logger::info(&s!("Skipping {} hook: {}", phase, hook.command_string()))
+      } else {
+        Logger::info(&s!("Running {} hook: {}", phase, hook.command_string()));
+        run_shell_hook(&hook.resolve_command(&user_config.repository_dir), &hook.args, None, user_config, hook_context)?
+      }
+    }
+
+    Ok(())
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::args::test_util::create_file_in;
+  use crate::config::{LifecycleHook, RepositoryHooks};
+  use crate::templates::{Choice, Scope, UserChoiceKey, UserChoiceValue};
+  use pretty_assertions::assert_eq;
+  use std::collections::HashMap;
+  use std::fs;
+  use tempfile::TempDir;
+
+  /// A post-generation hook that writes the ZAT_CHOICE_VCS environment variable to a file named after its argument.
+  fn config_with_hook(repository_dir: &TempDir, target_dir: &TempDir, scopes: Option<Vec<Scope>>) -> UserConfig {
+    create_file_in(repository_dir.path(), "write-choice.sh", b"#!/bin/bash\necho \"$ZAT_CHOICE_VCS\" > \"$ZAT_TARGET_DIR/$1\"", Some(0o755));
+
+    UserConfig {
+      hooks: RepositoryHooks {
+        pre_generation: vec![],
+        post_generation: vec![LifecycleHook { command: "write-choice.sh".to_owned(), args: vec!["vcs.txt".to_owned()], scopes }],
+      },
+      ..UserConfig::new(&repository_dir.path().to_string_lossy(), &target_dir.path().to_string_lossy())
+    }
+  }
+
+  fn hook_context(user_config: &UserConfig, vcs: &str) -> HookContext {
+    let choices = HashMap::from([(UserChoiceKey::new("vcs".to_owned()), UserChoiceValue::new(Choice::new(vcs, vcs, vcs)))]);
+    HookContext::new(user_config, &Default::default(), &HashMap::new(), &choices)
+  }
+
+  #[test]
+  fn runs_hooks_with_the_choices_of_the_template() {
+    let repository_dir = TempDir::new().unwrap();
+    let target_dir = TempDir::new().unwrap();
+    let user_config = config_with_hook(&repository_dir, &target_dir, None);
+
+    assert_eq!(LifecycleHooks(HookPhase::PostGeneration).run(&user_config, &hook_context(&user_config, "git")), Ok(()));
+    assert_eq!(fs::read_to_string(target_dir.path().join("vcs.txt")).unwrap(), "git\n")
+  }
+
+  #[test]
+  fn only_runs_hooks_in_scope() {
+    let repository_dir = TempDir::new().unwrap();
+    let target_dir = TempDir::new().unwrap();
+    let user_config = config_with_hook(&repository_dir, &target_dir, Some(vec![Scope::new_include_choice_value("vcs", "git")]));
+
+    assert_eq!(LifecycleHooks(HookPhase::PostGeneration).run(&user_config, &hook_context(&user_config, "none")), Ok(()));
+    assert!(!target_dir.path().join("vcs.txt").exists())
+  }
+
+  #[test]
+  fn only_runs_hooks_of_the_phase() {
+    let repository_dir = TempDir::new().unwrap();
+    let target_dir = TempDir::new().unwrap();
+    let user_config = config_with_hook(&repository_dir, &target_dir, None);
+
+    assert_eq!(LifecycleHooks(HookPhase::PreGeneration).run(&user_config, &hook_context(&user_config, "git")), Ok(()));
+    assert!(!target_dir.path().join("vcs.txt").exists())
+  }
+}
@@ -1,6 +1,14 @@
 pub mod post_processing_hook;
 pub mod shell_hook;
+pub mod hook_context;
+pub mod hook_output;
+pub mod lifecycle_hooks;
+pub mod post_processing_actions;
 
 
 pub use post_processing_hook::PostProcessingHook;
 pub use shell_hook::ShellHook;
+pub use hook_context::HookContext;
+pub use hook_output::{HookOutput, HookStream};
+pub use lifecycle_hooks::LifecycleHooks;
+pub use post_processing_actions::PostProcessingActions;
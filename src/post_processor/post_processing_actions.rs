@@ -0,0 +1,236 @@
+use crate::choice::DefaultChoiceScopeFilter;
+use crate::config::{ActionKind, PostProcessingAction, UserConfig};
+use crate::error::{ZatAction, ZatError, ZatResult};
+use crate::logging::Logger;
+
+use super::{HookContext, PostProcessingHook};
+use super::shell_hook::run_shell_hook;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::format as s;
+
+/// Runs the built-in actions listed in the manifest of the repository on the target directory, in the order they are
+/// listed. Actions with scopes are only run when their scopes match the selected choices.
+pub struct PostProcessingActions;
+
+impl PostProcessingHook for PostProcessingActions {
+
+  fn run(&self, user_config: &UserConfig, hook_context: &HookContext) -> ZatAction {
+    let actions =
+      user_config
+        .actions
+        .iter()
+        .filter(|action| DefaultChoiceScopeFilter::is_in_scope(&hook_context.choices, &action.scopes));
+
+    for action in actions {
+      // Only actions that run a command are skipped, as the others just change the files that were written
+      if user_config.no_hooks && action.command_string().is_some() {
+        Logger::info(&s!("Skipping post-processing action: {}", action))
+      } else {
+        Logger::info(&s!("Running post-processing action: {}", action));
+        Self::run_action(action, user_config, hook_context)?
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl PostProcessingActions {
+
+  fn run_action(action: &PostProcessingAction, user_config: &UserConfig, hook_context: &HookContext) -> ZatAction {
+    let target_dir = user_config.target_dir.as_ref();
+    let action_name = action.to_string();
+    let failed = |e: std::io::Error| ZatError::post_processing_action_failed(&action_name, e.to_string());
+
+    match &action.kind {
+      ActionKind::GitInit => run_shell_hook("git", &["init".to_owned()], Some(target_dir), user_config, hook_context),
+
+      ActionKind::Chmod { paths, mode } => {
+        let mode = u32::from_str_radix(mode, 8).map_err(|_| ZatError::post_processing_action_has_invalid_mode(&action_name, mode))?;
+
+        for path in paths {
+          Self::set_mode(&Self::target_path(target_dir, &action_name, path)?, mode).map_err(failed)?
+        }
+
+        Ok(())
+      },
+
+      ActionKind::Move { from, to } => {
+        let from = Self::target_path(target_dir, &action_name, from)?;
+        let to = Self::target_path(target_dir, &action_name, to)?;
+
+        if let Some(parent) = to.parent() {
+          fs::create_dir_all(parent).map_err(failed)?
+        }
+
+        fs::rename(from, to).map_err(failed)
+      },
+
+      ActionKind::Delete { path } => {
+        let path = Self::target_path(target_dir, &action_name, path)?;
+
+        if path.is_dir() {
+          fs::remove_dir_all(path).map_err(failed)
+        } else if path.exists() {
+          fs::remove_file(path).map_err(failed)
+        } else {
+          Logger::warn(&s!("Nothing to delete for post-processing action '{}', as '{}' does not exist.", action_name, path.to_string_lossy()));
+          Ok(())
+        }
+      },
+
+      ActionKind::Run { command, args, working_dir } => {
+        let working_dir =
+          match working_dir {
+            Some(working_dir) => Self::target_path(target_dir, &action_name, working_dir)?,
+            None => target_dir.to_path_buf(),
+          };
+
+        run_shell_hook(command, args, Some(&working_dir), user_config, hook_context)
+      },
+    }
+  }
+
+  /// The path within the target directory. Absolute paths and paths with '..' are not allowed, so that an action can
+  /// only change the files it generated.
+  fn target_path(target_dir: &Path, action_name: &str, path: &str) -> ZatResult<PathBuf> {
+    let is_within_target_dir =
+      Path::new(path)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+
+    if is_within_target_dir {
+      Ok(target_dir.join(path))
+    } else {
+      Err(ZatError::post_processing_action_path_is_outside_target_dir(action_name, path))
+    }
+  }
+
+  #[cfg(unix)]
+  fn set_mode(path: &Path, mode: u32) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))
+  }
+
+  #[cfg(not(unix))]
+  fn set_mode(path: &Path, _mode: u32) -> std::io::Result<()> {
+    Logger::warn(&s!("Permissions can only be set on Unix, so the permissions of '{}' were not changed.", path.to_string_lossy()));
+    Ok(())
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::args::test_util::create_file_in;
+  use crate::templates::{Choice, Scope, UserChoiceKey, UserChoiceValue};
+  use crate::error::ProcessCommandErrorReason;
+  use crate::error::post_processing_error_reason::PostProcessingErrorReason;
+  use pretty_assertions::assert_eq;
+  use std::collections::HashMap;
+  use tempfile::TempDir;
+
+  fn config_with_actions(target_dir: &TempDir, actions: Vec<PostProcessingAction>) -> UserConfig {
+    UserConfig {
+      actions,
+      ..UserConfig::new("", &target_dir.path().to_string_lossy())
+    }
+  }
+
+  fn hook_context(readme_type: &str) -> HookContext {
+    HookContext {
+      choices: HashMap::from([(UserChoiceKey::new("readme_type".to_owned()), UserChoiceValue::new(Choice::new(readme_type, readme_type, readme_type)))]),
+      env_vars: vec![],
+    }
+  }
+
+  #[test]
+  fn moves_and_deletes_files_in_scope() {
+    let target_dir = TempDir::new().unwrap();
+    create_file_in(target_dir.path(), "README-long.md", b"long", None);
+    create_file_in(target_dir.path(), "README-short.md", b"short", None);
+    fs::create_dir(target_dir.path().join("src")).unwrap();
+    create_file_in(target_dir.path(), "src/.gitkeep", b"", None);
+
+    let move_readme =
+      |readme_type: &str| PostProcessingAction {
+        kind: ActionKind::Move { from: s!("README-{}.md", readme_type), to: "docs/README.md".to_owned() },
+        scopes: Some(vec![Scope::new_include_choice_value("readme_type", readme_type)])
+      };
+
+    let user_config =
+      config_with_actions(
+        &target_dir,
+        vec![
+          move_readme("long"),
+          move_readme("short"),
+          PostProcessingAction::new(ActionKind::Delete { path: "src/.gitkeep".to_owned() }),
+        ]
+      );
+
+    assert_eq!(PostProcessingActions.run(&user_config, &hook_context("long")), Ok(()));
+    assert_eq!(fs::read_to_string(target_dir.path().join("docs/README.md")).unwrap(), "long");
+    assert!(target_dir.path().join("README-short.md").exists());
+    assert!(!target_dir.path().join("src/.gitkeep").exists())
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn sets_the_mode_of_files() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let target_dir = TempDir::new().unwrap();
+    fs::create_dir(target_dir.path().join("bin")).unwrap();
+    create_file_in(target_dir.path(), "bin/run.sh", b"#!/bin/bash", Some(0o644));
+
+    let user_config = config_with_actions(&target_dir, vec![PostProcessingAction::new(ActionKind::Chmod { paths: vec!["bin/run.sh".to_owned()], mode: "755".to_owned() })]);
+
+    assert_eq!(PostProcessingActions.run(&user_config, &hook_context("long")), Ok(()));
+    assert_eq!(fs::metadata(target_dir.path().join("bin/run.sh")).unwrap().permissions().mode() & 0o777, 0o755)
+  }
+
+  #[test]
+  fn runs_commands_in_their_working_dir() {
+    let target_dir = TempDir::new().unwrap();
+    fs::create_dir(target_dir.path().join("app")).unwrap();
+
+    let user_config =
+      config_with_actions(
+        &target_dir,
+        vec![PostProcessingAction::new(ActionKind::Run { command: "touch".to_owned(), args: vec!["ran.txt".to_owned()], working_dir: Some("app".to_owned()) })]
+      );
+
+    assert_eq!(PostProcessingActions.run(&user_config, &hook_context("long")), Ok(()));
+    assert!(target_dir.path().join("app/ran.txt").exists())
+  }
+
+  #[test]
+  fn skips_commands_without_hooks() {
+    let target_dir = TempDir::new().unwrap();
+
+    let user_config =
+      UserConfig {
+        no_hooks: true,
+        ..config_with_actions(&target_dir, vec![PostProcessingAction::new(ActionKind::Run { command: "touch".to_owned(), args: vec!["ran.txt".to_owned()], working_dir: None })])
+      };
+
+    assert_eq!(PostProcessingActions.run(&user_config, &hook_context("long")), Ok(()));
+    assert!(!target_dir.path().join("ran.txt").exists())
+  }
+
+  #[test]
+  fn fails_on_paths_outside_the_target_dir() {
+    let target_dir = TempDir::new().unwrap();
+    let user_config = config_with_actions(&target_dir, vec![PostProcessingAction::new(ActionKind::Delete { path: "../README.md".to_owned() })]);
+
+    assert!(
+      matches!(
+        PostProcessingActions.run(&user_config, &hook_context("long")),
+        Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::PostProcessingError(PostProcessingErrorReason::InvalidAction(..))))
+      )
+    )
+  }
+}
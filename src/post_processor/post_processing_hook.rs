@@ -1,6 +1,7 @@
 use crate::{config::UserConfig, error::ZatAction};
+use super::HookContext;
 
 
 pub trait PostProcessingHook {
-  fn run(&self, user_config: &UserConfig) -> ZatAction;
+  fn run(&self, user_config: &UserConfig, hook_context: &HookContext) -> ZatAction;
 }
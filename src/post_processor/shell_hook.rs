@@ -1,44 +1,138 @@
 use crate::config::{UserConfig, ConfigShellHookStatus, TargetDir};
 use crate::error::{ZatAction, ZatError};
-use crate::logging::{VerboseLogger, Logger};
+use crate::logging::{VerboseLogger, Logger, Printer};
 use crate::spath;
 
-use super::PostProcessingHook;
-use std::process::Command;
+use super::{HookContext, HookOutput, HookStream, PostProcessingHook};
+use std::process::{Child, Command, ExitStatus, Stdio};
 use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 use std::format as s;
 
 pub struct ShellHook;
 
 impl PostProcessingHook for ShellHook {
 
-  fn run(&self, user_config: &UserConfig) -> ZatAction {
+  fn run(&self, user_config: &UserConfig, hook_context: &HookContext) -> ZatAction {
     match &user_config.shell_hook_status {
       ConfigShellHookStatus::NoShellHook => Ok(()),
-      ConfigShellHookStatus::RunShellHook(shell_hook) => run_shell_hook(shell_hook, user_config)
+      ConfigShellHookStatus::RunShellHook(shell_hook) if user_config.no_hooks => {
+        Logger::info(&s!("Skipping shell hook: {}", shell_hook));
+        Ok(())
+      },
+      ConfigShellHookStatus::RunShellHook(shell_hook) => {
+        Logger::info("Executing Shellhook");
+        let target_dir_path = <TargetDir as AsRef<Path>>::as_ref(&user_config.target_dir);
+        run_shell_hook(shell_hook, &[spath!(target_dir_path).to_owned()], None, user_config, hook_context)
+      }
     }
   }
 }
 
-fn run_shell_hook(shell_hook: &str, user_config: &UserConfig) -> Result<(), ZatError> {
-    Logger::info("Executing Shellhook");
+/// How often a running hook is checked for having finished or timed out.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long to wait for the rest of the output of a hook once it has finished. Processes a hook starts in the background
+/// can keep its output open, so the output is not waited on forever.
+const OUTPUT_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+enum HookExit {
+  Exited(ExitStatus),
+  TimedOut(Duration),
+}
+
+/// Runs a hook with its arguments and the `ZAT_*` environment variables of the template being processed. The hook is run
+/// in the current directory unless a working directory is supplied.
+///
+/// The output of the hook is captured, so that it is not interleaved with the output of Zat. It is printed as it is
+/// written in verbose mode, and once the hook has finished otherwise. If the hook fails, the last lines of its stderr
+/// are included in the error and all of its output is written to a log file in the target directory.
+pub fn run_shell_hook(shell_hook: &str, args: &[String], working_dir: Option<&Path>, user_config: &UserConfig, hook_context: &HookContext) -> Result<(), ZatError> {
     VerboseLogger::log_content(user_config, &s!("Running shellhook: {}", shell_hook));
 
-    let target_dir_path = <TargetDir as AsRef<Path>>::as_ref(&user_config.target_dir);
-    Command::new(shell_hook)
-      .arg::<&Path>(target_dir_path)
-      .status()
-      .map_err(|e| ZatError::post_processing_hook_failed(shell_hook, e.to_string()))
-      .and_then(|exit|{
-        match exit.code() {
+    let mut command = Command::new(shell_hook);
+    if let Some(working_dir) = working_dir {
+      command.current_dir(working_dir);
+    }
+
+    let mut child =
+      command
+        .args(args)
+        .envs(hook_context.env_vars.iter().cloned())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| ZatError::post_processing_hook_failed(shell_hook, e.to_string()))?;
+
+    let output = Arc::new(Mutex::new(HookOutput::default()));
+
+    let readers: Vec<JoinHandle<()>> =
+      child.stdout.take().map(|stdout| HookOutput::read_in_background(&output, stdout, HookStream::Stdout, user_config.verbose))
+        .into_iter()
+        .chain(child.stderr.take().map(|stderr| HookOutput::read_in_background(&output, stderr, HookStream::Stderr, user_config.verbose)))
+        .collect();
+
+    let exit = wait_with_timeout(&mut child, user_config.hook_timeout).map_err(|e| ZatError::post_processing_hook_failed(shell_hook, e.to_string()))?;
+    wait_for_output(&readers);
+
+    let output = output.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    let arg = args.join(" ");
+    let command_line = if arg.is_empty() { shell_hook.to_owned() } else { s!("{} {}", shell_hook, arg) };
+
+    let log_output = || {
+      output
+        .write_log(user_config.target_dir.as_ref(), &command_line)
+        .map(|log_file| log_file.to_string_lossy().to_string())
+    };
+
+    match exit {
+      HookExit::Exited(status) => {
+        match status.code() {
           Some(0) => {
+            if !user_config.verbose && !output.is_empty() {
+              Printer::print_verbose_strings(&s!("Output of {}", command_line), output.lines())
+            }
+
             VerboseLogger::log_content(user_config, "Shell hook exited successfully");
             Ok(())
           },
-          Some(other) => Err(ZatError::post_processing_hook_completed_with_non_zero_status(shell_hook, spath!(target_dir_path), other)),
-          None => Err(ZatError::post_processing_hook_was_shutdown(shell_hook))
+          Some(other) => Err(ZatError::post_processing_hook_completed_with_non_zero_status(shell_hook, &arg, other, output.stderr_tail(), log_output().as_deref())),
+          None => Err(ZatError::post_processing_hook_was_shutdown(shell_hook, output.stderr_tail(), log_output().as_deref()))
         }
-      })
+      },
+      HookExit::TimedOut(timeout) => Err(ZatError::post_processing_hook_timed_out(shell_hook, timeout, output.stderr_tail(), log_output().as_deref())),
+    }
+}
+
+/// Waits for the hook to finish, stopping it if it runs for longer than the timeout.
+fn wait_with_timeout(child: &mut Child, timeout: Option<Duration>) -> std::io::Result<HookExit> {
+    let Some(timeout) = timeout else {
+      return child.wait().map(HookExit::Exited)
+    };
+
+    let started = Instant::now();
+
+    loop {
+      match child.try_wait()? {
+        Some(status) => return Ok(HookExit::Exited(status)),
+        None if started.elapsed() >= timeout => {
+          let _ = child.kill();
+          let _ = child.wait();
+          return Ok(HookExit::TimedOut(timeout))
+        },
+        None => thread::sleep(POLL_INTERVAL),
+      }
+    }
+}
+
+fn wait_for_output(readers: &[JoinHandle<()>]) {
+    let started = Instant::now();
+
+    while readers.iter().any(|reader| !reader.is_finished()) && started.elapsed() < OUTPUT_GRACE_PERIOD {
+      thread::sleep(POLL_INTERVAL)
+    }
 }
 
 #[cfg(test)]
@@ -49,11 +143,12 @@ mod tests {
     use crate::error::post_processing_error_reason::PostProcessingErrorReason;
     use std::println as p;
     use crate::error::ProcessCommandErrorReason;
+    use crate::post_processor::hook_output::HOOK_LOG_FILE;
 
     #[test]
     fn should_do_nothing_when_there_is_no_shell_hook() {
         let config = default_config();
-        assert_eq!(Ok(()), ShellHook.run(&config))
+        assert_eq!(Ok(()), ShellHook.run(&config, &HookContext::default()))
     }
 
     #[test]
@@ -64,7 +159,7 @@ mod tests {
           |error: String| assert!(error.ends_with("failed with an error."));
 
         assert_error_with!(
-          ShellHook.run(&config),
+          ShellHook.run(&config, &HookContext::default()),
           Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::PostProcessingError(PostProcessingErrorReason::ExecutionError(error, ..)))) => error,
           assert_error_ends_with
         )
@@ -98,7 +193,7 @@ mod tests {
         };
 
        assert_error_with!{
-          ShellHook.run(&config),
+          ShellHook.run(&config, &HookContext::default()),
           Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::PostProcessingError(PostProcessingErrorReason::ExecutionError(error, ..)))) => error,
           assert_error_ends_with
         }
@@ -126,12 +221,69 @@ mod tests {
 
         p!("config: {:?}", &config);
 
-        match ShellHook.run(&config) {
+        match ShellHook.run(&config, &HookContext::default()) {
           Ok(_) => assert!(target_dir.path().join("testing.txt").exists()),
           other => panic!("expected Ok(..) but got: {:?}", other)
         }
     }
 
+    #[test]
+    fn should_not_run_shell_hook_with_no_hooks() {
+        // The shell hook doesn't exist, so running it would fail
+        let config =
+          UserConfig {
+            no_hooks: true,
+            ..config_with_shell_hook(default_config())
+          };
+
+        assert_eq!(Ok(()), ShellHook.run(&config, &HookContext::default()))
+    }
+
+    #[test]
+    fn should_include_the_output_of_a_failed_shell_hook() {
+        let source_dir = tempfile::TempDir::new().unwrap();
+        let target_dir = tempfile::TempDir::new().unwrap();
+
+        let shell_hook_content = b"#!/bin/bash\necho \"checking cargo\"\necho \"cargo was not found\" >&2\nexit 2";
+        let _ = create_file_in(source_dir.path(), "some-script.sh", shell_hook_content, Some(0o755));
+
+        let config = config_with_shell_hook(config_with_source_and_target(source_dir.path(), target_dir.path()));
+        let log_file = target_dir.path().join(HOOK_LOG_FILE);
+
+        match ShellHook.run(&config, &HookContext::default()) {
+          Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::PostProcessingError(PostProcessingErrorReason::NonZeroStatusCode(_, stderr_tail, fix)))) => {
+            assert_eq!(stderr_tail, Some("cargo was not found".to_owned()));
+            assert!(fix.contains(spath!(log_file)), "Expected the fix to mention the log file: {}", fix);
+            assert!(std::fs::read_to_string(&log_file).unwrap().ends_with("[stdout] checking cargo\n[stderr] cargo was not found\n"))
+          },
+          other => panic!("expected a NonZeroStatusCode error but got: {:?}", other)
+        }
+    }
+
+    #[test]
+    fn should_stop_a_shell_hook_that_times_out() {
+        let source_dir = tempfile::TempDir::new().unwrap();
+        let target_dir = tempfile::TempDir::new().unwrap();
+
+        let _ = create_file_in(source_dir.path(), "some-script.sh", b"#!/bin/bash\nexec sleep 10", Some(0o755));
+
+        let config =
+          UserConfig {
+            hook_timeout: Some(Duration::from_millis(100)),
+            ..config_with_shell_hook(config_with_source_and_target(source_dir.path(), target_dir.path()))
+          };
+
+        let started = Instant::now();
+
+        assert!(
+          matches!(
+            ShellHook.run(&config, &HookContext::default()),
+            Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::PostProcessingError(PostProcessingErrorReason::TimedOut(..))))
+          )
+        );
+        assert!(started.elapsed() < Duration::from_secs(5))
+    }
+
     fn default_config() -> UserConfig {
         UserConfig::new("", "")
     }
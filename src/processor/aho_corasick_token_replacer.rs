@@ -1,5 +1,6 @@
 use crate::token_expander::key_tokenizer::TokenizedKeysExpandedVariables;
 use super::StringTokenReplacer;
+#[cfg(test)]
 use super::{ContentWithTokens, TokenReplacer, ContentTokensReplaced};
 
 use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
@@ -31,6 +32,7 @@ impl AhoCorasickTokenReplacer {
   }
 }
 
+#[cfg(test)]
 impl TokenReplacer for AhoCorasickTokenReplacer {
     // TODO: Deprecate this
     fn replace_content_token(&self, content_with_token: ContentWithTokens) -> ContentTokensReplaced {
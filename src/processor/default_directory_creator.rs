@@ -4,7 +4,7 @@ use crate::config::UserConfig;
 use crate::error::{ZatError, ZatResult};
 use crate::logging::VerboseLogger;
 use super::StringTokenReplacer;
-use std::{fs, format as s};
+use std::{fs, path::Path, format as s};
 
 pub struct DefaultDirectoryCreator<'a> {
   user_config: &'a UserConfig
@@ -24,6 +24,11 @@ impl DirectoryCreator for DefaultDirectoryCreator<'_> {
       let directory_path_with_tokens_replaced = destination_directory.map(|dd| replacer.replace(dd));
       VerboseLogger::log_content(self.user_config, &s!("Creating directory: {}", &directory_path_with_tokens_replaced));
 
+      // Directories may already exist when several repositories are processed into the same target directory
+      if Path::new(&directory_path_with_tokens_replaced.0).is_dir() {
+        return Ok(())
+      }
+
       fs::create_dir(&directory_path_with_tokens_replaced)
         .map_err(|e| {
           ZatError::could_not_create_output_file_directory(directory_path_with_tokens_replaced.0.as_str(), e.to_string())
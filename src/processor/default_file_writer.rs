@@ -1,10 +1,11 @@
 use super::FileWriter;
 use super::SourceFile;
 use super::DestinationFile;
-use crate::config::UserConfig;
+use super::MarkerInsertion;
+use crate::config::{CollisionPolicy, UserConfig};
 use crate::error::ZatAction;
 use crate::error::{ZatError, ZatResult};
-use crate::logging::VerboseLogger;
+use crate::logging::{Logger, VerboseLogger};
 use crate::templates::UserChoices;
 use super::StringTokenReplacer;
 use std::{fs, path::Path, fmt::Display, format as s};
@@ -43,11 +44,11 @@ impl FileWriter for DefaultFileWriter<'_> {
       let parent_dir = &target_file_name_tokens_applied.parent_directory();
       let full_target_file_path_templated = parent_dir.join(target_file_name_tokens_applied.file_stem());
       let content_with_tokens_applied = token_replacer.replace(&content);
-      Self::write_file(full_target_file_path_templated, content_with_tokens_applied)
+      self.write_file(full_target_file_path_templated, content_with_tokens_applied)
     } else {
       VerboseLogger::log_content(self.user_config, &s!("Copying file: {}", &target_file_name_tokens_applied));
       let content = source_file.read_binary()?;
-      Self::write_file(target_file_name_tokens_applied, content)
+      self.write_file(target_file_name_tokens_applied, content)
     }
   }
 }
@@ -84,13 +85,52 @@ impl <'a> DefaultFileWriter<'a> {
     Ok(())
   }
 
-  fn write_file<C, T>(target_file_with_tokens_replaced: T, content: C) -> ZatResult<()> where
+  fn write_file<C, T>(&self, target_file_with_tokens_replaced: T, content: C) -> ZatResult<()> where
     T: AsRef<Path> + Display,
     C: AsRef<[u8]>
   {
+    // Insert files add their content to an existing file, instead of being written as a new file
+    if let Some(insertion) = MarkerInsertion::from_path(target_file_with_tokens_replaced.as_ref()) {
+      return Self::insert_file(&insertion, &target_file_with_tokens_replaced.to_string(), content)
+    }
+
+    // Files may already exist when several repositories are processed into the same target directory
+    if target_file_with_tokens_replaced.as_ref().exists() {
+      match self.user_config.collision_policy {
+        CollisionPolicy::Fail => return Err(ZatError::output_file_already_exists(target_file_with_tokens_replaced.to_string().as_str())),
+        CollisionPolicy::Skip => {
+          Logger::warn(&s!("Skipping file '{}' as it already exists.", target_file_with_tokens_replaced));
+          return Ok(())
+        },
+        CollisionPolicy::Overwrite => Logger::warn(&s!("Overwriting file '{}' as it already exists.", target_file_with_tokens_replaced)),
+      }
+    }
+
     fs::write(&target_file_with_tokens_replaced, content)
       .map_err(|e| ZatError::could_not_write_output_file(target_file_with_tokens_replaced.to_string().as_str(), e.to_string()))
   }
+
+  fn insert_file<C>(insertion: &MarkerInsertion, insert_file: &str, content: C) -> ZatResult<()> where
+    C: AsRef<[u8]>
+  {
+    let target_file = insertion.target_file.to_string_lossy().to_string();
+
+    if !insertion.target_file.exists() {
+      return Err(ZatError::insertion_file_does_not_exist(&target_file, insert_file))
+    }
+
+    let existing_content =
+      fs::read_to_string(&insertion.target_file)
+        .map_err(|e| ZatError::could_not_write_output_file(&target_file, e.to_string()))?;
+
+    let updated_content =
+      insertion
+        .insert(&existing_content, &String::from_utf8_lossy(content.as_ref()))
+        .ok_or_else(|| ZatError::insertion_marker_not_found(&target_file, &insertion.marker))?;
+
+    fs::write(&insertion.target_file, updated_content)
+      .map_err(|e| ZatError::could_not_write_output_file(&target_file, e.to_string()))
+  }
 }
 
 
@@ -512,4 +552,32 @@ mod tests {
 
       assert_eq!(&expected_destination_content, &destination_content, "token replaced content should be equal to the destination content");
     }
+
+    fn write_over_existing_file(collision_policy: CollisionPolicy) -> (ZatResult<()>, String) {
+      let temp_source_file = NamedTempFile::new().unwrap();
+      let temp_destination_file = NamedTempFile::new().unwrap();
+
+      let source_file = SourceFile(temp_source_file.path().to_string_lossy().to_string());
+      let destination_file = DestinationFile(temp_destination_file.path().to_string_lossy().to_string());
+
+      fs::write(&source_file, "from the later template").unwrap();
+      fs::write(&destination_file, "from the earlier template").unwrap();
+
+      let user_config = UserConfig { collision_policy, ..Default::default() };
+      let user_choices = UserChoices::default();
+      let file_writer = DefaultFileWriter::new(&user_config, &user_choices);
+
+      let result = file_writer.write_source_to_destination(&source_file, &destination_file, &EchoingStringTokenReplacer);
+      (result, fs::read_to_string(&destination_file).unwrap())
+    }
+
+    #[test]
+    fn should_apply_the_collision_policy_to_existing_files() {
+      assert_eq!(write_over_existing_file(CollisionPolicy::Overwrite), (Ok(()), "from the later template".to_owned()));
+      assert_eq!(write_over_existing_file(CollisionPolicy::Skip), (Ok(()), "from the earlier template".to_owned()));
+
+      let (result, content) = write_over_existing_file(CollisionPolicy::Fail);
+      assert!(matches!(result, Err(ZatError::ProcessCommandError(crate::error::ProcessCommandErrorReason::TemplateProcessingError(crate::error::template_processing_error_reason::TemplateProcessingErrorReason::FileAlreadyExistsError(..))))));
+      assert_eq!(content, "from the earlier template")
+    }
 }
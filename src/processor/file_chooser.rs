@@ -5,6 +5,7 @@ use super::TemplateFile;
 pub trait FileChooser  {
   fn is_included(&self, file_type: TemplateFile) -> bool;
 
+  #[cfg(test)]
   fn is_ignored(&self, file_type: TemplateFile) -> bool {
     !self.is_included(file_type)
   }
@@ -0,0 +1,125 @@
+use std::path::{Path, PathBuf};
+use std::format as s;
+
+/// Files with this extension are inserted into an existing file at a marker, instead of being written as a new file.
+pub const INSERT_EXTENSION: &str = ".zat-insert";
+
+/// Markers are lines in the existing file that contain this text, usually within a comment. Eg. // zat-insert:routes
+const MARKER_PREFIX: &str = "zat-insert";
+
+/// Where to insert the content of an insert file.
+///
+/// `main.rs@routes.zat-insert` is inserted into `main.rs` before the line containing `zat-insert:routes`.
+/// `main.rs.zat-insert` is inserted into `main.rs` before the line containing `zat-insert`, without a name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkerInsertion {
+  pub target_file: PathBuf,
+  pub marker: String,
+}
+
+impl MarkerInsertion {
+
+  /// Returns the MarkerInsertion for an insert file, or None for a regular file.
+  pub fn from_path(path: &Path) -> Option<Self> {
+    let file_name = path.file_name()?.to_string_lossy().to_string();
+    let insertion = file_name.strip_suffix(INSERT_EXTENSION)?;
+
+    let (target_file_name, marker) =
+      match insertion.rsplit_once('@') {
+        Some((target_file_name, marker_name)) => (target_file_name, s!("{}:{}", MARKER_PREFIX, marker_name)),
+        None => (insertion.strip_suffix('.').unwrap_or(insertion), MARKER_PREFIX.to_owned()),
+      };
+
+    if target_file_name.is_empty() {
+      return None
+    }
+
+    Some(
+      MarkerInsertion {
+        target_file: path.with_file_name(target_file_name),
+        marker
+      }
+    )
+  }
+
+  /// Inserts the content before the first line with the marker, using the indentation of the marker line. The marker is
+  /// kept so that later insertions are added after this one. Returns None if the marker can't be found.
+  pub fn insert(&self, existing_content: &str, content: &str) -> Option<String> {
+    let lines: Vec<&str> = existing_content.split_inclusive('\n').collect();
+    let marker_index = lines.iter().position(|line| self.is_marker(line))?;
+    let marker_line = lines[marker_index];
+    let indentation: String = marker_line.chars().take_while(|c| c.is_whitespace() && *c != '\n').collect();
+
+    let inserted_lines: String =
+      content
+        .lines()
+        .map(|line| if line.is_empty() { "\n".to_owned() } else { s!("{}{}\n", indentation, line) })
+        .collect();
+
+    let before: String = lines[..marker_index].concat();
+    let after: String = lines[marker_index..].concat();
+
+    Some(s!("{}{}{}", before, inserted_lines, after))
+  }
+
+  fn is_marker(&self, line: &str) -> bool {
+    match line.find(&self.marker) {
+      Some(index) => {
+        // Unnamed markers should not match named markers
+        let rest = &line[index + self.marker.len()..];
+        !(self.marker == MARKER_PREFIX && rest.starts_with(':'))
+      },
+      None => false
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn finds_named_markers_from_the_file_name() {
+    assert_eq!(
+      MarkerInsertion::from_path(Path::new("/project/src/main.rs@routes.zat-insert")),
+      Some(MarkerInsertion { target_file: PathBuf::from("/project/src/main.rs"), marker: "zat-insert:routes".to_owned() })
+    )
+  }
+
+  #[test]
+  fn finds_unnamed_markers_from_the_file_name() {
+    assert_eq!(
+      MarkerInsertion::from_path(Path::new("/project/src/main.rs.zat-insert")),
+      Some(MarkerInsertion { target_file: PathBuf::from("/project/src/main.rs"), marker: "zat-insert".to_owned() })
+    )
+  }
+
+  #[test]
+  fn regular_files_are_not_insertions() {
+    assert_eq!(MarkerInsertion::from_path(Path::new("/project/src/main.rs")), None);
+    assert_eq!(MarkerInsertion::from_path(Path::new("/project/.zat-insert")), None)
+  }
+
+  #[test]
+  fn inserts_before_the_marker_with_its_indentation() {
+    let insertion = MarkerInsertion::from_path(Path::new("main.rs@routes.zat-insert")).unwrap();
+
+    let existing_content = "fn routes() {\n  // zat-insert\n  add(\"/\");\n  // zat-insert:routes\n}\n";
+    let content = "add(\"/users\");\nadd(\"/users/:id\");\n";
+
+    assert_eq!(
+      insertion.insert(existing_content, content),
+      Some("fn routes() {\n  // zat-insert\n  add(\"/\");\n  add(\"/users\");\n  add(\"/users/:id\");\n  // zat-insert:routes\n}\n".to_owned())
+    )
+  }
+
+  #[test]
+  fn unnamed_markers_do_not_match_named_markers() {
+    let insertion = MarkerInsertion::from_path(Path::new("mod.rs.zat-insert")).unwrap();
+
+    assert_eq!(insertion.insert("// zat-insert:routes\n", "mod users;"), None);
+    assert_eq!(insertion.insert("// zat-insert:routes\n// zat-insert\n", "mod users;"), Some("// zat-insert:routes\nmod users;\n// zat-insert\n".to_owned()))
+  }
+}
@@ -1,5 +1,6 @@
 // Public modules
 pub mod file_chooser;
+#[cfg(test)]
 pub mod token_replacer;
 pub mod template_enricher;
 pub mod process_templates;
@@ -20,9 +21,11 @@ mod default_directory_creator;
 mod source_file;
 mod destination_file;
 mod file_traverser;
+mod marker_insertion;
 
 // Public exports
 pub use file_chooser::FileChooser;
+#[cfg(test)]
 pub use token_replacer::{ContentTokensReplaced, ContentWithTokens, TokenReplacer};
 pub use template_enricher::TemplateEnricher;
 pub use process_templates::ProcessTemplates;
@@ -31,16 +34,17 @@ pub use enriched_template_file_processor::{EnrichedTemplateFile, EnrichedTemplat
 pub use file_writer::FileWriter;
 pub use directory_creator::DirectoryCreator;
 pub use string_token_replacer::StringTokenReplacer;
+pub use aho_corasick_token_replacer::AhoCorasickTokenReplacer;
 
 // Module-private exports
 use file_traverser::{FileTraverser, TemplateFile};
 use regex_file_chooser::RegExFileChooser;
 use default_template_enricher::DefaultTemplateEnricher;
 use walk_dir_file_traverser::WalkDirFileTraverser;
-use aho_corasick_token_replacer::AhoCorasickTokenReplacer;
 use enriched_default_template_file_processor::DefaultEnrichedTemplateFileProcessor;
 use source_file::SourceFile;
 use destination_file::DestinationFile;
+use marker_insertion::MarkerInsertion;
 
 #[cfg(test)]
 use string_token_replacer::{ReplacingStringTokenReplacer, EchoingStringTokenReplacer};
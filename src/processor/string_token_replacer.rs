@@ -2,28 +2,23 @@ pub trait StringTokenReplacer {
   fn replace(&self, input: &str) -> String;
 }
 
+/// Leaves tokens as they are, for when the values of variables are not known.
 pub struct EchoingStringTokenReplacer;
 
-impl EchoingStringTokenReplacer {
-
-  pub fn replace(input: &str) -> String {
-    input.to_owned()
-  }
-}
-
 impl StringTokenReplacer for EchoingStringTokenReplacer {
   fn replace(&self, input: &str) -> String {
-    Self::replace(input)
+    input.to_owned()
   }
 }
 
+#[cfg(test)]
 pub struct ReplacingStringTokenReplacer<'a> {
   replacements: &'a[(&'a str, &'a str)]
 }
 
+#[cfg(test)]
 impl <'a> ReplacingStringTokenReplacer<'a> {
 
-  #[cfg(test)]
   pub fn new(replacements: &'a[(&'a str, &'a str)]) -> Self {
     Self {
       replacements
@@ -37,6 +32,7 @@ impl <'a> ReplacingStringTokenReplacer<'a> {
   }
 }
 
+#[cfg(test)]
 impl StringTokenReplacer for ReplacingStringTokenReplacer<'_> {
     fn replace(&self, input: &str) -> String {
       self.replace(input)
@@ -0,0 +1,5 @@
+pub mod template_registry;
+pub mod template_resolver;
+
+pub use template_registry::{TemplateRegistryFile, RegisteredTemplate};
+pub use template_resolver::TemplateResolver;
@@ -0,0 +1,146 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::config::ZatConfigDir;
+use crate::error::{ZatAction, ZatError, ZatResult};
+
+pub const TEMPLATE_REGISTRY_FILE: &str = "templates.toml";
+
+/// Short names for remote templates. Stored as TOML:
+///
+/// [templates.rust-cli]
+/// url = "https://github.com/org/rust-cli-template"
+/// ref = "v1.2.0"
+/// subdir = "cli"
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct TemplateRegistry {
+  #[serde(default)]
+  pub templates: BTreeMap<String, RegisteredTemplate>
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegisteredTemplate {
+  pub url: String,
+
+  #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+  pub git_ref: Option<String>,
+
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub subdir: Option<String>,
+}
+
+impl RegisteredTemplate {
+  pub fn new(url: &str, git_ref: Option<&str>, subdir: Option<&str>) -> Self {
+    Self {
+      url: url.to_owned(),
+      git_ref: git_ref.map(|r| r.to_owned()),
+      subdir: subdir.map(|s| s.to_owned())
+    }
+  }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateRegistryFile {
+  path: PathBuf
+}
+
+impl TemplateRegistryFile {
+
+  pub fn path(&self) -> &Path {
+    self.path.as_path()
+  }
+
+  /// Returns an empty registry if the registry file does not exist.
+  pub fn load(&self) -> ZatResult<TemplateRegistry> {
+    let path = self.path.to_string_lossy().to_string();
+
+    if self.path.exists() {
+      let content = fs::read_to_string(&self.path).map_err(|e| ZatError::could_not_read_zat_config_file(&path, e.to_string()))?;
+      toml::from_str(&content).map_err(|e| ZatError::could_not_decode_zat_config_file(&path, e.to_string()))
+    } else {
+      Ok(TemplateRegistry::default())
+    }
+  }
+
+  pub fn save(&self, registry: &TemplateRegistry) -> ZatAction {
+    let path = self.path.to_string_lossy().to_string();
+    let content = toml::to_string_pretty(registry).map_err(|e| ZatError::could_not_write_zat_config_file(&path, e.to_string()))?;
+
+    if let Some(parent) = self.path.parent() {
+      fs::create_dir_all(parent).map_err(|e| ZatError::could_not_write_zat_config_file(&path, e.to_string()))?;
+    }
+
+    fs::write(&self.path, content).map_err(|e| ZatError::could_not_write_zat_config_file(&path, e.to_string()))
+  }
+}
+
+impl From<&ZatConfigDir> for TemplateRegistryFile {
+  fn from(config_dir: &ZatConfigDir) -> Self {
+    Self {
+      path: config_dir.join(TEMPLATE_REGISTRY_FILE)
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+  use tempfile::TempDir;
+
+  #[test]
+  fn loads_an_empty_registry_if_the_file_does_not_exist() {
+    let config_dir = TempDir::new().unwrap();
+    let registry_file = TemplateRegistryFile::from(&ZatConfigDir::from(config_dir.path()));
+
+    assert_eq!(registry_file.load(), Ok(TemplateRegistry::default()))
+  }
+
+  #[test]
+  fn saves_and_loads_the_registry() {
+    let config_dir = TempDir::new().unwrap();
+    let registry_file = TemplateRegistryFile::from(&ZatConfigDir::from(config_dir.path().join("nested").as_path()));
+
+    let mut registry = TemplateRegistry::default();
+    registry.templates.insert("rust-cli".to_owned(), RegisteredTemplate::new("gh:org/rust-cli", Some("v1.2.0"), Some("cli")));
+    registry.templates.insert("scala".to_owned(), RegisteredTemplate::new("https://github.com/org/scala", None, None));
+
+    registry_file.save(&registry).unwrap();
+
+    assert_eq!(registry_file.load(), Ok(registry))
+  }
+
+  #[test]
+  fn decodes_the_registry_format() {
+    let content = r#"
+      [templates.rust-cli]
+      url = "https://github.com/org/rust-cli-template"
+      ref = "v1.2.0"
+      subdir = "cli"
+
+      [templates.minimal]
+      url = "gh:org/minimal"
+    "#;
+
+    let registry: TemplateRegistry = toml::from_str(content).unwrap();
+
+    assert_eq!(registry.templates.get("rust-cli"), Some(&RegisteredTemplate::new("https://github.com/org/rust-cli-template", Some("v1.2.0"), Some("cli"))));
+    assert_eq!(registry.templates.get("minimal"), Some(&RegisteredTemplate::new("gh:org/minimal", None, None)))
+  }
+
+  #[test]
+  fn fails_to_load_an_invalid_registry() {
+    let config_dir = TempDir::new().unwrap();
+    let registry_file = TemplateRegistryFile::from(&ZatConfigDir::from(config_dir.path()));
+    fs::write(registry_file.path(), "[templates.rust-cli]\nref = 123").unwrap();
+
+    match registry_file.load() {
+      Err(ZatError::ZatConfigError(crate::error::zat_config_error_reason::ZatConfigErrorReason::CouldNotDecodeFile(..))) => (),
+      other => panic!("Expected CouldNotDecodeFile but got: {:?}", other)
+    }
+  }
+}
@@ -0,0 +1,136 @@
+use super::{RegisteredTemplate, TemplateRegistryFile};
+use crate::args::ProcessRemoteTemplatesArgs;
+use crate::config::{RemoteRepository, ZatConfigDir};
+use crate::error::{ZatError, ZatResult};
+use std::format as s;
+
+const GITHUB_SHORTHAND_PREFIX: &str = "gh:";
+
+/// Resolves template names and shorthands supplied to `process-remote` into a RemoteRepository.
+pub struct TemplateResolver;
+
+impl TemplateResolver {
+
+  pub fn resolve(process_remote_template_args: &ProcessRemoteTemplatesArgs) -> ZatResult<RemoteRepository> {
+    let remote_repository =
+      match (&process_remote_template_args.repository_url, &process_remote_template_args.template) {
+        (Some(url), _) => RemoteRepository::new(&Self::expand_url(url)?),
+        (None, Some(template)) => {
+          let registry_file = TemplateRegistryFile::from(&ZatConfigDir::new()?);
+          Self::resolve_template(template, &registry_file)?
+        },
+        // clap ensures one of these is supplied
+        (None, None) => return Err(ZatError::generic_error("Neither a repository URL nor a template was supplied. ERROR_ID: 1002", "No repository or template".to_owned()))
+      };
+
+    // Any ref or subdir supplied on the command line overrides those in the registry
+    Ok(
+      RemoteRepository {
+        git_ref: process_remote_template_args.git_ref.clone().or(remote_repository.git_ref),
+        subdir: process_remote_template_args.subdir.clone().or(remote_repository.subdir),
+        ..remote_repository
+      }
+    )
+  }
+
+  /// A template is either a GitHub shorthand (gh:owner/repository) or the name of a template in the registry.
+  pub fn resolve_template(template: &str, registry_file: &TemplateRegistryFile) -> ZatResult<RemoteRepository> {
+    if template.starts_with(GITHUB_SHORTHAND_PREFIX) {
+      Ok(RemoteRepository::new(&Self::expand_url(template)?))
+    } else {
+      let registry = registry_file.load()?;
+
+      match registry.templates.get(template) {
+        Some(RegisteredTemplate { url, git_ref, subdir }) => {
+          Ok(
+            RemoteRepository {
+              url: Self::expand_url(url)?,
+              git_ref: git_ref.clone(),
+              subdir: subdir.clone()
+            }
+          )
+        },
+        None => Err(ZatError::template_not_found_in_registry(template, &registry_file.path().to_string_lossy()))
+      }
+    }
+  }
+
+  /// Expands GitHub shorthands into https URLs. All other URLs are returned as is.
+  pub fn expand_url(url: &str) -> ZatResult<String> {
+    match url.strip_prefix(GITHUB_SHORTHAND_PREFIX) {
+      Some(owner_and_repository) => {
+        match owner_and_repository.split('/').collect::<Vec<_>>().as_slice() {
+          [owner, repository] if !owner.is_empty() && !repository.is_empty() => Ok(s!("https://github.com/{}/{}", owner, repository)),
+          _ => Err(ZatError::invalid_template_shorthand(url))
+        }
+      },
+      None => Ok(url.to_owned())
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::template_registry::TemplateRegistry;
+  use crate::error::zat_config_error_reason::ZatConfigErrorReason;
+  use crate::assert_error_with;
+  use pretty_assertions::assert_eq;
+  use tempfile::TempDir;
+
+  #[test]
+  fn expands_github_shorthands() {
+    assert_eq!(TemplateResolver::expand_url("gh:ssanj/zat"), Ok("https://github.com/ssanj/zat".to_owned()));
+    assert_eq!(TemplateResolver::expand_url("https://example.com/ssanj/zat"), Ok("https://example.com/ssanj/zat".to_owned()))
+  }
+
+  #[test]
+  fn fails_on_invalid_github_shorthands() {
+    for shorthand in ["gh:ssanj", "gh:/zat", "gh:ssanj/", "gh:ssanj/zat/extra"] {
+      assert_error_with!(
+        TemplateResolver::expand_url(shorthand),
+        Err(ZatError::ZatConfigError(ZatConfigErrorReason::InvalidTemplateShorthand(..))) => (),
+        |_| ()
+      )
+    }
+  }
+
+  #[test]
+  fn resolves_registered_templates() {
+    let config_dir = TempDir::new().unwrap();
+    let registry_file = TemplateRegistryFile::from(&ZatConfigDir::from(config_dir.path()));
+
+    let mut registry = TemplateRegistry::default();
+    registry.templates.insert("rust-cli".to_owned(), RegisteredTemplate::new("gh:org/rust-cli", Some("v1.2.0"), Some("cli")));
+    registry_file.save(&registry).unwrap();
+
+    let expected_repository =
+      RemoteRepository {
+        url: "https://github.com/org/rust-cli".to_owned(),
+        git_ref: Some("v1.2.0".to_owned()),
+        subdir: Some("cli".to_owned())
+      };
+
+    assert_eq!(TemplateResolver::resolve_template("rust-cli", &registry_file), Ok(expected_repository))
+  }
+
+  #[test]
+  fn resolves_github_shorthands_without_the_registry() {
+    let registry_file = TemplateRegistryFile::from(&ZatConfigDir::from(std::path::Path::new("/does/not/exist")));
+
+    assert_eq!(TemplateResolver::resolve_template("gh:org/rust-cli", &registry_file), Ok(RemoteRepository::new("https://github.com/org/rust-cli")))
+  }
+
+  #[test]
+  fn fails_on_unknown_templates() {
+    let config_dir = TempDir::new().unwrap();
+    let registry_file = TemplateRegistryFile::from(&ZatConfigDir::from(config_dir.path()));
+
+    assert_error_with!(
+      TemplateResolver::resolve_template("rust-cli", &registry_file),
+      Err(ZatError::ZatConfigError(ZatConfigErrorReason::TemplateNotFound(error, _))) => error,
+      |error: String| assert!(error.starts_with("The template 'rust-cli' could not be found in the template registry"), "unexpected error: {}", error)
+    )
+  }
+}
@@ -0,0 +1,142 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::format as s;
+use ansi_term::Colour::{Green, Red, RGB};
+use similar::{ChangeTag, TextDiff};
+use walkdir::WalkDir;
+
+/// The differences between an expected directory and the directory that was actually rendered.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DirectoryDiff {
+  pub only_in_expected: Vec<String>,
+  pub only_in_actual: Vec<String>,
+  pub changed_files: Vec<String>,
+}
+
+impl DirectoryDiff {
+
+  pub fn new<E: AsRef<Path>, A: AsRef<Path>>(expected_dir: E, actual_dir: A) -> Self {
+    let expected_entries = Self::entries(expected_dir.as_ref());
+    let actual_entries = Self::entries(actual_dir.as_ref());
+
+    let changed_files =
+      expected_entries
+        .intersection(&actual_entries)
+        .filter(|entry| {
+          let expected_file = expected_dir.as_ref().join(entry);
+          let actual_file = actual_dir.as_ref().join(entry);
+          expected_file.is_file() && fs::read(expected_file).ok() != fs::read(actual_file).ok()
+        })
+        .cloned()
+        .collect();
+
+    Self {
+      only_in_expected: expected_entries.difference(&actual_entries).cloned().collect(),
+      only_in_actual: actual_entries.difference(&expected_entries).cloned().collect(),
+      changed_files
+    }
+  }
+
+  pub fn is_different(&self) -> bool {
+    !(self.only_in_expected.is_empty() && self.only_in_actual.is_empty() && self.changed_files.is_empty())
+  }
+
+  /// Describes the differences, with a line diff of each changed text file.
+  pub fn lines<E: AsRef<Path>, A: AsRef<Path>>(&self, expected_dir: E, actual_dir: A) -> Vec<String> {
+    let mut lines = vec![];
+
+    for entry in &self.only_in_expected {
+      lines.push(s!("Only in expected: {}", entry))
+    }
+
+    for entry in &self.only_in_actual {
+      lines.push(s!("Only in actual: {}", entry))
+    }
+
+    for file in &self.changed_files {
+      lines.push(s!("Changes found in: {}", Red.paint(file.as_str())));
+
+      let expected_content = fs::read(expected_dir.as_ref().join(file)).unwrap_or_default();
+      let actual_content = fs::read(actual_dir.as_ref().join(file)).unwrap_or_default();
+
+      match (std::str::from_utf8(&expected_content), std::str::from_utf8(&actual_content)) {
+        (Ok(expected_content), Ok(actual_content)) => {
+          let text_diff = TextDiff::from_lines(expected_content, actual_content);
+
+          for change in text_diff.iter_all_changes() {
+            let sign = match change.tag() {
+              ChangeTag::Delete => Red.paint("-"),
+              ChangeTag::Insert => Green.paint("+"),
+              ChangeTag::Equal => RGB(128, 128, 128).paint("|"),
+            };
+
+            lines.push(s!("  {}{}", sign, change.to_string_lossy().trim_end_matches('\n')))
+          }
+        },
+        _ => lines.push("  Binary files differ".to_owned())
+      }
+    }
+
+    lines
+  }
+
+  /// The relative paths of all files and directories within `dir`.
+  fn entries(dir: &Path) -> BTreeSet<String> {
+    WalkDir::new(dir)
+      .min_depth(1)
+      .into_iter()
+      .filter_map(|entry| entry.ok())
+      .filter_map(|entry| entry.path().strip_prefix(dir).ok().map(|path| path.to_string_lossy().to_string()))
+      .collect()
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use tempfile::TempDir;
+  use pretty_assertions::assert_eq;
+
+  fn write_files(dir: &Path, files: &[(&str, &str)]) {
+    for (file, content) in files {
+      let path = dir.join(file);
+      fs::create_dir_all(path.parent().unwrap()).unwrap();
+      fs::write(path, content).unwrap();
+    }
+  }
+
+  #[test]
+  fn finds_added_removed_and_changed_files() {
+    let expected_dir = TempDir::new().unwrap();
+    let actual_dir = TempDir::new().unwrap();
+
+    write_files(expected_dir.path(), &[("README.md", "# Hello\n"), ("src/main.rs", "fn main() {}\n"), ("LICENSE", "MIT\n")]);
+    write_files(actual_dir.path(), &[("README.md", "# Goodbye\n"), ("src/main.rs", "fn main() {}\n"), ("NOTICE", "None\n")]);
+
+    let diff = DirectoryDiff::new(expected_dir.path(), actual_dir.path());
+
+    assert_eq!(
+      diff,
+      DirectoryDiff {
+        only_in_expected: vec!["LICENSE".to_owned()],
+        only_in_actual: vec!["NOTICE".to_owned()],
+        changed_files: vec!["README.md".to_owned()]
+      }
+    );
+
+    assert!(diff.is_different())
+  }
+
+  #[test]
+  fn identical_directories_are_not_different() {
+    let expected_dir = TempDir::new().unwrap();
+    let actual_dir = TempDir::new().unwrap();
+
+    write_files(expected_dir.path(), &[("src/main.rs", "fn main() {}\n")]);
+    write_files(actual_dir.path(), &[("src/main.rs", "fn main() {}\n")]);
+
+    assert!(!DirectoryDiff::new(expected_dir.path(), actual_dir.path()).is_different())
+  }
+}
@@ -0,0 +1,5 @@
+pub mod test_case;
+pub mod directory_diff;
+
+pub use test_case::TestCase;
+pub use directory_diff::DirectoryDiff;
@@ -0,0 +1,180 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::format as s;
+use walkdir::WalkDir;
+
+use crate::config::RepositoryDir;
+use crate::config::zat_config_dir::ZAT_CONFIG_DIR_ENV;
+use crate::error::{ZatAction, ZatError, ZatResult};
+
+/// The directory within a Zat repository that holds its test cases.
+pub const TESTS_DIR: &str = "tests";
+
+/// The answers to each prompt of a test case, one per line, as they would be typed with the numbered choice menu style.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// The directory with the expected output of a test case.
+pub const EXPECTED_DIR: &str = "expected";
+
+/// A test case of a Zat repository: `tests/<name>/answers.txt` and the expected output in `tests/<name>/expected`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestCase {
+  pub name: String,
+  pub answers_file: PathBuf,
+  pub expected_dir: PathBuf,
+}
+
+impl TestCase {
+
+  /// Finds every directory within the tests directory of the repository that has an answers file, sorted by name.
+  pub fn find_all(repository_dir: &RepositoryDir) -> ZatResult<Vec<TestCase>> {
+    let tests_dir = repository_dir.join(TESTS_DIR);
+
+    if !tests_dir.is_dir() {
+      return Ok(vec![])
+    }
+
+    let entries =
+      fs::read_dir(&tests_dir)
+        .map_err(|e| ZatError::could_not_run_test_case(&tests_dir.to_string_lossy(), e.to_string()))?;
+
+    let mut test_cases: Vec<TestCase> =
+      entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.join(ANSWERS_FILE).is_file())
+        .map(|path| {
+          TestCase {
+            name: path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default(),
+            answers_file: path.join(ANSWERS_FILE),
+            expected_dir: path.join(EXPECTED_DIR),
+          }
+        })
+        .collect();
+
+    test_cases.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(test_cases)
+  }
+
+  /// Processes the repository into `target_dir`, answering the prompts from the answers file. Zat is run with an empty
+  /// config directory, so that the defaults in the user config file don't change the output.
+  pub fn render(&self, repository_dir: &RepositoryDir, target_dir: &Path, config_dir: &Path) -> ZatAction {
+    let mut answers =
+      fs::read_to_string(&self.answers_file)
+        .map_err(|e| ZatError::could_not_run_test_case(&self.name, e.to_string()))?;
+
+    if !answers.ends_with('\n') {
+      answers.push('\n')
+    }
+
+    let zat = std::env::current_exe().map_err(|e| ZatError::could_not_run_test_case(&self.name, e.to_string()))?;
+
+    let mut child =
+      Command::new(zat)
+        .arg("process")
+        .arg("--repository-dir")
+        .arg(repository_dir.path())
+        .arg("--target-dir")
+        .arg(target_dir)
+        .arg("--choice-menu-style")
+        .arg("numbered")
+        .env(ZAT_CONFIG_DIR_ENV, config_dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| ZatError::could_not_run_test_case(&self.name, e.to_string()))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+      // Zat may exit before reading all the answers, so a failed write is reported through its output instead
+      let _ = stdin.write_all(answers.as_bytes());
+    }
+
+    let output = child.wait_with_output().map_err(|e| ZatError::could_not_run_test_case(&self.name, e.to_string()))?;
+
+    if output.status.success() {
+      Ok(())
+    } else {
+      let error_output = s!("{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+      Err(ZatError::could_not_run_test_case(&self.name, error_output.trim().to_owned()))
+    }
+  }
+
+  /// Replaces the expected output with the rendered output in `target_dir`.
+  pub fn bless(&self, target_dir: &Path) -> ZatAction {
+    let bless_error = |e: String| ZatError::could_not_bless_test_case(&self.name, &self.expected_dir.to_string_lossy(), e);
+
+    if self.expected_dir.exists() {
+      fs::remove_dir_all(&self.expected_dir).map_err(|e| bless_error(e.to_string()))?
+    }
+
+    fs::create_dir_all(&self.expected_dir).map_err(|e| bless_error(e.to_string()))?;
+
+    for entry in WalkDir::new(target_dir).min_depth(1) {
+      let entry = entry.map_err(|e| bless_error(e.to_string()))?;
+      let relative_path = entry.path().strip_prefix(target_dir).expect("Could not strip the target directory prefix");
+      let expected_path = self.expected_dir.join(relative_path);
+
+      if entry.file_type().is_dir() {
+        fs::create_dir_all(&expected_path).map_err(|e| bless_error(e.to_string()))?
+      } else {
+        fs::copy(entry.path(), &expected_path).map_err(|e| bless_error(e.to_string()))?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use tempfile::TempDir;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn finds_test_cases_with_answers() {
+    let repository_dir = TempDir::new().unwrap();
+    let tests_dir = repository_dir.path().join(TESTS_DIR);
+
+    for (case, answers) in [("short-readme", true), ("long-readme", true), ("work-in-progress", false)] {
+      fs::create_dir_all(tests_dir.join(case)).unwrap();
+      if answers {
+        fs::write(tests_dir.join(case).join(ANSWERS_FILE), "y").unwrap();
+      }
+    }
+
+    let test_cases = TestCase::find_all(&RepositoryDir::from(repository_dir.path())).unwrap();
+    let names: Vec<&str> = test_cases.iter().map(|test_case| test_case.name.as_str()).collect();
+
+    assert_eq!(names, vec!["long-readme", "short-readme"]);
+    assert_eq!(test_cases[0].expected_dir, tests_dir.join("long-readme").join(EXPECTED_DIR))
+  }
+
+  #[test]
+  fn blessing_replaces_the_expected_output() {
+    let case_dir = TempDir::new().unwrap();
+    let target_dir = TempDir::new().unwrap();
+
+    let test_case =
+      TestCase {
+        name: "simple".to_owned(),
+        answers_file: case_dir.path().join(ANSWERS_FILE),
+        expected_dir: case_dir.path().join(EXPECTED_DIR)
+      };
+
+    fs::create_dir_all(&test_case.expected_dir).unwrap();
+    fs::write(test_case.expected_dir.join("OLD.md"), "old").unwrap();
+    fs::create_dir_all(target_dir.path().join("src")).unwrap();
+    fs::write(target_dir.path().join("src").join("main.rs"), "new").unwrap();
+
+    test_case.bless(target_dir.path()).unwrap();
+
+    assert!(!test_case.expected_dir.join("OLD.md").exists());
+    assert_eq!(fs::read_to_string(test_case.expected_dir.join("src").join("main.rs")).unwrap(), "new")
+  }
+}
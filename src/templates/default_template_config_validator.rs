@@ -5,8 +5,9 @@ use super::{Plugin, TemplateConfigValidator, TemplateVariable, TemplateVariableR
 use super::{UserVariableValue, UserVariableKey, UserChoiceKey, UserChoiceValue};
 use crate::choice::selected_choices::SelectedChoices;
 use crate::config::UserConfig;
-use crate::error::ZatResult;
-use crate::templates::PluginRunResult;
+use crate::error::{ZatError, ZatResult};
+use crate::plugin::{DefaultPluginRunner, PluginCache, PluginContext, PluginOutputs, PluginRunnerWorkflow};
+use crate::templates::{PluginRunResult, PluginRunStatus};
 use ansi_term::Colour::{Yellow, Green, Blue, Red};
 use ansi_term::Style;
 use std::{println as p, format as s};
@@ -29,7 +30,7 @@ impl UserInput {
 
 // This is a support trait to TemplateConfigValidator, so we define it here as opposed to in its own module.
 trait UserInputProvider {
-  fn get_user_input(&self, selected_choices: &SelectedChoices) -> ZatResult<UserInput>;
+  fn get_user_input(&self, user_config: &UserConfig, selected_choices: &SelectedChoices) -> ZatResult<UserInput>;
 }
 
 trait UserTemplateVariableValidator {
@@ -54,12 +55,20 @@ struct DynamicPair(String, String);
 
 
 impl UserInputProvider for Cli {
-  fn get_user_input(&self, selected_choices: &SelectedChoices) -> ZatResult<UserInput> {
+  fn get_user_input(&self, user_config: &UserConfig, selected_choices: &SelectedChoices) -> ZatResult<UserInput> {
     let mut token_map = HashMap::new();
+    let mut plugin_outputs = PluginOutputs::new();
 
     for v in &selected_choices.variables.tokens {
       p!();
-      Cli::get_variable(&mut token_map, v)
+      let mut variable = Cli::run_dependent_plugin(user_config, selected_choices, &token_map, v, &mut plugin_outputs)?;
+
+      // Outputs returned for this variable by the plugin of an earlier variable
+      if let Some(value) = plugin_outputs.remove(&variable.variable_name) {
+        variable.default_value = Some(value)
+      }
+
+      Cli::get_variable(&mut token_map, &variable)
     }
 
     Ok(UserInput::new(token_map, selected_choices.choices.clone()))
@@ -83,6 +92,34 @@ impl UserTemplateVariableValidator for Cli {
 
 impl Cli {
 
+  /// Plugins that depend on other variables or choices are run once those have been answered, just before the
+  /// variable is prompted for. Any outputs the plugin returns for other variables are added to plugin_outputs.
+  fn run_dependent_plugin(user_config: &UserConfig, selected_choices: &SelectedChoices, token_map: &HashMap<UserVariableKey, UserVariableValue>, template_variable: &TemplateVariable, plugin_outputs: &mut PluginOutputs) -> ZatResult<TemplateVariable> {
+    let mut variable = template_variable.clone();
+
+    match variable.plugin.as_mut() {
+      Some(plugin) if !plugin.depends_on.is_empty() && plugin.result == PluginRunStatus::NotRun && !user_config.no_plugins => {
+        let unanswered =
+          plugin
+            .depends_on
+            .iter()
+            .find(|dependency| {
+              !token_map.contains_key(&UserVariableKey::new(dependency.to_string())) &&
+              !selected_choices.choices.contains_key(&UserChoiceKey::new(dependency.to_string()))
+            });
+
+        if let Some(dependency) = unanswered {
+          return Err(ZatError::plugin_dependency_not_answered(&plugin.id, &template_variable.variable_name, dependency))
+        }
+
+        let context = PluginContext::new(user_config, &selected_choices.variables, token_map, &selected_choices.choices);
+        plugin_outputs.extend(PluginRunnerWorkflow::run_plugin(&DefaultPluginRunner::new(), &template_variable.variable_name, plugin, &context, &PluginCache::new(user_config))?);
+        Ok(variable)
+      },
+      _ => Ok(variable)
+    }
+  }
+
   fn print_user_input(user_variables: &HashMap<UserVariableKey, UserVariableValue>) {
     Logger::info("Please confirm the variable mappings below are correct:");
 
@@ -149,10 +186,9 @@ impl Cli {
 
 
   fn get_variable(token_map: &mut HashMap<UserVariableKey, UserVariableValue>, template_variable: &TemplateVariable) {
-    let default_value = Cli::get_default_value(template_variable.default_value.as_deref());
     let plugin_result_value: Option<PluginRunResult> = Cli::get_plugin_value(template_variable.plugin.as_ref()
       );
-    let dynamic_value = Cli::get_dynamic_values(default_value.as_deref(), plugin_result_value.as_ref());
+    let dynamic_value = Cli::get_dynamic_values(template_variable.default_value.as_deref(), plugin_result_value.as_ref());
 
       // Ask the user of values for each token
     Cli::prompt_user_for_variable(token_map, template_variable, &dynamic_value)
@@ -206,16 +242,12 @@ impl Cli {
     p!()
   }
 
-  fn get_default_value(opt_default_value: Option<&str>) -> Option<String> {
-    opt_default_value.map(|dv| dv.to_owned())
-  }
-
   fn get_plugin_value(opt_plugin_value: Option<&Plugin>) -> Option<PluginRunResult> {
     let plugin = opt_plugin_value?;
 
     match &plugin.result {
-      crate::templates::PluginRunStatus::NotRun => None,
-      crate::templates::PluginRunStatus::Run(run_result) => Some(run_result.to_owned()),
+      PluginRunStatus::NotRun => None,
+      PluginRunStatus::Run(run_result) => Some(run_result.to_owned()),
     }
   }
 }
@@ -248,7 +280,7 @@ impl DefaultTemplateConfigValidator {
 impl TemplateConfigValidator for DefaultTemplateConfigValidator {
 
   fn validate(&self, user_config: &UserConfig, selected_choices: &SelectedChoices) -> ZatResult<TemplateVariableReview> {
-      let user_variables = self.user_input_provider.get_user_input(selected_choices)?;
+      let user_variables = self.user_input_provider.get_user_input(user_config, selected_choices)?;
       Ok(self.user_template_variable_validator.review_user_template_variables(user_config, user_variables))
   }
 }
@@ -260,7 +292,7 @@ use super::super::{TemplateVariable, TemplateVariables};
 use super::*;
 use pretty_assertions::assert_eq;
 use crate::config::user_config::UserConfig;
-use crate::templates::PluginRunStatus;
+use crate::templates::{PluginProvides, PluginRunStatus};
 
   #[derive(Debug, Default)]
   struct SimpleInput {
@@ -278,7 +310,7 @@ use crate::templates::PluginRunStatus;
 
 
   impl UserInputProvider for SimpleInput {
-    fn get_user_input(&self, selected_choices: &SelectedChoices) -> ZatResult<UserInput> {
+    fn get_user_input(&self, _user_config: &UserConfig, selected_choices: &SelectedChoices) -> ZatResult<UserInput> {
       let variables = &selected_choices.variables;
 
       let token_pairs =
@@ -459,7 +491,13 @@ use crate::templates::PluginRunStatus;
     let plugin = Plugin {
         id: "MyPlugin".to_owned(),
         args: Default::default(),
+        depends_on: vec![],
+        timeout_ms: None,
+        cache_ttl_secs: None,
+        optional: false,
+        provides: PluginProvides::Value,
         result: PluginRunStatus::default(),
+        repository_dir: Default::default(),
       };
 
     let result = Cli::get_plugin_value(Some(&plugin));
@@ -473,29 +511,19 @@ use crate::templates::PluginRunStatus;
     let plugin = Plugin {
         id: "MyPlugin".to_owned(),
         args: Default::default(),
+        depends_on: vec![],
+        timeout_ms: None,
+        cache_ttl_secs: None,
+        optional: false,
+        provides: PluginProvides::Value,
         result: PluginRunStatus::Run(plugin_result.clone()),
+        repository_dir: Default::default(),
       };
 
     let result = Cli::get_plugin_value(Some(&plugin));
     assert_eq!(result, Some(plugin_result))
   }
 
-  #[test]
-  fn get_default_value_returns_none_if_not_set() {
-    let default_value = None;
-    let result = Cli::get_default_value(default_value);
-
-    assert_eq!(result, None)
-  }
-
-  #[test]
-  fn get_default_value_returns_default_if_set() {
-    let default_value = Some("my default");
-    let result = Cli::get_default_value(default_value);
-
-    assert_eq!(result, default_value.map(|dv| dv.to_owned()))
-  }
-
   #[test]
   fn get_dynamic_values_returns_plugin_if_set() {
     let plugin_result_value = "my plugin result";
@@ -1,10 +1,12 @@
 use crate::error::*;
 use super::TemplateVariableProvider;
 use crate::config::UserConfig;
-use crate::config::VariableFile;
+use crate::config::{VariableFile, VariableFileFormat};
 use super::{TemplateVariable, TemplateVariables};
 use std::fs::File;
 use std::io::Read;
+use serde_json::Value;
+use super::variable_file_schema::validate_variables;
 
 pub struct DefaultTemplateVariableProvider;
 
@@ -18,38 +20,96 @@ impl DefaultTemplateVariableProvider {
 // That would make it easier to test and lead to more reuse of code
 impl TemplateVariableProvider for DefaultTemplateVariableProvider {
   fn get_tokens(&self, user_config: UserConfig) -> ZatResult<TemplateVariables> {
-    let variables_file: VariableFile = VariableFile::from(user_config.repository_dir);
+    let variables_file: VariableFile = VariableFile::find(&user_config.repository_dir)?;
     let variable_file_path = variables_file.get_path().to_owned();
 
     if variables_file.does_exist() {
+      let variables_format = variables_file.format();
       let mut f = File::open(variables_file).map_err(|e| ZatError::variable_file_cant_be_opened(&variable_file_path, e.to_string().as_str()))?;
-      let mut variables_json = String::new();
+      let mut variables_content = String::new();
 
-      f.read_to_string(&mut variables_json).map_err(|e| ZatError::variable_file_cant_be_read(&variable_file_path, e.to_string().as_str()))?;
+      f.read_to_string(&mut variables_content).map_err(|e| ZatError::variable_file_cant_be_read(&variable_file_path, e.to_string().as_str()))?;
 
-      let tokens: Vec<TemplateVariable> = serde_json::from_str(&variables_json).map_err(|e| ZatError::variable_file_cant_be_decoded(&variable_file_path, e.to_string().as_str()))?;
+      let variables = parse_variables(&variables_content, variables_format).map_err(|e| ZatError::variable_file_cant_be_decoded(&variable_file_path, &variables_format.to_string(), e.as_str()))?;
 
-      if !tokens.is_empty() {
-        Ok(
-          TemplateVariables {
-            tokens
-          }
-        )
-      } else {
-        Err(ZatError::variable_file_has_no_variables_defined(&variable_file_path))
+      if variables.as_array().is_some_and(|variables| variables.is_empty()) {
+        return Err(ZatError::variable_file_has_no_variables_defined(&variable_file_path))
       }
+
+      // Validate against the schema first, as it gives more precise errors than decoding; especially for untagged enums
+      let json_content = (variables_format == VariableFileFormat::Json).then_some(variables_content.as_str());
+      let violations = validate_variables(&variables, pointer_prefix(variables_format), json_content);
+
+      if !violations.is_empty() {
+        let violation_details: Vec<(String, String, String)> =
+          violations
+            .into_iter()
+            .map(|violation| (violation.location(), violation.message, violation.fix))
+            .collect();
+
+        return Err(ZatError::variable_file_does_not_match_schema(&variable_file_path, &violation_details))
+      }
+
+      let tokens: Vec<TemplateVariable> = serde_json::from_value(variables).map_err(|e| ZatError::variable_file_cant_be_decoded(&variable_file_path, &variables_format.to_string(), e.to_string().as_str()))?;
+
+      // Plugins are looked up in the plugins directory of the repository that defines them
+      let tokens =
+        tokens
+          .into_iter()
+          .map(|token| {
+            match token.plugin {
+              Some(plugin) => plugin.defined_in(&user_config.repository_dir).map(|plugin| TemplateVariable { plugin: Some(plugin), ..token }),
+              None => Ok(token),
+            }
+          })
+          .collect::<ZatResult<Vec<TemplateVariable>>>()?;
+
+      Ok(
+        TemplateVariables {
+          tokens
+        }
+      )
     } else {
       Err(ZatError::variable_file_does_not_exist(&variable_file_path))
     }
   }
 }
 
+/// TOML documents can't be a top-level array, so TOML variable files use an array of tables named 'variables':
+///
+/// [[variables]]
+/// variable_name = "project"
+/// ...
+const TOML_VARIABLES_KEY: &str = "variables";
+
+/// Parses the variables into JSON, so every format can be validated against the same schema.
+fn parse_variables(content: &str, format: VariableFileFormat) -> Result<Value, String> {
+  match format {
+    VariableFileFormat::Json => serde_json::from_str(content).map_err(|e| e.to_string()),
+    VariableFileFormat::Yaml => serde_yaml::from_str(content).map_err(|e| e.to_string()),
+    VariableFileFormat::Toml => {
+      let mut table: toml::Table = toml::from_str(content).map_err(|e| e.to_string())?;
+      let variables = table.remove(TOML_VARIABLES_KEY).unwrap_or(toml::Value::Array(vec![]));
+      serde_json::to_value(variables).map_err(|e| e.to_string())
+    },
+  }
+}
+
+/// Where the variables are within the variable file, as a JSON pointer.
+fn pointer_prefix(format: VariableFileFormat) -> &'static str {
+  match format {
+    VariableFileFormat::Toml => "/variables",
+    VariableFileFormat::Json | VariableFileFormat::Yaml => "",
+  }
+}
+
 #[cfg(test)]
 mod tests {
 
   use super::*;
   use tempfile::TempDir;
   use crate::config::DOT_VARIABLES_PROMPT;
+  use crate::config::variable_file::{DOT_VARIABLES_PROMPT_YAML, DOT_VARIABLES_PROMPT_TOML};
   use crate::error::variable_file_error_reason::VariableFileErrorReason;
   use super::super::FilterType;
   use super::super::VariableFilter;
@@ -223,4 +283,171 @@ mod tests {
     drop(variable_file);
   }
 
+  const JSON_VARIABLES: &str = r#"
+    [
+      {
+        "variable_name": "readme_type",
+        "description": "Type of README",
+        "prompt": "Please choose your type of README",
+        "choices": [
+          { "display": "Short", "description": "A shorter README", "value": "short" },
+          { "display": "Long", "description": "A longer README", "value": "long" }
+        ]
+      },
+      {
+        "variable_name": "project",
+        "description": "Name of project",
+        "prompt": "Please enter your project name",
+        "filters": [
+          { "name": "__default__", "filter": "Pascal" },
+          { "name": "underscore", "filter": "Snake" }
+        ],
+        "plugin": {
+          "id": "scala-deps",
+          "args": [
+            { "name": "o", "value": "org.scala-lang", "prefix": "-" }
+          ]
+        },
+        "scopes": [
+          { "choice": "readme_type", "value": "short" },
+          { "not_choice": "license" }
+        ]
+      }
+    ]
+  "#;
+
+  const YAML_VARIABLES: &str = r#"
+# Comments are supported
+- variable_name: readme_type
+  description: Type of README
+  prompt: Please choose your type of README
+  choices:
+    - { display: Short, description: A shorter README, value: short }
+    - { display: Long, description: A longer README, value: long }
+
+- variable_name: project
+  description: Name of project
+  prompt: Please enter your project name
+  filters:
+    - name: __default__
+      filter: Pascal
+    - name: underscore
+      filter: Snake
+  plugin:
+    id: scala-deps
+    args:
+      - name: o
+        value: org.scala-lang
+        prefix: "-"
+  scopes:
+    - choice: readme_type
+      value: short
+    - not_choice: license
+"#;
+
+  const TOML_VARIABLES: &str = r#"
+    # Comments are supported
+    [[variables]]
+    variable_name = "readme_type"
+    description = "Type of README"
+    prompt = "Please choose your type of README"
+    choices = [
+      { display = "Short", description = "A shorter README", value = "short" },
+      { display = "Long", description = "A longer README", value = "long" },
+    ]
+
+    [[variables]]
+    variable_name = "project"
+    description = "Name of project"
+    prompt = "Please enter your project name"
+    filters = [
+      { name = "__default__", filter = "Pascal" },
+      { name = "underscore", filter = "Snake" },
+    ]
+    scopes = [
+      { choice = "readme_type", value = "short" },
+      { not_choice = "license" },
+    ]
+
+    [variables.plugin]
+    id = "scala-deps"
+    args = [
+      { name = "o", value = "org.scala-lang", prefix = "-" },
+    ]
+  "#;
+
+  fn decode_variables(content: &str, format: VariableFileFormat) -> Vec<TemplateVariable> {
+    serde_json::from_value(parse_variables(content, format).unwrap()).unwrap()
+  }
+
+  #[test]
+  fn yaml_and_toml_variables_decode_the_same_as_json() {
+    let json_variables = decode_variables(JSON_VARIABLES, VariableFileFormat::Json);
+    let yaml_variables = decode_variables(YAML_VARIABLES, VariableFileFormat::Yaml);
+    let toml_variables = decode_variables(TOML_VARIABLES, VariableFileFormat::Toml);
+
+    assert_eq!(json_variables.len(), 2);
+    assert_eq!(yaml_variables, json_variables);
+    assert_eq!(toml_variables, json_variables)
+  }
+
+  #[test]
+  fn tokens_are_loaded_from_a_yaml_variable_file() {
+    let template_dir = TempDir::new().unwrap();
+    let template_dir_path = template_dir.path().display().to_string();
+
+    std::fs::write(template_dir.path().join(DOT_VARIABLES_PROMPT_YAML), YAML_VARIABLES).unwrap();
+
+    let template_config_provider = DefaultTemplateVariableProvider::new();
+    let user_config = UserConfig::new(&template_dir_path, "");
+    let repository_dir = user_config.repository_dir.clone();
+
+    let tokens = template_config_provider.get_tokens(user_config).expect("Expected to get tokens");
+
+    // Plugins are defined by the repository the variables are loaded from
+    let expected_tokens: Vec<TemplateVariable> =
+      decode_variables(JSON_VARIABLES, VariableFileFormat::Json)
+        .into_iter()
+        .map(|token| TemplateVariable { plugin: token.plugin.map(|plugin| plugin.defined_in(&repository_dir).unwrap()), ..token })
+        .collect();
+
+    assert!(expected_tokens.iter().any(|token| token.plugin.as_ref().is_some_and(|plugin| plugin.repository_dir == repository_dir)));
+    assert_eq!(tokens.tokens, expected_tokens)
+  }
+
+  #[test]
+  fn fails_if_the_toml_variable_file_cannot_be_decoded() {
+    let template_dir = TempDir::new().unwrap();
+    let template_dir_path = template_dir.path().display().to_string();
+
+    // Invalid TOML
+    std::fs::write(template_dir.path().join(DOT_VARIABLES_PROMPT_TOML), "[[variables]\nvariable_name = 'project'").unwrap();
+
+    let template_config_provider = DefaultTemplateVariableProvider::new();
+    let user_config = UserConfig::new(&template_dir_path, "");
+
+    match template_config_provider.get_tokens(user_config) {
+      Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::VariableFileError(VariableFileErrorReason::VariableDecodeError(error, _)))) => assert!(error.contains("could not be decoded as TOML"), "unexpected error: {}", error),
+      Err(other_error) => panic!("Expected ZatError::VariableDecodeError but got different error : {}", other_error),
+      Ok(value) => panic!("Expected ZatError::VariableDecodeError but got success with: {:?}", value)
+    }
+  }
+
+  #[test]
+  fn fails_if_there_is_more_than_one_variable_file() {
+    let template_dir = TempDir::new().unwrap();
+    let template_dir_path = template_dir.path().display().to_string();
+
+    std::fs::write(template_dir.path().join(DOT_VARIABLES_PROMPT), JSON_VARIABLES).unwrap();
+    std::fs::write(template_dir.path().join(DOT_VARIABLES_PROMPT_YAML), YAML_VARIABLES).unwrap();
+
+    let template_config_provider = DefaultTemplateVariableProvider::new();
+    let user_config = UserConfig::new(&template_dir_path, "");
+
+    match template_config_provider.get_tokens(user_config) {
+      Err(ZatError::ProcessCommandError(ProcessCommandErrorReason::VariableFileError(VariableFileErrorReason::MultipleVariableFiles(..)))) => (),
+      Err(other_error) => panic!("Expected ZatError::MultipleVariableFiles but got different error : {}", other_error),
+      Ok(value) => panic!("Expected ZatError::MultipleVariableFiles but got success with: {:?}", value)
+    }
+  }
 }
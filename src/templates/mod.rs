@@ -6,6 +6,7 @@ pub mod variables;
 pub mod choice;
 pub mod user_choices;
 pub mod scope;
+pub mod variable_file_schema;
 
 mod plugin;
 
@@ -15,7 +16,7 @@ pub use template_config_validator::{TemplateConfigValidator, ValidConfig, Templa
 pub use default_template_config_validator::DefaultTemplateConfigValidator;
 pub use variables::{FilterType, TemplateVariable, UserVariableKey, UserVariableValue, TemplateVariables, UserChoiceKey, UserChoiceValue};
 
-pub use plugin::{Plugin, PluginRunResult, PluginRunStatus, ArgType};
+pub use plugin::{Plugin, PluginArg, PluginProvides, PluginRunResult, PluginRunStatus, ArgType};
 pub use choice::Choice;
 pub use user_choices::UserChoices;
 pub use scope::{Scope, IncludeChoiceValue};
@@ -23,5 +24,3 @@ pub use scope::{Scope, IncludeChoiceValue};
 #[cfg(test)]
 pub use variables::VariableFilter;
 
-#[cfg(test)]
-pub use plugin::PluginArg;
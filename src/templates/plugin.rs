@@ -1,5 +1,10 @@
+use std::path::{Component, Path};
+use std::time::Duration;
 use serde::Deserialize;
 
+use crate::config::RepositoryDir;
+use crate::error::{ZatError, ZatResult};
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ArgType {
   MutlipleArgs(Vec<PluginArg>),
@@ -45,37 +50,109 @@ impl <'de> Deserialize<'de> for ArgType {
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Plugin {
   pub id: String,
+
+  /// Arguments to the plugin. Tokens of answered variables, such as $project__snake$, are replaced before it is run.
   pub args: Option<ArgType>,
 
+  /// Variables and choices that are answered before the plugin is run. Plugins without dependencies are run before
+  /// any variables are prompted for.
+  #[serde(default)]
+  pub depends_on: Vec<String>,
+
+  /// How long to wait for the plugin to finish before stopping it. Defaults to DEFAULT_TIMEOUT_MS.
+  pub timeout_ms: Option<u64>,
+
+  /// How long a result of the plugin is reused for, instead of running the plugin again. Results are only cached when
+  /// this is set.
+  pub cache_ttl_secs: Option<u64>,
+
+  /// Whether the variable's default value is used when the plugin times out, instead of failing.
+  #[serde(default)]
+  pub optional: bool,
+
+  /// Whether the plugin provides the default value of its variable or the choices to select from.
+  #[serde(default)]
+  pub provides: PluginProvides,
+
   #[serde(default)]
   pub result: PluginRunStatus,
+
+  /// The repository that defines the plugin, whose plugins directory the plugin is looked up in. Set when the variables
+  /// of the repository are loaded.
+  #[serde(skip)]
+  pub repository_dir: RepositoryDir,
 }
 
 impl Plugin {
 
+  pub const DEFAULT_TIMEOUT_MS: u64 = 30_000;
+
+  pub fn timeout(&self) -> Duration {
+    Duration::from_millis(self.timeout_ms.unwrap_or(Self::DEFAULT_TIMEOUT_MS))
+  }
+
+  pub fn cache_ttl(&self) -> Option<Duration> {
+    self.cache_ttl_secs.map(Duration::from_secs)
+  }
+
+  /// The plugin as defined by a repository. Ids that are absolute or contain '..' are not allowed, so that a bundled
+  /// plugin can't be outside of the plugins directory of the repository.
+  pub fn defined_in(self, repository_dir: &RepositoryDir) -> ZatResult<Self> {
+    let is_within_plugins_dir =
+      Path::new(&self.id)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+
+    if is_within_plugins_dir {
+      Ok(
+        Self {
+          repository_dir: repository_dir.clone(),
+          ..self
+        }
+      )
+    } else {
+      Err(ZatError::plugin_id_is_outside_plugins_dir(&self.id, repository_dir.path()))
+    }
+  }
+
+  /// The path the plugin is run from.
+  pub fn path(&self) -> String {
+    self.repository_dir.resolve_plugin(&self.id)
+  }
+
   #[cfg(test)]
   pub fn new(id: &str, args: &[&str]) -> Self {
     let args = args.iter().map(|i| (*i).to_owned()).collect::<Vec<String>>();
     Self {
       id: id.to_owned(),
       args: Some(ArgType::ArgLine(args)),
-      result: PluginRunStatus::default()
+      depends_on: vec![],
+      timeout_ms: None,
+      cache_ttl_secs: None,
+      optional: false,
+      provides: PluginProvides::Value,
+      result: PluginRunStatus::default(),
+      repository_dir: Default::default()
     }
   }
 }
 
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
-pub enum PluginRunStatus {
-  NotRun,
-  Run(PluginRunResult)
+/// What a plugin provides to its variable. Plugins that provide choices are run before any choices are prompted for.
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PluginProvides {
+  #[default]
+  Value,
+  Choices,
 }
 
 
-impl Default for PluginRunStatus {
-  fn default() -> Self {
-      Self::NotRun
-  }
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+pub enum PluginRunStatus {
+  #[default]
+  NotRun,
+  Run(PluginRunResult)
 }
 
 
@@ -166,7 +243,13 @@ mod tests {
       Plugin {
         id: "scala-deps".to_owned(),
         args: Some(args),
-        result: PluginRunStatus::NotRun
+        depends_on: vec![],
+        timeout_ms: None,
+        cache_ttl_secs: None,
+        optional: false,
+        provides: PluginProvides::Value,
+        result: PluginRunStatus::NotRun,
+        repository_dir: Default::default()
     };
 
     assert_eq!(plugin, expected_plugin);
@@ -217,7 +300,13 @@ mod tests {
       Plugin {
         id: "scala-deps".to_owned(),
         args: Some(args),
-        result: PluginRunStatus::NotRun
+        depends_on: vec![],
+        timeout_ms: None,
+        cache_ttl_secs: None,
+        optional: false,
+        provides: PluginProvides::Value,
+        result: PluginRunStatus::NotRun,
+        repository_dir: Default::default()
     };
 
     assert_eq!(plugin, expected_plugin);
@@ -241,7 +330,13 @@ mod tests {
       Plugin {
         id: "scala-deps".to_owned(),
         args: None,
-        result: PluginRunStatus::NotRun
+        depends_on: vec![],
+        timeout_ms: None,
+        cache_ttl_secs: None,
+        optional: false,
+        provides: PluginProvides::Value,
+        result: PluginRunStatus::NotRun,
+        repository_dir: Default::default()
     };
 
     assert_eq!(plugin_result, expected_plugin)
@@ -287,6 +382,32 @@ mod tests {
     assert!(predicate.eval(plugin_result.unwrap_err().as_str()))
   }
 
+  #[test]
+  fn bundled_plugins_are_resolved_in_the_repository_that_defines_them() {
+    let root = tempfile::TempDir::new().unwrap();
+
+    for repository in ["base", "rust-cli"] {
+      std::fs::create_dir_all(root.path().join(repository).join(crate::config::PLUGINS_DIR)).unwrap();
+    }
+
+    std::fs::write(root.path().join("base").join(crate::config::PLUGINS_DIR).join("author.sh"), "#!/bin/sh").unwrap();
+
+    let base = RepositoryDir::from(root.path().join("base").as_path());
+    let rust_cli = RepositoryDir::from(root.path().join("rust-cli").as_path());
+
+    assert_eq!(Plugin::new("author.sh", &[]).defined_in(&base).unwrap().path(), base.plugins_dir().join("author.sh").to_string_lossy());
+    assert_eq!(Plugin::new("author.sh", &[]).defined_in(&rust_cli).unwrap().path(), "author.sh")
+  }
+
+  #[test]
+  fn plugin_ids_outside_the_plugins_dir_are_not_allowed() {
+    let repository_dir = RepositoryDir::new("rust-cli");
+
+    assert_eq!(Plugin::new("../../bin/author.sh", &[]).defined_in(&repository_dir), Err(ZatError::plugin_id_is_outside_plugins_dir("../../bin/author.sh", "rust-cli")));
+    assert_eq!(Plugin::new("/bin/author.sh", &[]).defined_in(&repository_dir), Err(ZatError::plugin_id_is_outside_plugins_dir("/bin/author.sh", "rust-cli")));
+    assert!(Plugin::new("tools/author.sh", &[]).defined_in(&repository_dir).is_ok())
+  }
+
 
   fn plugin_arg(name: &str, value: &str, prefix: &str) -> PluginArg {
     PluginArg {
@@ -0,0 +1,327 @@
+use jsonschema::error::ValidationErrorKind;
+use jsonschema::{ValidationError, Validator};
+use serde_json::Value;
+use std::format as s;
+
+/// The schema for variable files. This is also published for editors to use, so keep it in sync with TemplateVariable.
+const VARIABLE_FILE_SCHEMA: &str = include_str!("../../dot-variables.schema.json");
+
+/// A part of a variable file that does not match the schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaViolation {
+  /// JSON pointer to the invalid value. Eg. /0/scopes/1
+  pub pointer: String,
+
+  /// Line and column of the invalid value, when it can be found.
+  pub position: Option<(usize, usize)>,
+
+  pub message: String,
+
+  pub fix: String,
+}
+
+impl SchemaViolation {
+
+  pub fn location(&self) -> String {
+    let pointer = if self.pointer.is_empty() { "/" } else { self.pointer.as_str() };
+
+    match self.position {
+      Some((line, column)) => s!("{} (line {}, column {})", pointer, line, column),
+      None => pointer.to_owned(),
+    }
+  }
+}
+
+/// Validates variables against the variable file schema, returning every violation found.
+///
+/// `pointer_prefix` is added to each pointer to locate the variables within their file. `json_content` is used to find
+/// the line and column of each violation for JSON variable files.
+pub fn validate_variables(variables: &Value, pointer_prefix: &str, json_content: Option<&str>) -> Vec<SchemaViolation> {
+  let schema: Value = serde_json::from_str(VARIABLE_FILE_SCHEMA).expect("Invalid variable file schema");
+  let validator: Validator = jsonschema::validator_for(&schema).expect("Could not compile variable file schema");
+
+  validator
+    .iter_errors(variables)
+    .map(|error| {
+      let pointer = s!("{}{}", pointer_prefix, error.instance_path().as_str());
+      let position = json_content.and_then(|content| json_pointer_position(content, &pointer));
+      let message = violation_message(&error);
+      let fix = violation_fix(&error, &pointer);
+
+      SchemaViolation {
+        pointer,
+        position,
+        message,
+        fix
+      }
+    })
+    .collect()
+}
+
+fn violation_message(error: &ValidationError) -> String {
+  match error.kind() {
+    ValidationErrorKind::Required { property } => s!("The required field {} is missing", property),
+    ValidationErrorKind::AnyOf { .. } | ValidationErrorKind::OneOfNotValid { .. } => "The value does not match any of the supported formats".to_owned(),
+    ValidationErrorKind::OneOfMultipleValid { .. } => "The value matches more than one of the supported formats".to_owned(),
+    _ => error.to_string(),
+  }
+}
+
+fn violation_fix(error: &ValidationError, pointer: &str) -> String {
+  match error.kind() {
+    ValidationErrorKind::Required { property } => s!("Add the {} field.", property),
+    ValidationErrorKind::Enum { options } => s!("Use one of: {}.", options),
+    ValidationErrorKind::Type { .. } => "Change the value to the type in the error.".to_owned(),
+    ValidationErrorKind::MinLength { .. } => "Supply a non-empty value.".to_owned(),
+    ValidationErrorKind::MinItems { .. } => "Supply at least one item or remove the field.".to_owned(),
+    ValidationErrorKind::AnyOf { .. } | ValidationErrorKind::OneOfNotValid { .. } | ValidationErrorKind::OneOfMultipleValid { .. } => {
+      if pointer.contains("/scopes/") {
+        "A scope needs a 'choice' field, optionally with either a 'value' or 'not_value' field, or a 'not_choice' field.".to_owned()
+      } else if pointer.ends_with("/args") {
+        "'args' should be a non-empty list of strings or a non-empty list of objects with 'name', 'value' and 'prefix' fields.".to_owned()
+      } else {
+        "Change the value to one of the formats in the variable file schema.".to_owned()
+      }
+    },
+    _ => "Change the value to the format in the variable file schema.".to_owned(),
+  }
+}
+
+
+/// Finds the 1-based line and column of the value at a JSON pointer within JSON content.
+fn json_pointer_position(content: &str, pointer: &str) -> Option<(usize, usize)> {
+  let mut scanner = JsonScanner { bytes: content.as_bytes(), position: 0 };
+  scanner.skip_whitespace();
+
+  for segment in pointer.split('/').skip(1) {
+    let segment = segment.replace("~1", "/").replace("~0", "~");
+
+    match scanner.peek()? {
+      b'{' => scanner.find_member(&segment)?,
+      b'[' => scanner.find_element(segment.parse().ok()?)?,
+      _ => return None
+    }
+  }
+
+  let preceding = content.get(..scanner.position)?;
+  let line = preceding.matches('\n').count() + 1;
+  let column = preceding.rsplit('\n').next().map(|l| l.chars().count()).unwrap_or(0) + 1;
+
+  Some((line, column))
+}
+
+/// Just enough of a JSON scanner to walk to a value. The content is known to be valid JSON.
+struct JsonScanner<'a> {
+  bytes: &'a [u8],
+  position: usize
+}
+
+impl JsonScanner<'_> {
+
+  fn peek(&self) -> Option<u8> {
+    self.bytes.get(self.position).copied()
+  }
+
+  fn skip_whitespace(&mut self) {
+    while matches!(self.peek(), Some(b' ' | b'\t' | b'\r' | b'\n')) {
+      self.position += 1
+    }
+  }
+
+  fn expect(&mut self, byte: u8) -> Option<()> {
+    self.skip_whitespace();
+    if self.peek()? == byte {
+      self.position += 1;
+      Some(())
+    } else {
+      None
+    }
+  }
+
+  /// Moves to the value of the member named `name` in the object at the current position.
+  fn find_member(&mut self, name: &str) -> Option<()> {
+    self.expect(b'{')?;
+
+    loop {
+      self.skip_whitespace();
+      let key_start = self.position;
+      self.skip_string()?;
+      let key: String = serde_json::from_slice(&self.bytes[key_start..self.position]).ok()?;
+
+      self.expect(b':')?;
+      self.skip_whitespace();
+
+      if key == name {
+        return Some(())
+      }
+
+      self.skip_value()?;
+      self.expect(b',')?
+    }
+  }
+
+  /// Moves to the element at `index` in the array at the current position.
+  fn find_element(&mut self, index: usize) -> Option<()> {
+    self.expect(b'[')?;
+
+    for _ in 0..index {
+      self.skip_whitespace();
+      self.skip_value()?;
+      self.expect(b',')?
+    }
+
+    self.skip_whitespace();
+    Some(())
+  }
+
+  fn skip_string(&mut self) -> Option<()> {
+    if self.peek()? != b'"' {
+      return None
+    }
+
+    self.position += 1;
+
+    loop {
+      match self.peek()? {
+        b'\\' => self.position += 2,
+        b'"' => {
+          self.position += 1;
+          return Some(())
+        },
+        _ => self.position += 1
+      }
+    }
+  }
+
+  fn skip_value(&mut self) -> Option<()> {
+    match self.peek()? {
+      b'"' => self.skip_string(),
+      b'{' | b'[' => {
+        let mut depth = 0;
+
+        loop {
+          match self.peek()? {
+            b'"' => {
+              self.skip_string()?;
+              continue
+            },
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => depth -= 1,
+            _ => ()
+          }
+
+          self.position += 1;
+
+          if depth == 0 {
+            return Some(())
+          }
+        }
+      },
+      _ => {
+        while !matches!(self.peek(), None | Some(b',' | b'}' | b']' | b' ' | b'\t' | b'\r' | b'\n')) {
+          self.position += 1
+        }
+
+        Some(())
+      }
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  fn validate_json(content: &str) -> Vec<SchemaViolation> {
+    let variables: Value = serde_json::from_str(content).unwrap();
+    validate_variables(&variables, "", Some(content))
+  }
+
+  #[test]
+  fn valid_variables_have_no_violations() {
+    let content = r#"
+      [
+        {
+          "variable_name": "readme_type",
+          "description": "Type of README",
+          "prompt": "Please choose your type of README",
+          "choices": [
+            { "display": "Short", "description": "A shorter README", "value": "short" }
+          ]
+        },
+        {
+          "variable_name": "project",
+          "description": "Name of project",
+          "prompt": "Please enter your project name",
+          "default_value": null,
+          "filters": [ { "name": "__default__", "filter": "Pascal" } ],
+          "plugin": { "id": "scala-deps", "args": [ { "name": "o", "value": "org.scala-lang", "prefix": "-" } ] },
+          "scopes": [ { "choice": "readme_type", "value": "short" }, { "not_choice": "license" } ]
+        }
+      ]
+    "#;
+
+    assert_eq!(validate_json(content), vec![])
+  }
+
+  #[test]
+  fn reports_every_violation_with_its_position() {
+    let content = r#"[
+  {
+    "variable_name": "project",
+    "description": "Name of project",
+    "filters": [ { "name": "python", "filter": "Pythonic" } ]
+  },
+  {
+    "variable_name": "description",
+    "description": "What your project is about",
+    "prompt": "Please a description of your project",
+    "scopes": [ { "value": "short" } ]
+  }
+]"#;
+
+    let violations = validate_json(content);
+    let summary: Vec<(String, String)> = violations.iter().map(|v| (v.location(), v.fix.clone())).collect();
+
+    assert_eq!(
+      summary,
+      vec![
+        ("/0 (line 2, column 3)".to_owned(), "Add the \"prompt\" field.".to_owned()),
+        ("/0/filters/0/filter (line 5, column 48)".to_owned(), "Use one of: [\"Camel\",\"Cobol\",\"Flat\",\"Kebab\",\"Lower\",\"Noop\",\"Pascal\",\"Snake\",\"Title\",\"Upper\"].".to_owned()),
+        ("/1/scopes/0 (line 11, column 17)".to_owned(), "A scope needs a 'choice' field, optionally with either a 'value' or 'not_value' field, or a 'not_choice' field.".to_owned()),
+      ]
+    )
+  }
+
+  #[test]
+  fn reports_invalid_plugin_args() {
+    let content = r#"[{ "variable_name": "v", "description": "d", "prompt": "p", "plugin": { "id": "p", "args": [] } }]"#;
+    let violations = validate_json(content);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].location(), "/0/plugin/args (line 1, column 92)");
+    assert!(violations[0].fix.starts_with("'args' should be a non-empty list"))
+  }
+
+  #[test]
+  fn prefixes_pointers_without_positions() {
+    let variables: Value = serde_json::from_str(r#"[{ "variable_name": "v", "description": "d" }]"#).unwrap();
+    let violations = validate_variables(&variables, "/variables", None);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].location(), "/variables/0")
+  }
+
+  #[test]
+  fn finds_positions_of_json_pointers() {
+    let content = "{\n  \"a/b\": [1, {\"c\": \"x,]}\"}, 3],\n  \"d\": true\n}";
+
+    assert_eq!(json_pointer_position(content, ""), Some((1, 1)));
+    assert_eq!(json_pointer_position(content, "/a~1b/1/c"), Some((2, 20)));
+    assert_eq!(json_pointer_position(content, "/a~1b/2"), Some((2, 29)));
+    assert_eq!(json_pointer_position(content, "/d"), Some((3, 8)));
+    assert_eq!(json_pointer_position(content, "/e"), None)
+  }
+}
@@ -1,8 +1,10 @@
+use std::collections::BTreeMap;
 use serde::Deserialize;
 
+use crate::error::{ZatError, ZatResult};
 use crate::logging::Lines;
 use std::format as s;
-use super::{Choice, Plugin, Scope};
+use super::{Choice, Plugin, PluginProvides, Scope};
 use super::ArgType;
 
 #[derive(Debug, Clone, Deserialize, PartialEq, Default)]
@@ -16,6 +18,69 @@ impl TemplateVariables {
       tokens
     }
   }
+
+  /// Merges these variables over the variables of a parent repository. Variables with the same name replace the parent
+  /// variable in its position, and new variables are added after the parent variables. A replaced variable keeps the
+  /// filters of the parent variable, so the templates of the parent can still use them, unless a filter with the same
+  /// name is defined by the child.
+  pub fn inherit_from(self, parent: TemplateVariables) -> Self {
+    let mut tokens = parent.tokens;
+
+    for mut token in self.tokens {
+      match tokens.iter_mut().find(|parent_token| parent_token.variable_name == token.variable_name) {
+        Some(parent_token) => {
+          token.inherit_filters(&parent_token.filters);
+          *parent_token = token
+        },
+        None => tokens.push(token),
+      }
+    }
+
+    Self {
+      tokens
+    }
+  }
+
+  /// Adds the variables of another repository that is processed along with these variables. Variables with the same
+  /// name are shared, so the first definition is kept and the user is only prompted once. The filters of each definition
+  /// are kept, so that each repository can use the filters it defines. Filters with the same name must be the same.
+  pub fn compose_with(self, other: TemplateVariables) -> ZatResult<Self> {
+    let mut tokens = self.tokens;
+
+    for token in other.tokens {
+      match tokens.iter_mut().find(|existing_token| existing_token.variable_name == token.variable_name) {
+        Some(existing_token) => existing_token.add_filters(token.filters)?,
+        None => tokens.push(token),
+      }
+    }
+
+    Ok(
+      Self {
+        tokens
+      }
+    )
+  }
+
+  /// Sets the default value of each variable with a default in the user config file, replacing the default of the
+  /// template. These are set before any plugins are run, so values that plugins return for other variables replace them.
+  pub fn with_user_defaults(mut self, variable_defaults: &BTreeMap<String, String>) -> Self {
+    for token in self.tokens.iter_mut() {
+      if let Some(default_value) = variable_defaults.get(&token.variable_name) {
+        token.default_value = Some(default_value.clone())
+      }
+    }
+
+    self
+  }
+
+  /// Whether any variable has a plugin that provides its choices.
+  pub fn has_choice_plugins(&self) -> bool {
+    self
+      .tokens
+      .iter()
+      .filter_map(|token| token.plugin.as_ref())
+      .any(|plugin| plugin.provides == PluginProvides::Choices)
+  }
 }
 
 
@@ -141,6 +206,29 @@ pub struct TemplateVariable {
 
 impl TemplateVariable {
 
+  /// Adds the filters of a parent definition of this variable, unless a filter with the same name is already defined.
+  fn inherit_filters(&mut self, parent_filters: &[VariableFilter]) {
+    for filter in parent_filters {
+      if !self.filters.iter().any(|existing_filter| existing_filter.name == filter.name) {
+        self.filters.push(filter.clone())
+      }
+    }
+  }
+
+  /// Adds the filters of another definition of this variable that are not already defined.
+  fn add_filters(&mut self, filters: Vec<VariableFilter>) -> ZatResult<()> {
+    for filter in filters {
+      match self.filters.iter().find(|existing_filter| existing_filter.name == filter.name) {
+        Some(existing_filter) if existing_filter.filter != filter.filter =>
+          return Err(ZatError::variable_has_conflicting_filters(&self.variable_name, &filter.name, &s!("{:?}", existing_filter.filter), &s!("{:?}", filter.filter))),
+        Some(_) => (),
+        None => self.filters.push(filter),
+      }
+    }
+
+    Ok(())
+  }
+
   #[cfg(test)]
   pub fn new(variable_name: &str, description: &str, prompt: &str, filters: &[VariableFilter], default_value: Option<&str>) -> Self {
     Self {
@@ -421,4 +509,168 @@ mod test {
 
      assert_eq!(variables, expected_variables)
   }
+
+  #[test]
+  fn child_variables_override_parent_variables() {
+    let parent =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("project", "Name of project", "Please enter your project name", &[], None),
+          TemplateVariable::new("license", "License to use", "Please choose a license", &[], Some("MIT")),
+        ]
+      );
+
+    let child =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("description", "What your project is about", "Please enter a description", &[], None),
+          TemplateVariable::new("license", "License to use", "Please choose a license", &[], Some("Apache-2.0")),
+        ]
+      );
+
+    let expected =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("project", "Name of project", "Please enter your project name", &[], None),
+          TemplateVariable::new("license", "License to use", "Please choose a license", &[], Some("Apache-2.0")),
+          TemplateVariable::new("description", "What your project is about", "Please enter a description", &[], None),
+        ]
+      );
+
+    assert_eq!(child.inherit_from(parent), expected)
+  }
+
+  #[test]
+  fn child_variables_keep_the_filters_of_parent_variables() {
+    let parent =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("project", "Name of project", "Please enter your project name", &VariableFilter::from_pairs(&[("kebab", &FilterType::Kebab), ("name", &FilterType::Snake)]), None),
+        ]
+      );
+
+    let child =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("project", "Project", "Project?", &VariableFilter::from_pairs(&[("name", &FilterType::Pascal), ("title", &FilterType::Title)]), None),
+        ]
+      );
+
+    let expected =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new(
+            "project",
+            "Project",
+            "Project?",
+            &VariableFilter::from_pairs(&[("name", &FilterType::Pascal), ("title", &FilterType::Title), ("kebab", &FilterType::Kebab)]),
+            None
+          ),
+        ]
+      );
+
+    assert_eq!(child.inherit_from(parent), expected)
+  }
+
+  #[test]
+  fn user_defaults_replace_template_defaults() {
+    let variables =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("project", "Name of project", "Please enter your project name", &[], None),
+          TemplateVariable::new("author_email", "Email of author", "Please enter your email", &[], Some("you@example.com")),
+          TemplateVariable::new("license", "License to use", "Please choose a license", &[], Some("MIT")),
+        ]
+      );
+
+    let variable_defaults =
+      BTreeMap::from([
+        ("project".to_owned(), "my-app".to_owned()),
+        ("author_email".to_owned(), "me@example.com".to_owned()),
+      ]);
+
+    let expected =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("project", "Name of project", "Please enter your project name", &[], Some("my-app")),
+          TemplateVariable::new("author_email", "Email of author", "Please enter your email", &[], Some("me@example.com")),
+          TemplateVariable::new("license", "License to use", "Please choose a license", &[], Some("MIT")),
+        ]
+      );
+
+    assert_eq!(variables.with_user_defaults(&variable_defaults), expected)
+  }
+
+  #[test]
+  fn composed_variables_are_only_defined_once() {
+    let rust_cli =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("project", "Name of project", "Please enter your project name", &[], None),
+          TemplateVariable::new("binary", "Name of binary", "Please enter your binary name", &[], None),
+        ]
+      );
+
+    let with_docker =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("image", "Docker image", "Please enter your image name", &[], None),
+          TemplateVariable::new("project", "Project", "Project?", &[], None),
+        ]
+      );
+
+    let expected =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("project", "Name of project", "Please enter your project name", &[], None),
+          TemplateVariable::new("binary", "Name of binary", "Please enter your binary name", &[], None),
+          TemplateVariable::new("image", "Docker image", "Please enter your image name", &[], None),
+        ]
+      );
+
+    assert_eq!(rust_cli.compose_with(with_docker), Ok(expected))
+  }
+
+  #[test]
+  fn composed_variables_keep_the_filters_of_each_repository() {
+    let rust_cli =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("project", "Name of project", "Please enter your project name", &VariableFilter::from_pairs(&[("crate", &FilterType::Snake), ("title", &FilterType::Title)]), None),
+        ]
+      );
+
+    let with_docker =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new("project", "Project", "Project?", &VariableFilter::from_pairs(&[("image", &FilterType::Kebab), ("title", &FilterType::Title)]), None),
+        ]
+      );
+
+    let expected =
+      TemplateVariables::new(
+        vec![
+          TemplateVariable::new(
+            "project",
+            "Name of project",
+            "Please enter your project name",
+            &VariableFilter::from_pairs(&[("crate", &FilterType::Snake), ("title", &FilterType::Title), ("image", &FilterType::Kebab)]),
+            None
+          ),
+        ]
+      );
+
+    assert_eq!(rust_cli.compose_with(with_docker), Ok(expected))
+  }
+
+  #[test]
+  fn composed_variables_fail_on_conflicting_filters() {
+    let rust_cli = TemplateVariables::new(vec![TemplateVariable::new("project", "Name of project", "Please enter your project name", &VariableFilter::from_pairs(&[("name", &FilterType::Snake)]), None)]);
+    let with_docker = TemplateVariables::new(vec![TemplateVariable::new("project", "Project", "Project?", &VariableFilter::from_pairs(&[("name", &FilterType::Kebab)]), None)]);
+
+    assert_eq!(
+      rust_cli.compose_with(with_docker),
+      Err(ZatError::variable_has_conflicting_filters("project", "name", "Snake", "Kebab"))
+    )
+  }
 }
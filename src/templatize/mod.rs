@@ -0,0 +1,5 @@
+pub mod text_replacement;
+pub mod project_templatizer;
+
+pub use text_replacement::TextReplacement;
+pub use project_templatizer::ProjectTemplatizer;
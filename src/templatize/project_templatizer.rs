@@ -0,0 +1,161 @@
+use std::collections::BTreeSet;
+use std::format as s;
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use serde::Serialize;
+
+use crate::token_expander::DEFAULT_FILTER;
+use super::text_replacement::{ReplacementVariant, TextReplacement};
+
+#[derive(Serialize)]
+struct VariableDefinition {
+  variable_name: String,
+  description: String,
+  prompt: String,
+  filters: Vec<FilterDefinition>,
+}
+
+#[derive(Serialize)]
+struct FilterDefinition {
+  name: String,
+  filter: String,
+}
+
+/// Replaces the text of each TextReplacement, and its variations, with tokens.
+pub struct ProjectTemplatizer {
+  replacements: Vec<TextReplacement>,
+  ahocorasick: AhoCorasick,
+
+  /// The replacement index and variant for each pattern in `ahocorasick`.
+  variants: Vec<(usize, ReplacementVariant)>,
+
+  /// The patterns that were replaced at least once. Only the filters of these are written to the variables file.
+  used_patterns: BTreeSet<usize>,
+}
+
+impl ProjectTemplatizer {
+
+  pub fn new(replacements: Vec<TextReplacement>) -> Self {
+    let variants: Vec<(usize, ReplacementVariant)> =
+      replacements
+        .iter()
+        .enumerate()
+        .flat_map(|(index, replacement)| replacement.variants().into_iter().map(move |variant| (index, variant)))
+        .collect();
+
+    let ahocorasick =
+      AhoCorasickBuilder::new()
+        .match_kind(MatchKind::LeftmostLongest)
+        .build(variants.iter().map(|(_, variant)| variant.value.as_str()));
+
+    Self {
+      replacements,
+      ahocorasick,
+      variants,
+      used_patterns: BTreeSet::new()
+    }
+  }
+
+  /// Returns the input with tokens replacing any text, or None if there was nothing to replace.
+  pub fn templatize(&mut self, input: &str) -> Option<String> {
+    let matches: Vec<usize> = self.ahocorasick.find_iter(input).map(|m| m.pattern()).collect();
+
+    if matches.is_empty() {
+      None
+    } else {
+      self.used_patterns.extend(matches);
+
+      let tokens: Vec<String> =
+        self
+          .variants
+          .iter()
+          .map(|(index, variant)| variant.token(&self.replacements[*index].variable_name))
+          .collect();
+
+      Some(self.ahocorasick.replace_all(input, &tokens))
+    }
+  }
+
+  /// The content of a variables file, with a variable for each TextReplacement. Each variable has a default filter
+  /// matching the case of the replaced text, and a filter for every variation that was replaced.
+  pub fn variables_file(&self) -> String {
+    let variables: Vec<VariableDefinition> =
+      self
+        .replacements
+        .iter()
+        .enumerate()
+        .map(|(index, replacement)| {
+          let filters: Vec<FilterDefinition> =
+            self
+              .variants
+              .iter()
+              .enumerate()
+              .filter(|(pattern, (replacement_index, variant))| {
+                *replacement_index == index && (variant.filter_name.is_none() || self.used_patterns.contains(pattern))
+              })
+              .map(|(_, (_, variant))| {
+                FilterDefinition {
+                  name: variant.filter_name.clone().unwrap_or_else(|| DEFAULT_FILTER.to_owned()),
+                  filter: s!("{:?}", variant.filter)
+                }
+              })
+              .collect();
+
+          VariableDefinition {
+            variable_name: replacement.variable_name.clone(),
+            description: s!("Replaces '{}' in the project", replacement.text),
+            prompt: s!("Please enter a value for {}", replacement.variable_name),
+            filters
+          }
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&variables).expect("Could not serialise the variables file")
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+  use serde_json::{json, Value};
+
+  fn templatizer(replacements: &[&str]) -> ProjectTemplatizer {
+    ProjectTemplatizer::new(replacements.iter().map(|r| TextReplacement::parse(r).unwrap()).collect())
+  }
+
+  #[test]
+  fn replaces_text_and_its_variations_with_tokens() {
+    let mut templatizer = templatizer(&["my-service=project", "acme=company"]);
+
+    assert_eq!(
+      templatizer.templatize("struct MyService; // my-service by Acme. See my_service.rs"),
+      Some("struct $project__pascal$; // $project$ by $company__pascal$. See $project__snake$.rs".to_owned())
+    );
+
+    assert_eq!(templatizer.templatize("nothing to see here"), None)
+  }
+
+  #[test]
+  fn writes_filters_for_replaced_variations() {
+    let mut templatizer = templatizer(&["my-service=project"]);
+    templatizer.templatize("MyService");
+
+    let variables: Value = serde_json::from_str(&templatizer.variables_file()).unwrap();
+
+    assert_eq!(
+      variables,
+      json!([
+        {
+          "variable_name": "project",
+          "description": "Replaces 'my-service' in the project",
+          "prompt": "Please enter a value for project",
+          "filters": [
+            { "name": "__default__", "filter": "Kebab" },
+            { "name": "pascal", "filter": "Pascal" }
+          ]
+        }
+      ])
+    )
+  }
+}
@@ -0,0 +1,149 @@
+use std::format as s;
+
+use crate::error::{ZatError, ZatResult};
+use crate::templates::FilterType;
+use crate::token_expander::{ConvertCaseFilterApplicator, FilterApplicator};
+
+/// The filters tried when finding variations of the text to replace. The first filter that doesn't change the text
+/// becomes the default filter of the variable. Cases with spaces or without separators, such as "my service", are left
+/// out as they match ordinary prose and identifiers.
+const FILTERS: [FilterType; 4] =
+  [
+    FilterType::Kebab,
+    FilterType::Snake,
+    FilterType::Camel,
+    FilterType::Pascal,
+  ];
+
+/// Text within a project to replace with a variable. Supplied as `my-service=project`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextReplacement {
+  pub text: String,
+  pub variable_name: String,
+}
+
+/// A variation of the text to replace, such as `MyService` for `my-service`, and the token that replaces it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplacementVariant {
+  pub value: String,
+  pub filter_name: Option<String>,
+  pub filter: FilterType,
+}
+
+impl ReplacementVariant {
+  pub fn token(&self, variable_name: &str) -> String {
+    match &self.filter_name {
+      Some(filter_name) => s!("${}__{}$", variable_name, filter_name),
+      None => s!("${}$", variable_name),
+    }
+  }
+}
+
+impl TextReplacement {
+
+  pub fn parse(replacement: &str) -> ZatResult<Self> {
+    match replacement.split_once('=') {
+      Some((text, variable_name)) if !text.is_empty() && Self::is_variable_name(variable_name) =>
+        Ok(
+          Self {
+            text: text.to_owned(),
+            variable_name: variable_name.to_owned()
+          }
+        ),
+      _ => Err(ZatError::invalid_templatize_replacement(replacement))
+    }
+  }
+
+  /// The text followed by its variations in other cases. The text itself is replaced with the default token of the
+  /// variable and each variation with a token for its filter.
+  pub fn variants(&self) -> Vec<ReplacementVariant> {
+    let default_filter =
+      FILTERS
+        .iter()
+        .find(|filter| ConvertCaseFilterApplicator.apply_filter(filter, &self.text) == self.text)
+        .cloned()
+        .unwrap_or(FilterType::Noop);
+
+    let mut variants =
+      vec![
+        ReplacementVariant {
+          value: self.text.clone(),
+          filter_name: None,
+          filter: default_filter
+        }
+      ];
+
+    for filter in FILTERS {
+      let value = ConvertCaseFilterApplicator.apply_filter(&filter, &self.text);
+
+      if !value.is_empty() && !variants.iter().any(|variant| variant.value == value) {
+        variants.push(
+          ReplacementVariant {
+            value,
+            filter_name: Some(s!("{:?}", filter).to_lowercase()),
+            filter
+          }
+        )
+      }
+    }
+
+    variants
+  }
+
+  fn is_variable_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn parses_replacements() {
+    assert_eq!(
+      TextReplacement::parse("my-service=project").unwrap(),
+      TextReplacement { text: "my-service".to_owned(), variable_name: "project".to_owned() }
+    );
+
+    assert!(TextReplacement::parse("my-service").is_err());
+    assert!(TextReplacement::parse("=project").is_err());
+    assert!(TextReplacement::parse("my-service=my project").is_err())
+  }
+
+  #[test]
+  fn finds_variants_in_other_cases() {
+    let replacement = TextReplacement::parse("my-service=project").unwrap();
+
+    let variants: Vec<(String, String)> =
+      replacement
+        .variants()
+        .iter()
+        .map(|variant| (variant.value.clone(), variant.token("project")))
+        .collect();
+
+    let expected: Vec<(String, String)> =
+      [
+        ("my-service", "$project$"),
+        ("my_service", "$project__snake$"),
+        ("myService", "$project__camel$"),
+        ("MyService", "$project__pascal$"),
+      ]
+      .iter()
+      .map(|(value, token)| (value.to_string(), token.to_string()))
+      .collect();
+
+    assert_eq!(variants, expected)
+  }
+
+  #[test]
+  fn uses_the_case_of_the_text_as_the_default_filter() {
+    let replacement = TextReplacement::parse("MyService=project").unwrap();
+    assert_eq!(replacement.variants()[0].filter, FilterType::Pascal);
+
+    let replacement = TextReplacement::parse("My_Service=project").unwrap();
+    assert_eq!(replacement.variants()[0].filter, FilterType::Noop)
+  }
+}
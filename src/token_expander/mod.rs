@@ -16,8 +16,8 @@ pub use template_variable_expander::{DEFAULT_FILTER, ExpandedKey, ExpandedValue,
 pub use filter_applicator::FilterApplicator;
 pub use expand_filters::ExpandFilters;
 pub use default_expand_filters::DefaultExpandFilters;
+pub use convert_case_filter_applicator::ConvertCaseFilterApplicator;
 
 // Module-private exports
 use default_key_tokenizer::DefaultKeyTokenizer;
 use default_template_variable_expander::DefaultTemplateVariableExpander;
-use convert_case_filter_applicator::ConvertCaseFilterApplicator;
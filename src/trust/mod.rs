@@ -0,0 +1,5 @@
+pub mod trust_store;
+pub mod remote_trust;
+
+pub use trust_store::TrustStoreFile;
+pub use remote_trust::RemoteTrust;
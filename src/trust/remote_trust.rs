@@ -0,0 +1,345 @@
+use std::fs;
+use std::io::BufRead;
+use std::format as s;
+use ansi_term::Style;
+use ansi_term::Colour::Yellow;
+
+use crate::config::{ConfigShellHookStatus, HookPhase, RepositoryOrigin, UserConfig, ZatConfigDir};
+use crate::error::{ZatAction, ZatError, ZatResult};
+use crate::logging::{Logger, Printer};
+use crate::plugin::DefaultPluginRunner;
+use crate::templates::TemplateVariables;
+use super::TrustStoreFile;
+
+/// The commands a repository would run on this machine.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ExecutionReview {
+  /// The command line of each plugin, along with the path of any plugin bundled in a repository.
+  pub plugins: Vec<String>,
+
+  /// The path and content of each shell hook.
+  pub shell_hooks: Vec<(String, String)>,
+
+  /// The phase and command line of each lifecycle hook, along with the path of any script bundled in the repository.
+  pub lifecycle_hooks: Vec<String>,
+
+  /// The command line of each post-processing action that runs a command.
+  pub action_commands: Vec<String>,
+}
+
+impl ExecutionReview {
+
+  /// Lists the plugins and shell hooks that would be run, leaving out any that are skipped with `--no-plugins` or
+  /// `--no-hooks`.
+  pub fn new(repository_configs: &[UserConfig], variables: &TemplateVariables) -> ZatResult<Self> {
+    let user_config = &repository_configs[0];
+
+    let plugins =
+      if user_config.no_plugins {
+        vec![]
+      } else {
+        variables
+          .tokens
+          .iter()
+          .filter_map(|variable| variable.plugin.as_ref())
+          .map(|plugin| Self::with_resolved_path(DefaultPluginRunner::generate_command_string(plugin), &plugin.id, &plugin.path()))
+          .collect()
+      };
+
+    let mut shell_hooks = vec![];
+    let mut lifecycle_hooks = vec![];
+    let mut action_commands = vec![];
+
+    if !user_config.no_hooks {
+      for repository_config in repository_configs {
+        if let ConfigShellHookStatus::RunShellHook(shell_hook) = &repository_config.shell_hook_status {
+          let content = fs::read_to_string(shell_hook).map_err(|e| ZatError::could_not_read_remote_shell_hook(shell_hook, e.to_string()))?;
+          shell_hooks.push((shell_hook.clone(), content))
+        }
+
+        for phase in HookPhase::ALL {
+          lifecycle_hooks.extend(
+            repository_config
+              .hooks
+              .phase(phase)
+              .iter()
+              .map(|hook| s!("{}: {}", phase, Self::with_resolved_path(hook.command_string(), &hook.command, &hook.resolve_command(&repository_config.repository_dir))))
+          )
+        }
+
+        action_commands.extend(repository_config.actions.iter().filter_map(|action| action.command_string()))
+      }
+    }
+
+    Ok(
+      Self {
+        plugins,
+        shell_hooks,
+        lifecycle_hooks,
+        action_commands
+      }
+    )
+  }
+
+  /// Bundled scripts are run from the repository, so their path is shown along with the command line.
+  fn with_resolved_path(command_line: String, command: &str, resolved_command: &str) -> String {
+    if command == resolved_command {
+      command_line
+    } else {
+      s!("{} ({})", command_line, resolved_command)
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.plugins.is_empty() && self.shell_hooks.is_empty() && self.lifecycle_hooks.is_empty() && self.action_commands.is_empty()
+  }
+
+  fn print(&self) {
+    if !self.plugins.is_empty() {
+      Printer::print_verbose_strings("Plugins to run", self.plugins.clone())
+    }
+
+    for (shell_hook, content) in &self.shell_hooks {
+      Printer::print_verbose_strings(&s!("Shell hook to run: {}", shell_hook), content.lines().map(|line| line.to_owned()).collect())
+    }
+
+    if !self.lifecycle_hooks.is_empty() {
+      Printer::print_verbose_strings("Lifecycle hooks to run", self.lifecycle_hooks.clone())
+    }
+
+    if !self.action_commands.is_empty() {
+      Printer::print_verbose_strings("Post-processing commands to run", self.action_commands.clone())
+    }
+  }
+}
+
+
+/// Asks the user to confirm the plugins and shell hooks of remote repositories and archives before they are run. Each
+/// commit or archive the user trusts is remembered, so they are only asked again when a repository changes.
+pub struct RemoteTrust;
+
+impl RemoteTrust {
+
+  /// The origins are those of every repository being processed and of their parents. Parents are checked out at
+  /// whichever commit their ref points to, so each remote commit in the chain needs to be trusted.
+  pub fn confirm(origins: &[RepositoryOrigin], repository_configs: &[UserConfig], variables: &TemplateVariables) -> ZatAction {
+    let trust_keys = Self::trust_keys(origins);
+
+    if trust_keys.is_empty() {
+      return Ok(())
+    }
+
+    let review = ExecutionReview::new(repository_configs, variables)?;
+
+    if review.is_empty() {
+      return Ok(())
+    }
+
+    let trust_store_file = TrustStoreFile::from(&ZatConfigDir::new()?);
+    let mut trust_store = trust_store_file.load()?;
+
+    let untrusted: Vec<String> =
+      trust_keys
+        .iter()
+        .filter(|(source, version)| !trust_store.is_trusted(source, version))
+        .map(|(source, version)| s!("'{}' at {}", source, version))
+        .collect();
+
+    if untrusted.is_empty() {
+      let trusted: Vec<String> = trust_keys.iter().map(|(source, version)| s!("'{}' at {}", source, version)).collect();
+      Logger::info(&s!("Running the plugins and shell hooks of {}, which you have trusted.", trusted.join(", ")));
+      return Ok(())
+    }
+
+    Logger::warn(&s!("The repositories {} will run the following commands on this machine:", untrusted.join(", ")));
+    review.print();
+
+    if Self::user_trusts_repository() {
+      for (source, version) in &trust_keys {
+        trust_store.trust(source, version)
+      }
+      trust_store_file.save(&trust_store)?;
+      Logger::info(&s!("Trusted {} in '{}'.", untrusted.join(", "), trust_store_file.path().to_string_lossy()));
+      Ok(())
+    } else {
+      Err(ZatError::remote_repository_not_trusted(&untrusted.join(", ")))
+    }
+  }
+
+  /// The remote repositories and archives among the origins that the user has not trusted.
+  pub fn untrusted(origins: &[RepositoryOrigin]) -> ZatResult<Vec<RepositoryOrigin>> {
+    if Self::trust_keys(origins).is_empty() {
+      return Ok(vec![])
+    }
+
+    let trust_store = TrustStoreFile::from(&ZatConfigDir::new()?).load()?;
+
+    let untrusted =
+      origins
+        .iter()
+        .filter(|origin| origin.trust_key().is_some_and(|(source, version)| !trust_store.is_trusted(&source, &version)))
+        .cloned()
+        .collect();
+
+    Ok(untrusted)
+  }
+
+  /// The source and version of each remote repository and archive, without duplicates. Local repositories are not
+  /// reviewed.
+  fn trust_keys(origins: &[RepositoryOrigin]) -> Vec<(String, String)> {
+    let mut trust_keys = vec![];
+
+    for trust_key in origins.iter().filter_map(RepositoryOrigin::trust_key) {
+      if !trust_keys.contains(&trust_key) {
+        trust_keys.push(trust_key)
+      }
+    }
+
+    trust_keys
+  }
+
+  fn user_trusts_repository() -> bool {
+    Logger::coloured(
+      &s!("{}{}{}",
+        Yellow.paint("Press "),
+        Style::new().bold().paint("y"),
+        Yellow.paint(" to trust this repository and run these commands, and any other key to stop.")
+      )
+    );
+
+    let mut user_response = String::new();
+    let stdin = std::io::stdin();
+    let mut handle = stdin.lock();
+    handle.read_line(&mut user_response).expect("Could not read from stdin"); // Unexpected, so throw
+
+    user_response.trim() == "y"
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::config::{ActionKind, LifecycleHook, PostProcessingAction, RepositoryDir, RepositoryHooks};
+  use crate::templates::{Plugin, TemplateVariable};
+  use pretty_assertions::assert_eq;
+
+  fn variables_with_plugin() -> TemplateVariables {
+    TemplateVariables::new(
+      vec![
+        TemplateVariable {
+          variable_name: "author".to_owned(),
+          description: "Author".to_owned(),
+          prompt: "Please enter the author".to_owned(),
+          filters: vec![],
+          default_value: None,
+          plugin: Some(Plugin::new("whoami", &["--short"])),
+          choices: vec![],
+          scopes: None,
+        }
+      ]
+    )
+  }
+
+  #[test]
+  fn reviews_plugins_and_shell_hooks() {
+    let repository_dir = tempfile::TempDir::new().unwrap();
+    let shell_hook = repository_dir.path().join("shell-hook.zat-exec");
+    fs::write(&shell_hook, "#!/bin/bash\necho hello").unwrap();
+
+    let shell_hook_path = shell_hook.to_string_lossy().to_string();
+    let user_config =
+      UserConfig {
+        shell_hook_status: ConfigShellHookStatus::RunShellHook(shell_hook_path.clone()),
+        hooks: RepositoryHooks {
+          pre_generation: vec![LifecycleHook { command: "hooks/check-cargo.sh".to_owned(), args: vec![], scopes: None }],
+          post_generation: vec![LifecycleHook { command: "git".to_owned(), args: vec!["init".to_owned()], scopes: None }],
+        },
+        actions: vec![
+          PostProcessingAction::new(ActionKind::GitInit),
+          PostProcessingAction::new(ActionKind::Run { command: "cargo".to_owned(), args: vec!["fmt".to_owned()], working_dir: None }),
+        ],
+        ..UserConfig::new(&repository_dir.path().to_string_lossy(), "")
+      };
+
+    let review = ExecutionReview::new(&[user_config], &variables_with_plugin()).unwrap();
+
+    assert_eq!(review.plugins, vec!["whoami --short"]);
+    assert_eq!(review.shell_hooks, vec![(shell_hook_path, "#!/bin/bash\necho hello".to_owned())]);
+    assert_eq!(review.lifecycle_hooks, vec!["pre-generation: hooks/check-cargo.sh", "post-generation: git init"]);
+    assert_eq!(review.action_commands, vec!["cargo fmt"])
+  }
+
+  #[test]
+  fn review_leaves_out_skipped_plugins_and_shell_hooks() {
+    let user_config =
+      UserConfig {
+        shell_hook_status: ConfigShellHookStatus::RunShellHook("/does/not/exist".to_owned()),
+        no_plugins: true,
+        no_hooks: true,
+        ..UserConfig::new("", "")
+      };
+
+    let review = ExecutionReview::new(&[user_config], &variables_with_plugin()).unwrap();
+
+    assert!(review.is_empty())
+  }
+
+  #[test]
+  fn local_repositories_are_not_reviewed() {
+    let user_config =
+      UserConfig {
+        shell_hook_status: ConfigShellHookStatus::RunShellHook("/does/not/exist".to_owned()),
+        ..UserConfig::new("", "")
+      };
+
+    assert_eq!(RemoteTrust::confirm(&[RepositoryOrigin::Local], &[user_config], &variables_with_plugin()), Ok(()))
+  }
+
+  #[test]
+  fn reviews_every_remote_repository_and_archive_in_the_chain() {
+    let origins = vec![
+      RepositoryOrigin::Local,
+      RepositoryOrigin::Remote { url: "https://github.com/org/base".to_owned(), commit: "abc123".to_owned() },
+      RepositoryOrigin::Archive { path: "/tmp/rust-cli.zip".to_owned(), sha256: "d4e5f6".to_owned() },
+      RepositoryOrigin::Remote { url: "https://github.com/org/base".to_owned(), commit: "abc123".to_owned() },
+    ];
+
+    assert_eq!(
+      RemoteTrust::trust_keys(&origins),
+      vec![
+        ("https://github.com/org/base".to_owned(), "abc123".to_owned()),
+        ("/tmp/rust-cli.zip".to_owned(), "sha256:d4e5f6".to_owned()),
+      ]
+    )
+  }
+
+  #[test]
+  fn reviews_the_path_of_bundled_plugins_and_hooks() {
+    let repository_dir = tempfile::TempDir::new().unwrap();
+    fs::create_dir_all(repository_dir.path().join("plugins")).unwrap();
+    fs::write(repository_dir.path().join("plugins").join("author.sh"), "#!/bin/bash").unwrap();
+    fs::create_dir_all(repository_dir.path().join("hooks")).unwrap();
+    fs::write(repository_dir.path().join("hooks").join("check-cargo.sh"), "#!/bin/bash").unwrap();
+
+    let repository = RepositoryDir::new(&repository_dir.path().to_string_lossy());
+    let user_config =
+      UserConfig {
+        hooks: RepositoryHooks {
+          pre_generation: vec![LifecycleHook { command: "hooks/check-cargo.sh".to_owned(), args: vec![], scopes: None }],
+          post_generation: vec![],
+        },
+        ..UserConfig::new(repository.path(), "")
+      };
+
+    let mut variables = variables_with_plugin();
+    variables.tokens[0].plugin = Some(Plugin::new("author.sh", &["--short"]).defined_in(&repository).unwrap());
+
+    let review = ExecutionReview::new(&[user_config], &variables).unwrap();
+
+    let plugin_path = repository_dir.path().join("plugins").join("author.sh");
+    let hook_path = repository_dir.path().join("hooks").join("check-cargo.sh");
+    assert_eq!(review.plugins, vec![s!("author.sh --short ({})", plugin_path.to_string_lossy())]);
+    assert_eq!(review.lifecycle_hooks, vec![s!("pre-generation: hooks/check-cargo.sh ({})", hook_path.to_string_lossy())])
+  }
+}
@@ -0,0 +1,131 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::config::ZatConfigDir;
+use crate::error::{ZatAction, ZatError, ZatResult};
+
+pub const TRUST_STORE_FILE: &str = "trusted-repositories.toml";
+
+/// Remote repositories the user has trusted to run plugins and shell hooks, at a specific commit. Stored as TOML:
+///
+/// [[trusted]]
+/// url = "https://github.com/org/rust-cli-template"
+/// commit = "3f2c0e1d9a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d"
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct TrustStore {
+  #[serde(default)]
+  pub trusted: Vec<TrustedRepository>
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrustedRepository {
+  pub url: String,
+  pub commit: String,
+}
+
+impl TrustStore {
+
+  pub fn is_trusted(&self, url: &str, commit: &str) -> bool {
+    self
+      .trusted
+      .iter()
+      .any(|trusted| trusted.url == url && trusted.commit == commit)
+  }
+
+  pub fn trust(&mut self, url: &str, commit: &str) {
+    if !self.is_trusted(url, commit) {
+      self.trusted.push(
+        TrustedRepository {
+          url: url.to_owned(),
+          commit: commit.to_owned()
+        }
+      )
+    }
+  }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrustStoreFile {
+  path: PathBuf
+}
+
+impl TrustStoreFile {
+
+  pub fn path(&self) -> &Path {
+    self.path.as_path()
+  }
+
+  /// Returns an empty trust store if the trust store file does not exist.
+  pub fn load(&self) -> ZatResult<TrustStore> {
+    let path = self.path.to_string_lossy().to_string();
+
+    if self.path.exists() {
+      let content = fs::read_to_string(&self.path).map_err(|e| ZatError::could_not_read_zat_config_file(&path, e.to_string()))?;
+      toml::from_str(&content).map_err(|e| ZatError::could_not_decode_zat_config_file(&path, e.to_string()))
+    } else {
+      Ok(TrustStore::default())
+    }
+  }
+
+  pub fn save(&self, trust_store: &TrustStore) -> ZatAction {
+    let path = self.path.to_string_lossy().to_string();
+    let content = toml::to_string_pretty(trust_store).map_err(|e| ZatError::could_not_write_zat_config_file(&path, e.to_string()))?;
+
+    if let Some(parent) = self.path.parent() {
+      fs::create_dir_all(parent).map_err(|e| ZatError::could_not_write_zat_config_file(&path, e.to_string()))?;
+    }
+
+    fs::write(&self.path, content).map_err(|e| ZatError::could_not_write_zat_config_file(&path, e.to_string()))
+  }
+}
+
+impl From<&ZatConfigDir> for TrustStoreFile {
+  fn from(config_dir: &ZatConfigDir) -> Self {
+    Self {
+      path: config_dir.join(TRUST_STORE_FILE)
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+  use tempfile::TempDir;
+
+  #[test]
+  fn loads_an_empty_trust_store_if_the_file_does_not_exist() {
+    let config_dir = TempDir::new().unwrap();
+    let trust_store_file = TrustStoreFile::from(&ZatConfigDir::from(config_dir.path()));
+
+    assert_eq!(trust_store_file.load(), Ok(TrustStore::default()))
+  }
+
+  #[test]
+  fn saves_and_loads_trusted_repositories() {
+    let config_dir = TempDir::new().unwrap();
+    let trust_store_file = TrustStoreFile::from(&ZatConfigDir::from(config_dir.path().join("nested").as_path()));
+
+    let mut trust_store = TrustStore::default();
+    trust_store.trust("https://github.com/org/rust-cli", "abc123");
+    trust_store.trust("https://github.com/org/rust-cli", "abc123");
+
+    trust_store_file.save(&trust_store).unwrap();
+
+    assert_eq!(trust_store_file.load(), Ok(trust_store.clone()));
+    assert_eq!(trust_store.trusted.len(), 1)
+  }
+
+  #[test]
+  fn only_trusts_the_commit_that_was_trusted() {
+    let mut trust_store = TrustStore::default();
+    trust_store.trust("https://github.com/org/rust-cli", "abc123");
+
+    assert!(trust_store.is_trusted("https://github.com/org/rust-cli", "abc123"));
+    assert!(!trust_store.is_trusted("https://github.com/org/rust-cli", "def456"));
+    assert!(!trust_store.is_trusted("https://github.com/org/scala", "abc123"))
+  }
+}
@@ -1,6 +1,7 @@
 use crate::args::{ArgSupplier, DefaultUserConfigProvider, ZatCommand, UserConfigProvider};
 use crate::args::cli_arg_supplier::CliArgSupplier;
-use crate::command::{BootstrapProject, ProcessTemplates, ProcessRemoteTemplates};
+use crate::command::{BootstrapProject, ProcessTemplates, ProcessRemoteTemplates, ProcessArchiveTemplates, GenerateTemplates, TemplatizeProject, TestTemplates, DescribeTemplate, ValidateTemplate, ManageTemplates, ShowConfig};
+use crate::registry::TemplateResolver;
 use crate::error::ZatAction;
 
 
@@ -25,7 +26,40 @@ impl Workflow {
       },
 
       ZatCommand::ProcessRemote(process_remote_template_args) => {
-        ProcessRemoteTemplates::process_remote(config_provider, process_remote_template_args)
+        let remote_repository = TemplateResolver::resolve(&process_remote_template_args)?;
+        ProcessRemoteTemplates::process_remote(config_provider, remote_repository, process_remote_template_args)
+      },
+
+      ZatCommand::ProcessArchive(process_archive_template_args) => {
+        ProcessArchiveTemplates::process_archive(config_provider, process_archive_template_args)
+      },
+
+      ZatCommand::Generate(generate_args) => {
+        GenerateTemplates::process_generator(config_provider, generate_args)
+      },
+
+      ZatCommand::Templatize(templatize_args) => {
+        TemplatizeProject::process_templatize(templatize_args)
+      },
+
+      ZatCommand::Test(test_templates_args) => {
+        TestTemplates::process_tests(test_templates_args)
+      },
+
+      ZatCommand::Describe(describe_args) => {
+        DescribeTemplate::process_describe(describe_args)
+      },
+
+      ZatCommand::Validate(validate_args) => {
+        ValidateTemplate::process_validate(validate_args)
+      },
+
+      ZatCommand::Templates(templates_args) => {
+        ManageTemplates::process_templates(templates_args)
+      },
+
+      ZatCommand::Config(config_args) => {
+        ShowConfig::process_config(config_args)
       },
     }
   }
@@ -61,10 +61,24 @@ fn error_message_on_non_json_variables_file() -> Result<(), Box<dyn std::error::
   let test_directory = "non-json-variables-file";
   let source_directory = get_source_directory(test_directory);
 
+  let expected_lines = [
+    s!("Variable file '{}/.variables.zat-prompt' does not match the format required by Zat. It has the following problems:", source_directory),
+    "- / (line 1, column 1): 123 is not of type \"array\"".to_owned(),
+    "- / (line 1, column 1): Change the value to the type in the error.".to_owned(),
+  ];
+
+  run_error_lines_test(test_directory, &expected_lines)
+}
+
+#[test]
+fn error_message_on_invalid_json_variables_file() -> Result<(), Box<dyn std::error::Error>> {
+  let test_directory = "invalid-json-variables-file";
+  let source_directory = get_source_directory(test_directory);
+
   let error_parts =
     ErrorParts::new(
       "Got an error processing variables".to_owned(),
-      s!("Variable file '{}/.variables.zat-prompt' could not be decoded as JSON into the expected format. It failed decoding with this error: invalid type: integer `123`, expected a sequence at line 1 column 3. Zat uses this file to retrieve tokens that will be replaced when rendering the templates.", source_directory),
+      s!("Variable file '{}/.variables.zat-prompt' could not be decoded as JSON into the expected format. It failed decoding with this error: expected value at line 1 column 1. Zat uses this file to retrieve tokens that will be replaced when rendering the templates.", source_directory),
       s!("Make the variable file '{}/.variables.zat-prompt' is a valid JSON file in the format required by Zat. See `zat --help` for more details on the format", source_directory),
     );
 
@@ -72,6 +86,22 @@ fn error_message_on_non_json_variables_file() -> Result<(), Box<dyn std::error::
   run_error_test(error_test_config)
 }
 
+#[test]
+fn error_message_on_variables_file_not_matching_schema() -> Result<(), Box<dyn std::error::Error>> {
+  let test_directory = "variables-file-not-matching-schema";
+  let source_directory = get_source_directory(test_directory);
+
+  let expected_lines = [
+    s!("Variable file '{}/.variables.zat-prompt' does not match the format required by Zat. It has the following problems:", source_directory),
+    "- /0 (line 2, column 3): The required field \"prompt\" is missing".to_owned(),
+    "- /1/scopes/0 (line 11, column 17): The value does not match any of the supported formats".to_owned(),
+    "- /0 (line 2, column 3): Add the \"prompt\" field.".to_owned(),
+    "- /1/scopes/0 (line 11, column 17): A scope needs a 'choice' field, optionally with either a 'value' or 'not_value' field, or a 'not_choice' field.".to_owned(),
+  ];
+
+  run_error_lines_test(test_directory, &expected_lines)
+}
+
 #[test]
 fn error_message_on_no_template_files() -> Result<(), Box<dyn std::error::Error>> {
   let test_directory = "no-template-files";
@@ -112,6 +142,23 @@ fn error_message_on_target_dir_exists() -> Result<(), Box<dyn std::error::Error>
 }
 
 
+#[test]
+fn error_message_on_zat_version_too_old() -> Result<(), Box<dyn std::error::Error>> {
+  let test_directory = "zat-version-too-old";
+  let version = env!("CARGO_PKG_VERSION");
+
+  let error_parts =
+    ErrorParts::new(
+      "Got an error reading the repository manifest".to_owned(),
+      s!("The template 'from-the-future' requires Zat version '999.0.0' or later, but this is Zat version '{}'.", version),
+      "Please upgrade Zat to version '999.0.0' or later.".to_owned()
+    );
+
+  let error_test_config = ErrorTestConfig::run_template_without_input(test_directory, error_parts);
+
+  run_error_test(error_test_config)
+}
+
 #[test]
 fn error_message_on_no_variables_defined() -> Result<(), Box<dyn std::error::Error>> {
   let test_directory = "no-variables-defined";
@@ -159,11 +206,13 @@ fn error_message_on_shell_hook_returning_non_zero_result() -> Result<(), Box<dyn
   // delete temp dir so we can use it as the target dir (which should not exist)
   drop(temp_dir);
 
+  // The last lines of stderr are shown, and all of the output is written to a log file in the target directory
   let error_parts =
-    ErrorParts::new(
+    ErrorParts::with_exception(
       "There was an error running the post processor".to_owned(),
       s!("Shell hook '{}/shell-hook.zat-exec {}' failed with status code 1. The shell hook failed with a non-zero error code signifying an error.", source_directory, target_dir),
-      s!("Please check the logs above for why the shell hook failed. Try running the shell hook file '{}/shell-hook.zat-exec' with argument '{}' manually to iterate on the error.", source_directory, target_dir),
+      "cargo was not found".to_owned(),
+      s!("Please check the output of the shell hook in '{}/zat-hook-failure.log' for why it failed. Try running the shell hook file '{}/shell-hook.zat-exec' with argument '{}' manually to iterate on the error.", target_dir, source_directory, target_dir),
     );
 
 
@@ -192,6 +241,25 @@ fn error_message_plugin_failure() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 
+#[test]
+fn error_message_plugin_timeout() -> Result<(), Box<dyn std::error::Error>> {
+  let test_directory = "plugin-timeout";
+
+  let error_parts =
+    ErrorParts {
+      error_type: AssertionType::Equals("There was an error running a plugin".to_owned()),
+      // The elapsed time varies from run to run
+      error: AssertionType::Contains("Plugin 'tests/plugins/hangs.sh' returned the following error: The plugin did not finish within its timeout of 200ms and was stopped after".to_owned()),
+      maybe_exception: None,
+      fix: AssertionType::Equals("Try running the plugin manually to see why it does not finish. Increase its 'timeout_ms' if it needs longer, or mark it as 'optional' to use the variable's default value instead.".to_owned()),
+    };
+
+  let error_test_config = ErrorTestConfig::source_no_input_directory_not_exists(test_directory, error_parts);
+
+  run_error_test(error_test_config)
+}
+
+
 #[test]
 fn error_message_incorrect_plugin_invocation() -> Result<(), Box<dyn std::error::Error>> {
   let test_directory = "plugin-invocation-failure";
@@ -270,7 +338,7 @@ fn error_message_plugin_does_not_exist() -> Result<(), Box<dyn std::error::Error
   let error_parts =
     ErrorParts::with_exception(
       "There was an error running a plugin".to_owned(),
-      "Plugin 'tests/plugins/a-failure.sh one two' returned the following error: Plugin could not be run. Does it exist and is it executable?".to_owned(),
+      "Plugin 'tests/plugins/a-failure.sh one two' returned the following error: Plugin could not be run from 'tests/plugins/a-failure.sh'. Does it exist and is it executable?".to_owned(),
       "No such file or directory (os error 2)".to_owned(),
       "Try running the plugin manually to fix the above error.".to_owned(),
     );
@@ -369,6 +437,41 @@ fn error_message_on_git_clone_status_failure() -> Result<(), Box<dyn std::error:
   run_remote_error_test(process_remote_config)
 }
 
+#[test]
+fn error_message_on_template_not_found_in_registry() -> Result<(), Box<dyn std::error::Error>> {
+  let config_directory = tempdir()?;
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.path().join("errors-remote-template-not-found");
+  let registry_file = config_directory.path().join("templates.toml");
+
+  let error_parts =
+    ErrorParts::new(
+      "There was an error with the Zat configuration".to_owned(),
+      s!("The template 'does-not-exist' could not be found in the template registry '{}'.", registry_file.to_string_lossy()),
+      "Please add the template with `zat templates add --name does-not-exist --url <REPOSITORY_URL>` or use `zat templates list` to see the registered templates.".to_owned()
+    );
+
+  let std_err_contains =
+    predicate::function(move |out: &[u8]| {
+      let output = std::str::from_utf8(out).expect("Could not convert stdout to string");
+      let lines: Vec<&str> = output.split('\n').collect();
+      assert_error_message(&lines, error_parts.clone())
+    });
+
+  Command::cargo_bin("zat")?
+    .env("ZAT_CONFIG_DIR", config_directory.path())
+    .arg("process-remote")
+    .arg("--template")
+    .arg("does-not-exist")
+    .arg("--target-dir")
+    .arg(&target_directory)
+    .assert()
+    .failure()
+    .stderr(std_err_contains);
+
+  Ok(())
+}
+
 
 #[test]
 fn error_message_on_tera_template_rendering_failure() -> Result<(), Box<dyn std::error::Error>> {
@@ -396,8 +499,6 @@ fn error_message_on_tera_template_rendering_failure() -> Result<(), Box<dyn std:
 #[derive(Clone)]
 enum AssertionType {
   Equals(String),
-
-  #[allow(dead_code)]
   Contains(String),
 }
 
@@ -512,7 +613,6 @@ impl <'a> ErrorTestConfig<'a> {
   }
 
   /// Source error test, with input and with a specific a target directory, which may or may not exist.
-
   #[allow(dead_code)]
   fn source_with_input_and_target_directory(test_directory: &'a str, input: &'a[&'a str], target_directory: &'a str, target_directory_should_exist: bool, error_parts: ErrorParts) -> Self {
     let maybe_input = Some(input);
@@ -598,6 +698,36 @@ fn get_source_directory(test_directory: &str) -> String {
   source_directory.to_string_lossy().to_string()
 }
 
+/// Runs an error test for errors that span several lines, asserting each expected line is in stderr.
+fn run_error_lines_test(test_directory: &str, expected_lines: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+  let source_directory = get_source_directory(test_directory);
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.path().join(s!("errors-{}", test_directory));
+
+  let output =
+    Command::cargo_bin("zat")?
+      .arg("process")
+      .arg("--repository-dir")
+      .arg(&source_directory)
+      .arg("--target-dir")
+      .arg(&target_directory)
+      .assert()
+      .failure()
+      .get_output()
+      .clone();
+
+  let stderr = String::from_utf8(output.stderr)?;
+  let missing_lines: Vec<&String> =
+    expected_lines
+      .iter()
+      .filter(|expected| !stderr.lines().any(|line| line.trim() == expected.as_str()))
+      .collect();
+
+  assert!(missing_lines.is_empty(), "Could not find lines: {:?}\nin:\n{}", missing_lines, stderr);
+
+  Ok(())
+}
+
 fn run_error_test(error_config: ErrorTestConfig<'_>) -> Result<(), Box<dyn std::error::Error>> {
   let mut cmd = Command::cargo_bin("zat").unwrap();
   let working_directory = tempdir()?;
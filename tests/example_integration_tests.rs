@@ -74,6 +74,249 @@ fn runs_a_simple_template_with_shell_hook() -> Result<(), Box<dyn std::error::Er
 }
 
 
+#[test]
+fn runs_a_simple_template_with_manifest() -> Result<(), Box<dyn std::error::Error>> {
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.into_path().join("example-simple-with-manifest");
+
+  let manifest_assertions = ["Processing template: simple (1.0.0)", "A simple template with a manifest", "Authors: ssanj", "Tags: example"];
+
+  let example_test_config =
+    ExampleTestConfig::with_expected_output_and_files(
+      "simple-with-manifest",
+      &["YouOnlyLiveOnce", "", "y"],
+      AssertionType::Contains(&manifest_assertions),
+      target_directory.as_path(),
+    );
+
+  assert_run_example(example_test_config)
+}
+
+
+#[test]
+fn runs_a_simple_template_with_parent() -> Result<(), Box<dyn std::error::Error>> {
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.into_path().join("example-simple-with-parent");
+
+  let parent_assertions = ["Inheriting from parent repository: ../parent"];
+
+  let example_test_config =
+    ExampleTestConfig::with_expected_output_and_files(
+      "simple-with-parent",
+      &["YouOnlyLiveOnce", "", "", "y"],
+      AssertionType::Contains(&parent_assertions),
+      target_directory.as_path(),
+    );
+
+  assert_run_example(example_test_config)
+}
+
+
+#[test]
+fn runs_composed_templates() -> Result<(), Box<dyn std::error::Error>> {
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.into_path().join("example-composed-templates");
+
+  let collision_assertions = ["Overwriting file", "README.md' as it already exists."];
+
+  // The shared project variable is only prompted for once
+  let example_test_config =
+    ExampleTestConfig::with_additional_sources(
+      "composed-templates",
+      &["YouOnlyLiveOnce", "", "", "y"],
+      AssertionType::Contains(&collision_assertions),
+      target_directory.as_path(),
+      &["with-docker"]
+    );
+
+  assert_run_example(example_test_config)
+}
+
+
+#[test]
+fn runs_a_generator_against_an_existing_project() -> Result<(), Box<dyn std::error::Error>> {
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.into_path().join("example-generator");
+
+  copy_directory(Path::new("./tests/examples/generator/project"), &target_directory)?;
+
+  let mut cmd = Command::cargo_bin("zat").unwrap();
+
+  cmd
+    .arg("generate")
+    .arg("./tests/examples/generator/source")
+    .arg("handler")
+    .arg("--target-dir")
+    .arg(&target_directory)
+    .arg("--path-prefix")
+    .arg("src")
+    .arg("--choice-menu-style")
+    .arg("numbered")
+    .write_stdin(stdin(&["UserProfile", "y"]))
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Running generator: handler"));
+
+  let expected_target_directory = "./tests/examples/generator/destination";
+  print_changes(expected_target_directory, &target_directory);
+
+  assert!(!dir_diff::is_different(&target_directory, expected_target_directory).unwrap());
+
+  Ok(())
+}
+
+
+#[test]
+fn creates_a_repository_from_an_existing_project() -> Result<(), Box<dyn std::error::Error>> {
+  let working_directory = tempdir()?;
+  let repository_directory = working_directory.into_path().join("example-templatize");
+
+  let mut cmd = Command::cargo_bin("zat").unwrap();
+
+  cmd
+    .arg("templatize")
+    .arg("--source")
+    .arg("./tests/examples/templatize/project")
+    .arg("--repository-dir")
+    .arg(&repository_directory)
+    .arg("--replace")
+    .arg("my-service=project")
+    .arg("--ignores")
+    .arg("build/.*")
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("with 3 template file(s)."));
+
+  let expected_repository_directory = "./tests/examples/templatize/destination";
+  print_changes(expected_repository_directory, &repository_directory);
+
+  assert!(!dir_diff::is_different(&repository_directory, expected_repository_directory).unwrap());
+
+  Ok(())
+}
+
+
+#[test]
+fn runs_the_test_cases_of_a_repository() -> Result<(), Box<dyn std::error::Error>> {
+  let mut cmd = Command::cargo_bin("zat").unwrap();
+
+  cmd
+    .arg("test")
+    .arg("--repository-dir")
+    .arg("./tests/examples/template-tests/source")
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("PASSED: long-readme"))
+    .stdout(predicate::str::contains("PASSED: short-readme"))
+    .stdout(predicate::str::contains("2 test case(s) passed."));
+
+  Ok(())
+}
+
+
+#[test]
+fn fails_and_blesses_test_cases_with_changed_output() -> Result<(), Box<dyn std::error::Error>> {
+  let working_directory = tempdir()?;
+  let repository_directory = working_directory.path().join("template-tests");
+
+  copy_directory(Path::new("./tests/examples/template-tests/source"), &repository_directory)?;
+
+  let expected_readme = repository_directory.join("tests").join("short-readme").join("expected").join("README.md");
+  let original_readme = std::fs::read_to_string(&expected_readme)?;
+  std::fs::write(&expected_readme, original_readme.replace("This is a short one", "This is a tiny one"))?;
+
+  Command::cargo_bin("zat")
+    .unwrap()
+    .arg("test")
+    .arg("--repository-dir")
+    .arg(&repository_directory)
+    .assert()
+    .failure()
+    .stdout(predicate::str::contains("FAILED: short-readme"))
+    .stdout(predicate::str::contains("Changes found in:"))
+    .stderr(predicate::str::contains("1 of 2 test case(s) failed: short-readme"));
+
+  Command::cargo_bin("zat")
+    .unwrap()
+    .arg("test")
+    .arg("--repository-dir")
+    .arg(&repository_directory)
+    .arg("--bless")
+    .assert()
+    .success();
+
+  assert_eq!(std::fs::read_to_string(&expected_readme)?, original_readme);
+
+  Ok(())
+}
+
+
+#[test]
+fn describes_a_repository() -> Result<(), Box<dyn std::error::Error>> {
+  Command::cargo_bin("zat")
+    .unwrap()
+    .arg("describe")
+    .arg("--repository-dir")
+    .arg("./tests/examples/simple-with-scopes/source")
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Variable name: readme_type"))
+    .stdout(predicate::str::contains("include if choice: readme_type has value: long"))
+    .stdout(predicate::str::contains("README.md.tmpl"));
+
+  Ok(())
+}
+
+
+#[test]
+fn validates_the_arguments_of_plugins() -> Result<(), Box<dyn std::error::Error>> {
+  Command::cargo_bin("zat")
+    .unwrap()
+    .arg("validate")
+    .arg("--repository-dir")
+    .arg("./tests/examples/simple-with-plugin-outputs/source")
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("is valid"));
+
+  Command::cargo_bin("zat")
+    .unwrap()
+    .arg("validate")
+    .arg("--repository-dir")
+    .arg("./tests/examples/simple-with-invalid-plugin-args/source")
+    .assert()
+    .failure()
+    .stdout(predicate::str::contains("Plugin 'tests/plugins/outputs.sh' of variable 'description' does not declare the argument 'lang'."))
+    .stdout(predicate::str::contains("Plugin 'tests/plugins/outputs.sh' of variable 'description' requires the argument 'language', which is not supplied."));
+
+  Ok(())
+}
+
+
+#[test]
+fn describes_a_repository_as_json() -> Result<(), Box<dyn std::error::Error>> {
+  let output =
+    Command::cargo_bin("zat")
+      .unwrap()
+      .arg("describe")
+      .arg("--repository-dir")
+      .arg("./tests/examples/simple-with-plugin/source")
+      .arg("--format")
+      .arg("json")
+      .output()?;
+
+  assert!(output.status.success());
+
+  let description: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+
+  assert_eq!(description["plugins"][0]["command"], "tests/plugins/success.sh Testing 123");
+  assert_eq!(description["variables"][0]["variable_name"], "project");
+  assert_eq!(description["files"], serde_json::json!(["README.md.tmpl"]));
+
+  Ok(())
+}
+
+
 #[test]
 fn runs_a_simple_template_with_plugin() -> Result<(), Box<dyn std::error::Error>> {
   let example_test_config =
@@ -86,6 +329,82 @@ fn runs_a_simple_template_with_plugin() -> Result<(), Box<dyn std::error::Error>
 }
 
 
+#[test]
+fn runs_a_simple_template_with_dependent_plugin() -> Result<(), Box<dyn std::error::Error>> {
+  let example_test_config =
+    ExampleTestConfig::with_input(
+      "simple-with-dependent-plugin",
+      &["YouOnlyLiveOnce", "", "y"]
+    );
+
+  assert_run_example(example_test_config)
+}
+
+
+#[test]
+fn runs_a_simple_template_with_lifecycle_hooks() -> Result<(), Box<dyn std::error::Error>> {
+  let example_test_config =
+    ExampleTestConfig {
+      // The hook scoped to the 'Short' README is not run
+      files_that_should_not_exist: &[Path::new("short-readme.txt")],
+      ..ExampleTestConfig::with_input(
+        "simple-with-lifecycle-hooks",
+        &["2", "YouOnlyLiveOnce", "y"]
+      )
+    };
+
+  assert_run_example(example_test_config)
+}
+
+
+#[test]
+fn runs_a_simple_template_with_post_processing_actions() -> Result<(), Box<dyn std::error::Error>> {
+  let example_test_config =
+    ExampleTestConfig::with_input(
+      "simple-with-post-processing-actions",
+      &["2", "YouOnlyLiveOnce", "y"]
+    );
+
+  assert_run_example(example_test_config)
+}
+
+
+#[test]
+fn runs_a_simple_template_with_bundled_plugin() -> Result<(), Box<dyn std::error::Error>> {
+  let example_test_config =
+    ExampleTestConfig::with_input(
+      "simple-with-bundled-plugin",
+      &["YouOnlyLiveOnce", "", "y"]
+    );
+
+  assert_run_example(example_test_config)
+}
+
+
+#[test]
+fn runs_a_simple_template_with_optional_plugin_that_times_out() -> Result<(), Box<dyn std::error::Error>> {
+  let example_test_config =
+    ExampleTestConfig::with_input(
+      "simple-with-optional-plugin",
+      &["YouOnlyLiveOnce", "", "y"]
+    );
+
+  assert_run_example(example_test_config)
+}
+
+
+#[test]
+fn runs_a_simple_template_with_plugin_outputs() -> Result<(), Box<dyn std::error::Error>> {
+  let example_test_config =
+    ExampleTestConfig::with_input(
+      "simple-with-plugin-outputs",
+      &["YouOnlyLiveOnce", "", "", "y"]
+    );
+
+  assert_run_example(example_test_config)
+}
+
+
 #[test]
 fn runs_a_simple_template_with_choices() -> Result<(), Box<dyn std::error::Error>> {
   let example_test_config =
@@ -98,6 +417,18 @@ fn runs_a_simple_template_with_choices() -> Result<(), Box<dyn std::error::Error
 }
 
 
+#[test]
+fn runs_a_simple_template_with_choices_from_a_plugin() -> Result<(), Box<dyn std::error::Error>> {
+  let example_test_config =
+    ExampleTestConfig::with_input(
+      "simple-with-plugin-choices",
+      &["2", "YouOnlyLiveOnce", "y"]
+    );
+
+  assert_run_example(example_test_config)
+}
+
+
 #[test]
 fn runs_a_simple_template_with_scopes() -> Result<(), Box<dyn std::error::Error>> {
   let example_test_config =
@@ -169,12 +500,14 @@ fn runs_the_bootstrap_template() -> Result<(), Box<dyn std::error::Error>> {
   let variable_file = Path::new(".variables.zat-prompt");
   let readme_file = Path::new("template").join("README.md.tmpl");
   let config_file = Path::new("template").join("$project__underscore$_config.conf");
+  let answers_file = Path::new("bootstrap-answers.txt");
 
   let files_that_should_exist =
     [
       variable_file,
       readme_file.as_path(),
-      config_file.as_path()
+      config_file.as_path(),
+      answers_file
     ];
 
     let working_directory_path = tempdir()?.into_path();
@@ -199,6 +532,366 @@ fn runs_the_bootstrap_template() -> Result<(), Box<dyn std::error::Error>> {
   assert_run_bootstrap_example(bootstrap_test_config)
 }
 
+#[test]
+fn runs_the_full_bootstrap_template_with_its_answers() -> Result<(), Box<dyn std::error::Error>> {
+  let working_directory = tempdir()?.into_path();
+  let repository_directory = working_directory.join("example-full-bootstrap-dir");
+  let target_directory = working_directory.join("example-full-bootstrap");
+
+  Command::cargo_bin("zat")
+    .unwrap()
+    .arg("bootstrap")
+    .arg("--repository-dir")
+    .arg(&repository_directory)
+    .arg("--kind")
+    .arg("full")
+    .assert()
+    .success();
+
+  let answers = std::fs::read_to_string(repository_directory.join("bootstrap-answers.txt"))?;
+
+  Command::cargo_bin("zat")
+    .unwrap()
+    .arg("process")
+    .arg("--repository-dir")
+    .arg(&repository_directory)
+    .arg("--target-dir")
+    .arg(&target_directory)
+    .arg("--choice-menu-style")
+    .arg("numbered")
+    .write_stdin(answers)
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("The shell hook received the target directory"));
+
+  let readme = std::fs::read_to_string(target_directory.join("README.md"))?;
+
+  assert!(readme.starts_with("# My Cool Project"));
+  assert!(readme.contains("A longer summary of my cool project"));
+  assert!(target_directory.join("my_cool_project_config.conf").exists());
+
+  Ok(())
+}
+
+#[test]
+fn runs_a_simple_template_from_an_archive() -> Result<(), Box<dyn std::error::Error>> {
+  let archive_directory = tempdir()?;
+  let archive_path = archive_directory.path().join("simple-1.0.0.tar.gz");
+
+  // Archive the example repository under a single top-level folder, as release tarballs usually are
+  let encoder = flate2::write::GzEncoder::new(std::fs::File::create(&archive_path)?, flate2::Compression::default());
+  let mut builder = tar::Builder::new(encoder);
+  builder.append_dir_all("simple-1.0.0", "./tests/examples/simple/source")?;
+  builder.into_inner()?.finish()?;
+
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.into_path().join("example-simple-from-archive");
+  let archive_url = s!("file://{}", archive_path.to_string_lossy());
+
+  let mut cmd = Command::cargo_bin("zat").unwrap();
+
+  cmd
+    .arg("process-archive")
+    .arg("--archive")
+    .arg(&archive_url)
+    .arg("--target-dir")
+    .arg(&target_directory)
+    .arg("--choice-menu-style")
+    .arg("numbered")
+    .write_stdin(stdin(&["YouOnlyLiveOnce", "", "y"]))
+    .assert()
+    .success();
+
+  let expected_target_directory = "./tests/examples/simple/destination";
+  print_changes(expected_target_directory, &target_directory);
+
+  assert!(!dir_diff::is_different(&target_directory, expected_target_directory).unwrap());
+
+  Ok(())
+}
+
+#[test]
+fn runs_a_simple_template_from_a_file_url_repository() -> Result<(), Box<dyn std::error::Error>> {
+  let repository_directory = tempdir()?;
+  let bare_repository = repository_directory.path().join("simple.git");
+  create_bare_repository("./tests/examples/simple/source", &bare_repository)?;
+
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.into_path().join("example-simple-from-file-url");
+  let repository_url = s!("file://{}", bare_repository.to_string_lossy());
+
+  let mut cmd = Command::cargo_bin("zat").unwrap();
+
+  cmd
+    .arg("process-remote")
+    .arg("--repository-url")
+    .arg(&repository_url)
+    .arg("--target-dir")
+    .arg(&target_directory)
+    .arg("--choice-menu-style")
+    .arg("numbered")
+    .write_stdin(stdin(&["YouOnlyLiveOnce", "", "y"]))
+    .assert()
+    .success();
+
+  let expected_target_directory = "./tests/examples/simple/destination";
+  print_changes(expected_target_directory, &target_directory);
+
+  assert!(!dir_diff::is_different(&target_directory, expected_target_directory).unwrap());
+
+  Ok(())
+}
+
+#[test]
+fn asks_to_trust_the_shell_hook_of_a_remote_repository() -> Result<(), Box<dyn std::error::Error>> {
+  let repository_directory = tempdir()?;
+  let bare_repository = repository_directory.path().join("simple-with-shell-hook.git");
+  create_bare_repository("./tests/examples/simple-with-shell-hook/source", &bare_repository)?;
+
+  let config_directory = tempdir()?;
+  let working_directory = tempdir()?;
+  let repository_url = s!("file://{}", bare_repository.to_string_lossy());
+
+  let process_remote = |target_directory: &str, answers: &[&str]| -> Result<assert_cmd::assert::Assert, Box<dyn std::error::Error>> {
+    Ok(
+      Command::cargo_bin("zat")?
+        .env("ZAT_CONFIG_DIR", config_directory.path())
+        .arg("process-remote")
+        .arg("--repository-url")
+        .arg(&repository_url)
+        .arg("--target-dir")
+        .arg(working_directory.path().join(target_directory))
+        .arg("--choice-menu-style")
+        .arg("numbered")
+        .write_stdin(stdin(answers))
+        .assert()
+    )
+  };
+
+  process_remote("rejected", &["n"])?
+    .failure()
+    .stdout(predicate::str::contains("will run the following commands on this machine"))
+    .stdout(predicate::str::contains("echo \"running shell hook\""))
+    .stderr(predicate::str::contains("were not trusted, so they were not run"));
+
+  assert!(!working_directory.path().join("rejected").exists());
+
+  process_remote("trusted", &["y", "Something Cool", "", "y"])?
+    .success()
+    .stdout(predicate::str::contains("running shell hook"));
+
+  assert!(working_directory.path().join("trusted").join("created-by-shell-hook").exists());
+
+  // The same commit is not confirmed again
+  process_remote("trusted-again", &["Something Cool", "", "y"])?
+    .success()
+    .stdout(predicate::str::contains("which you have trusted"))
+    .stdout(predicate::str::contains("running shell hook"));
+
+  Ok(())
+}
+
+
+#[test]
+fn skips_the_shell_hook_of_a_remote_repository_with_no_hooks() -> Result<(), Box<dyn std::error::Error>> {
+  let repository_directory = tempdir()?;
+  let bare_repository = repository_directory.path().join("simple-with-shell-hook.git");
+  create_bare_repository("./tests/examples/simple-with-shell-hook/source", &bare_repository)?;
+
+  let config_directory = tempdir()?;
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.path().join("example-simple-without-shell-hook");
+
+  Command::cargo_bin("zat")?
+    .env("ZAT_CONFIG_DIR", config_directory.path())
+    .arg("process-remote")
+    .arg("--repository-url")
+    .arg(s!("file://{}", bare_repository.to_string_lossy()))
+    .arg("--target-dir")
+    .arg(&target_directory)
+    .arg("--choice-menu-style")
+    .arg("numbered")
+    .arg("--no-hooks")
+    .write_stdin(stdin(&["Something Cool", "", "y"]))
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Skipping shell hook"))
+    .stdout(predicate::str::contains("will run the following commands").not());
+
+  assert!(target_directory.exists());
+  assert!(!target_directory.join("created-by-shell-hook").exists());
+
+  Ok(())
+}
+
+
+#[test]
+fn skips_plugins_with_no_plugins() -> Result<(), Box<dyn std::error::Error>> {
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.path().join("example-simple-without-plugins");
+
+  Command::cargo_bin("zat")?
+    .arg("process")
+    .arg("--repository-dir")
+    .arg("./tests/examples/simple-with-plugin/source")
+    .arg("--target-dir")
+    .arg(&target_directory)
+    .arg("--choice-menu-style")
+    .arg("numbered")
+    .arg("--no-plugins")
+    .write_stdin(stdin(&["YouOnlyLiveOnce", "Entered without a plugin", "y"]))
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Skipping plugins"));
+
+  let readme = std::fs::read_to_string(target_directory.join("README.md"))?;
+  assert!(readme.contains("Entered without a plugin"));
+
+  Ok(())
+}
+
+
+#[test]
+fn reuses_cached_plugin_results() -> Result<(), Box<dyn std::error::Error>> {
+  let config_directory = tempdir()?;
+  let working_directory = tempdir()?;
+
+  // Each run generates a new project, as results are reused across target directories
+  let run = |project: &str, extra_args: &[&str]| -> Result<assert_cmd::assert::Assert, Box<dyn std::error::Error>> {
+    let target_directory = working_directory.path().join(project);
+
+    Ok(
+      Command::cargo_bin("zat")?
+        .env("ZAT_CONFIG_DIR", config_directory.path())
+        .arg("process")
+        .arg("--repository-dir")
+        .arg("./tests/examples/simple-with-cached-plugin/source")
+        .arg("--target-dir")
+        .arg(&target_directory)
+        .arg("--choice-menu-style")
+        .arg("numbered")
+        .args(extra_args)
+        .write_stdin(stdin(&["YouOnlyLiveOnce", "", "y"]))
+        .assert()
+        .success()
+    )
+  };
+
+  let cached_result = "Using the cached result of plugin: tests/plugins/success.sh Cached for an hour";
+
+  run("first-project", &[])?.stdout(predicate::str::contains(cached_result).not());
+  run("second-project", &[])?.stdout(predicate::str::contains(cached_result));
+
+  let readme = std::fs::read_to_string(working_directory.path().join("second-project").join("README.md"))?;
+  assert!(readme.contains("Cached for an hour"));
+
+  run("third-project", &["--no-plugin-cache"])?.stdout(predicate::str::contains(cached_result).not());
+
+  Ok(())
+}
+
+
+#[test]
+fn runs_a_simple_template_from_the_template_registry() -> Result<(), Box<dyn std::error::Error>> {
+  let repository_directory = tempdir()?;
+  let bare_repository = repository_directory.path().join("examples.git");
+  create_bare_repository("./tests/examples/simple", &bare_repository)?;
+
+  let config_directory = tempdir()?;
+  let repository_url = s!("file://{}", bare_repository.to_string_lossy());
+
+  Command::cargo_bin("zat")?
+    .env("ZAT_CONFIG_DIR", config_directory.path())
+    .args(["templates", "add", "--name", "simple", "--url", &repository_url, "--ref", "v1.0.0", "--subdir", "source"])
+    .assert()
+    .success();
+
+  Command::cargo_bin("zat")?
+    .env("ZAT_CONFIG_DIR", config_directory.path())
+    .args(["templates", "list"])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("simple").and(predicate::str::contains("ref=v1.0.0 subdir=source")));
+
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.into_path().join("example-simple-from-registry");
+
+  Command::cargo_bin("zat")?
+    .env("ZAT_CONFIG_DIR", config_directory.path())
+    .arg("process-remote")
+    .arg("--template")
+    .arg("simple")
+    .arg("--target-dir")
+    .arg(&target_directory)
+    .arg("--choice-menu-style")
+    .arg("numbered")
+    .write_stdin(stdin(&["YouOnlyLiveOnce", "", "y"]))
+    .assert()
+    .success();
+
+  let expected_target_directory = "./tests/examples/simple/destination";
+  print_changes(expected_target_directory, &target_directory);
+
+  assert!(!dir_diff::is_different(&target_directory, expected_target_directory).unwrap());
+
+  Command::cargo_bin("zat")?
+    .env("ZAT_CONFIG_DIR", config_directory.path())
+    .args(["templates", "remove", "--name", "simple"])
+    .assert()
+    .success();
+
+  Command::cargo_bin("zat")?
+    .env("ZAT_CONFIG_DIR", config_directory.path())
+    .args(["templates", "list"])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("There are no templates registered"));
+
+  Ok(())
+}
+
+#[test]
+fn runs_a_simple_template_with_user_defaults() -> Result<(), Box<dyn std::error::Error>> {
+  let config_directory = tempdir()?;
+  let user_defaults = r#"
+    choice_menu_style = "numbered"
+
+    [variables]
+    project = "YouOnlyLiveOnce"
+  "#;
+
+  std::fs::write(config_directory.path().join("config.toml"), user_defaults)?;
+
+  Command::cargo_bin("zat")?
+    .env("ZAT_CONFIG_DIR", config_directory.path())
+    .args(["config", "show"])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Menu Style: numbered").and(predicate::str::contains("Variable defaults: project=YouOnlyLiveOnce")));
+
+  let working_directory = tempdir()?;
+  let target_directory = working_directory.into_path().join("example-simple-with-user-defaults");
+
+  // Accept the user default for 'project' and the template default for 'description'
+  Command::cargo_bin("zat")?
+    .env("ZAT_CONFIG_DIR", config_directory.path())
+    .arg("process")
+    .arg("--repository-dir")
+    .arg("./tests/examples/simple/source")
+    .arg("--target-dir")
+    .arg(&target_directory)
+    .write_stdin(stdin(&["", "", "y"]))
+    .assert()
+    .success();
+
+  let expected_target_directory = "./tests/examples/simple/destination";
+  print_changes(expected_target_directory, &target_directory);
+
+  assert!(!dir_diff::is_different(&target_directory, expected_target_directory).unwrap());
+
+  Ok(())
+}
+
 //----------------------------------------------------------------------------------------------------------------------
 // Helper classes
 //----------------------------------------------------------------------------------------------------------------------
@@ -214,10 +907,18 @@ struct ExampleTestConfig<'a> {
   maybe_target_directory: Option<&'a Path>,
   maybe_stdout_assertions: Option<AssertionType<'a>>,
   files_that_should_not_exist: &'a[&'a Path],
-  ignores: &'a[&'a str]
+  ignores: &'a[&'a str],
+  additional_sources: &'a[&'a str]
 }
 
 impl <'a> ExampleTestConfig<'a> {
+  fn with_additional_sources(test_directory: &'a str, input: &'a[&'a str], expected_output: AssertionType<'a>, target_dir: &'a Path, additional_sources: &'a[&'a str]) -> Self {
+    Self {
+      additional_sources,
+      ..Self::with_expected_output_and_files(test_directory, input, expected_output, target_dir)
+    }
+  }
+
   fn with_input(test_directory: &'a str, input: &'a[&'a str]) -> Self {
 
     let maybe_input = Some(input);
@@ -232,7 +933,8 @@ impl <'a> ExampleTestConfig<'a> {
       maybe_target_directory,
       maybe_stdout_assertions,
       files_that_should_not_exist,
-      ignores
+      ignores,
+      additional_sources: &[]
     }
   }
 
@@ -250,7 +952,8 @@ impl <'a> ExampleTestConfig<'a> {
       maybe_target_directory,
       maybe_stdout_assertions,
       files_that_should_not_exist,
-      ignores
+      ignores,
+      additional_sources: &[]
     }
   }
 
@@ -266,7 +969,8 @@ impl <'a> ExampleTestConfig<'a> {
       maybe_target_directory,
       maybe_stdout_assertions,
       files_that_should_not_exist,
-      ignores
+      ignores,
+      additional_sources: &[]
     }
   }
 }
@@ -330,6 +1034,13 @@ fn assert_run_example(example_config: ExampleTestConfig) -> Result<(), Box<dyn s
     .arg("--choice-menu-style")
     .arg("numbered"); // The selection menu style can't be tested through stdin at the moment; use numbered which can.
 
+    // Additional repositories are siblings of the source directory
+    for additional_source in example_config.additional_sources {
+      cmd
+        .arg("--repository-dir")
+        .arg(s!("./tests/examples/{}/{}", example_config.test_directory, additional_source));
+    }
+
     for ig in example_config.ignores {
       cmd
         .arg("--ignores")
@@ -426,3 +1137,43 @@ fn stdin(responses: &[&str]) -> String {
 
   s!("{}\n", delimited) // add the extra newline for complete the final answer
 }
+
+/// Commits the work tree into a new bare repository tagged 'v1.0.0', without touching this repository's index.
+fn create_bare_repository(work_tree: &str, bare_repository: &Path) -> Result<(), Box<dyn std::error::Error>> {
+  let bare_repository_arg = s!("--git-dir={}", bare_repository.to_string_lossy());
+  let work_tree_arg = s!("--work-tree={}", work_tree);
+
+  let git_commands: [&[&str]; 4] =
+    [
+      &["init", "--quiet", "--bare", &bare_repository.to_string_lossy()],
+      &[&bare_repository_arg, &work_tree_arg, "add", "--all"],
+      &[&bare_repository_arg, &work_tree_arg, "-c", "user.name=zat", "-c", "user.email=zat@example.com", "commit", "--quiet", "-m", "examples"],
+      &[&bare_repository_arg, "tag", "v1.0.0"],
+    ];
+
+  for git_args in git_commands {
+    let status = std::process::Command::new("git").args(git_args).status()?;
+    assert!(status.success(), "git {:?} failed", git_args);
+  }
+
+  Ok(())
+}
+
+
+/// Copies an example project so it can be changed by a test.
+fn copy_directory(source: &Path, destination: &Path) -> Result<(), Box<dyn std::error::Error>> {
+  std::fs::create_dir_all(destination)?;
+
+  for entry in std::fs::read_dir(source)? {
+    let entry = entry?;
+    let destination_path = destination.join(entry.file_name());
+
+    if entry.file_type()?.is_dir() {
+      copy_directory(&entry.path(), &destination_path)?
+    } else {
+      std::fs::copy(entry.path(), destination_path)?;
+    }
+  }
+
+  Ok(())
+}
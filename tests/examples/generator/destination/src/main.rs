@@ -0,0 +1,10 @@
+mod router;
+mod user_profile;
+// zat-insert
+
+fn main() {
+  let mut router = router::Router::new();
+  router.add("/user_profile", user_profile::UserProfileHandler);
+  // zat-insert:routes
+  router.run();
+}
@@ -0,0 +1,7 @@
+pub struct UserProfileHandler;
+
+impl UserProfileHandler {
+  pub fn handle(&self) -> String {
+    "user_profile".to_owned()
+  }
+}
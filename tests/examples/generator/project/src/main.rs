@@ -0,0 +1,8 @@
+mod router;
+// zat-insert
+
+fn main() {
+  let mut router = router::Router::new();
+  // zat-insert:routes
+  router.run();
+}
@@ -0,0 +1,4 @@
+#!/bin/bash
+
+KIND="$1"
+echo "{\"success\":{\"result\": \"Described by a $KIND plugin\"}}"
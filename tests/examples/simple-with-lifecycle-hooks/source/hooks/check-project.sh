@@ -0,0 +1,7 @@
+#!/bin/bash
+
+# Runs before any templates are written, so the target directory does not exist yet
+if [ -z "$ZAT_VAR_PROJECT" ] || [ -d "$ZAT_TARGET_DIR" ]; then
+  echo "Expected a project name and no target directory"
+  exit 1
+fi
@@ -0,0 +1,3 @@
+#!/bin/bash
+
+echo "$ZAT_VAR_PROJECT with a $ZAT_CHOICE_README_TYPE README" > "$ZAT_TARGET_DIR/$1"
@@ -0,0 +1,7 @@
+mod my_service;
+
+fn main() {
+  let service = my_service::MyService::new();
+  println!("Starting My Service");
+  service.run();
+}
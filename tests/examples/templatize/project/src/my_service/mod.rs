@@ -0,0 +1,9 @@
+pub struct MyService;
+
+impl MyService {
+  pub fn new() -> Self {
+    MyService
+  }
+
+  pub fn run(&self) {}
+}
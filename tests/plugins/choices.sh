@@ -0,0 +1,4 @@
+#!/bin/bash
+
+# Returns a list of choices instead of a single result
+echo '{"success":{"choices":[{"display":"Scala 3","description":"The latest version of Scala 3","value":"3.3.1"},{"display":"Scala 2","description":"The latest version of Scala 2","value":"2.13.12"}]}}'
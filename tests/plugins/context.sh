@@ -0,0 +1,13 @@
+#!/bin/bash
+
+# Returns its argument, a variable from the environment and whether the context was received on stdin
+ARG="$1"
+CONTEXT=$(cat)
+
+if echo "$CONTEXT" | grep -q '"project":"YouOnlyLiveOnce"'; then
+  STDIN="yes"
+else
+  STDIN="no"
+fi
+
+echo "{\"success\":{\"result\": \"$ARG for $ZAT_VAR_PROJECT (context on stdin: $STDIN)\"}}"
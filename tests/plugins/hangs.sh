@@ -0,0 +1,4 @@
+#!/bin/bash
+
+# Never returns a result in time
+exec sleep 10
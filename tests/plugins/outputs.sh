@@ -0,0 +1,10 @@
+#!/bin/bash
+
+# A version 2 plugin that describes its arguments, and returns outputs for several variables along with a warning
+if [ "$1" == "--zat-describe" ]; then
+  echo '{"protocol_version":2,"name":"outputs","arguments":[{"name":"language","required":true,"description":"The language of the project"}]}'
+  exit 0
+fi
+
+LANGUAGE="$2"
+echo "{\"protocol_version\":2,\"success\":{\"outputs\":{\"description\":\"A $LANGUAGE project\",\"author\":\"The $LANGUAGE team\"}},\"warnings\":[\"Using the default $LANGUAGE team\"]}"